@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! Fetches an anime from AniList in the browser.
+
+use rust_anilist::Client;
+use wasm_bindgen::prelude::*;
+
+/// Returns the romaji title of the anime with the given ID.
+#[wasm_bindgen]
+pub async fn anime_title(id: i64) -> Result<String, JsValue> {
+    let anime = Client::default()
+        .get_anime(id)
+        .await
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(anime.title.romaji().to_string())
+}
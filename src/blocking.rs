@@ -0,0 +1,988 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! A blocking client, for programs that don't run an async runtime.
+//!
+//! The blocking [`Client`] wraps the async [`Client`](crate::Client) and
+//! drives it on a runtime of its own. Both clients return the same
+//! models.
+//!
+//! This module is only available with the `blocking` feature.
+//!
+//! # Panics
+//!
+//! The blocking client must not be used from within an async runtime:
+//! doing so panics. Use the async client there instead.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # fn f() -> rust_anilist::Result<()> {
+//! let client = rust_anilist::blocking::Client::default();
+//! let anime = client.get_anime(1)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{FixedOffset, NaiveDate};
+use futures_util::StreamExt;
+use serde::de::DeserializeOwned;
+use tokio::runtime::Runtime;
+
+use crate::import::{ImportOptions, ImportReport, ImportedEntry};
+use crate::models::{
+    Activity, AiringEntry, AiringEvent, Anime, AnimeId, Character, CharacterId, CharacterMedia,
+    CharacterMediaFilter, Favourites, Genre, ListComparison, Manga, MangaId, MediaListCollection,
+    MediaListEntry, MediaPreview, MediaSearchOptions, MediaType, Notification, Page, Person,
+    PersonId, SearchResults, Studio, StudioId, User, UserId,
+};
+use crate::{AnimeFields, Result};
+
+/// Represents a blocking client for interacting with the AniList API.
+///
+/// Every method blocks the current thread until the request completes.
+/// Clones of a client share the same runtime, and the state of the
+/// async client they wrap.
+///
+/// Configure an async client with [`Client::builder`](crate::Client::builder)
+/// and convert it to configure a blocking one.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// let client: rust_anilist::blocking::Client = rust_anilist::Client::builder()
+///     .timeout(Duration::from_secs(10))
+///     .build()
+///     .into();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Client {
+    /// The async client sending the requests.
+    inner: crate::Client,
+    /// The runtime driving the async client.
+    runtime: Arc<Runtime>,
+}
+
+impl Client {
+    /// Creates a blocking client wrapping the given async client.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The async client.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the runtime cannot be created.
+    pub fn new(client: crate::Client) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to create the runtime of the blocking client");
+
+        Self {
+            inner: client,
+            runtime: Arc::new(runtime),
+        }
+    }
+
+    /// Creates a new client with a specified timeout duration.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The timeout duration.
+    pub fn with_timeout(duration: Duration) -> Self {
+        Self::new(crate::Client::with_timeout(duration))
+    }
+
+    /// Creates a new client with the given API token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - A string slice that holds the API token.
+    pub fn with_token(token: &str) -> Self {
+        Self::new(crate::Client::with_token(token))
+    }
+
+    /// Sets the timeout duration of the client.
+    ///
+    /// See [`Client::timeout`](crate::Client::timeout), setting the
+    /// timeout of a clone overrides it for some requests only.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The timeout duration.
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.inner = self.inner.timeout(duration);
+        self
+    }
+
+    /// Returns the async client wrapped by this client.
+    pub fn as_async(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Get an anime by its ID.
+    ///
+    /// See [`Client::get_anime`](crate::Client::get_anime).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the anime.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_anime(&self, id: impl Into<AnimeId>) -> Result<Anime> {
+        self.block_on(self.inner.get_anime(id))
+    }
+
+    /// Get an anime by its ID, requesting only the given blocks.
+    ///
+    /// See [`Client::get_anime_with`](crate::Client::get_anime_with).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the anime.
+    /// * `fields` - The optional blocks to request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_anime_with(&self, id: impl Into<AnimeId>, fields: AnimeFields) -> Result<Anime> {
+        self.block_on(self.inner.get_anime_with(id, fields))
+    }
+
+    /// Get the animes of a franchise in watch order, following the
+    /// prequels and the sequels of an anime.
+    ///
+    /// See [`Client::get_relation_chain`](crate::Client::get_relation_chain).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of an anime of the franchise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails.
+    pub fn get_relation_chain(&self, id: impl Into<AnimeId>) -> Result<Vec<Anime>> {
+        self.block_on(self.inner.get_relation_chain(id))
+    }
+
+    /// Get a page of the characters of an anime, most favourited first.
+    ///
+    /// See [`Client::get_characters_of_anime`](crate::Client::get_characters_of_anime).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the anime.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of characters to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_characters_of_anime(
+        &self,
+        id: impl Into<AnimeId>,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Character>> {
+        self.block_on(self.inner.get_characters_of_anime(id, page, per_page))
+    }
+
+    /// Get a page of the staff of an anime, by relevance.
+    ///
+    /// See [`Client::get_staff_of_anime`](crate::Client::get_staff_of_anime).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the anime.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of people to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_staff_of_anime(
+        &self,
+        id: impl Into<AnimeId>,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Person>> {
+        self.block_on(self.inner.get_staff_of_anime(id, page, per_page))
+    }
+
+    /// Get several animes by their IDs, sending up to `concurrency`
+    /// requests at a time.
+    ///
+    /// See [`Client::get_animes_concurrent`](crate::Client::get_animes_concurrent).
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the animes.
+    /// * `concurrency` - The maximum number of requests in flight.
+    pub fn get_animes_concurrent(
+        &self,
+        ids: &[impl Into<AnimeId> + Copy],
+        concurrency: usize,
+    ) -> Vec<(AnimeId, Result<Anime>)> {
+        self.block_on(self.inner.get_animes_concurrent(ids, concurrency))
+    }
+
+    /// Get several mangas by their IDs, sending up to `concurrency`
+    /// requests at a time.
+    ///
+    /// See [`Client::get_mangas_concurrent`](crate::Client::get_mangas_concurrent).
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the mangas.
+    /// * `concurrency` - The maximum number of requests in flight.
+    pub fn get_mangas_concurrent(
+        &self,
+        ids: &[impl Into<MangaId> + Copy],
+        concurrency: usize,
+    ) -> Vec<(MangaId, Result<Manga>)> {
+        self.block_on(self.inner.get_mangas_concurrent(ids, concurrency))
+    }
+
+    /// Get several characters by their IDs, sending up to `concurrency`
+    /// requests at a time.
+    ///
+    /// See [`Client::get_characters_concurrent`](crate::Client::get_characters_concurrent).
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the characters.
+    /// * `concurrency` - The maximum number of requests in flight.
+    pub fn get_characters_concurrent(
+        &self,
+        ids: &[impl Into<CharacterId> + Copy],
+        concurrency: usize,
+    ) -> Vec<(CharacterId, Result<Character>)> {
+        self.block_on(self.inner.get_characters_concurrent(ids, concurrency))
+    }
+
+    /// Send a GraphQL document and deserialize its `data` object.
+    ///
+    /// See [`Client::query_raw`](crate::Client::query_raw).
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The GraphQL document to send.
+    /// * `variables` - The variables to send with the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, the API answers with
+    /// GraphQL errors or `data` doesn't match `T`.
+    pub fn query_raw<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> Result<T> {
+        self.block_on(self.inner.query_raw(query, variables))
+    }
+
+    /// Send a GraphQL document and return its `data` object as JSON.
+    ///
+    /// See [`Client::query_raw_value`](crate::Client::query_raw_value).
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The GraphQL document to send.
+    /// * `variables` - The variables to send with the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the API answers with
+    /// GraphQL errors.
+    pub fn query_raw_value(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        self.block_on(self.inner.query_raw_value(query, variables))
+    }
+
+    /// Get a manga by its ID.
+    ///
+    /// See [`Client::get_manga`](crate::Client::get_manga).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the manga.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_manga(&self, id: impl Into<MangaId>) -> Result<Manga> {
+        self.block_on(self.inner.get_manga(id))
+    }
+
+    /// Get a character by its ID.
+    ///
+    /// See [`Client::get_character`](crate::Client::get_character).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the character.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_character(&self, id: impl Into<CharacterId>) -> Result<Character> {
+        self.block_on(self.inner.get_character(id))
+    }
+
+    /// Get a page of the medias a character appears in, most popular
+    /// first.
+    ///
+    /// See [`Client::get_character_medias`](crate::Client::get_character_medias).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the character.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of medias to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_character_medias(
+        &self,
+        id: impl Into<CharacterId>,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<CharacterMedia>> {
+        self.block_on(self.inner.get_character_medias(id, page, per_page))
+    }
+
+    /// Get a page of the medias a character appears in, in the order and
+    /// with the options of the filter.
+    ///
+    /// See [`Client::get_character_medias_filtered`](crate::Client::get_character_medias_filtered).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the character.
+    /// * `filter` - The options of the request.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of medias to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_character_medias_filtered(
+        &self,
+        id: impl Into<CharacterId>,
+        filter: CharacterMediaFilter,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<CharacterMedia>> {
+        self.block_on(
+            self.inner
+                .get_character_medias_filtered(id, filter, page, per_page),
+        )
+    }
+
+    /// Get a user by its ID.
+    ///
+    /// See [`Client::get_user`](crate::Client::get_user).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_user(&self, id: impl Into<UserId>) -> Result<User> {
+        self.block_on(self.inner.get_user(id))
+    }
+
+    /// Get a user by its name.
+    ///
+    /// See [`Client::get_user_by_name`](crate::Client::get_user_by_name).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_user_by_name<N: ToString>(&self, name: N) -> Result<User> {
+        self.block_on(self.inner.get_user_by_name(name))
+    }
+
+    /// Get the favourites of a user.
+    ///
+    /// See [`Client::get_user_favourites`](crate::Client::get_user_favourites).
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user doesn't exist or a request fails.
+    pub fn get_user_favourites(&self, user_id: impl Into<UserId>) -> Result<Favourites> {
+        self.block_on(self.inner.get_user_favourites(user_id))
+    }
+
+    /// Get a person by its ID.
+    ///
+    /// See [`Client::get_person`](crate::Client::get_person).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the person.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_person(&self, id: impl Into<PersonId>) -> Result<Person> {
+        self.block_on(self.inner.get_person(id))
+    }
+
+    /// Get a page of the characters voiced by a person, by relevance.
+    ///
+    /// See [`Client::get_person_characters`](crate::Client::get_person_characters).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the person.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of characters to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_person_characters(
+        &self,
+        id: impl Into<PersonId>,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Character>> {
+        self.block_on(self.inner.get_person_characters(id, page, per_page))
+    }
+
+    /// Get a studio by its ID.
+    ///
+    /// See [`Client::get_studio`](crate::Client::get_studio).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the studio.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_studio(&self, id: impl Into<StudioId>) -> Result<Studio> {
+        self.block_on(self.inner.get_studio(id))
+    }
+
+    /// Get a page of the animes produced by a studio, most popular
+    /// first.
+    ///
+    /// See [`Client::get_studio_animes`](crate::Client::get_studio_animes).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the studio.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of animes to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_studio_animes(
+        &self,
+        id: impl Into<StudioId>,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Anime>> {
+        self.block_on(self.inner.get_studio_animes(id, page, per_page))
+    }
+
+    /// Search for animes.
+    ///
+    /// See [`Client::search_anime`](crate::Client::search_anime).
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the anime to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of animes to get per page.
+    pub fn search_anime(&self, title: &str, page: u16, limit: u16) -> Option<Page<Anime>> {
+        self.block_on(self.inner.search_anime(title, page, limit))
+    }
+
+    /// Search for animes having all the given genres.
+    ///
+    /// See [`Client::search_anime_by_genres`](crate::Client::search_anime_by_genres).
+    ///
+    /// # Arguments
+    ///
+    /// * `genres` - The genres the animes must have.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of animes to get per page.
+    pub fn search_anime_by_genres(
+        &self,
+        genres: &[Genre],
+        page: u16,
+        limit: u16,
+    ) -> Option<Page<Anime>> {
+        self.block_on(self.inner.search_anime_by_genres(genres, page, limit))
+    }
+
+    /// Counts the medias matching the given filters.
+    ///
+    /// See [`Client::count_media`](crate::Client::count_media).
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The filters of the medias.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type of the medias is neither anime nor
+    /// manga, or if the request fails.
+    pub fn count_media(&self, options: MediaSearchOptions) -> Result<u32> {
+        self.block_on(self.inner.count_media(options))
+    }
+
+    /// Search for mangas.
+    ///
+    /// See [`Client::search_manga`](crate::Client::search_manga).
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the manga to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of mangas to get per page.
+    pub fn search_manga(&self, title: &str, page: u16, limit: u16) -> Option<Page<Manga>> {
+        self.block_on(self.inner.search_manga(title, page, limit))
+    }
+
+    /// Search for mangas having all the given genres.
+    ///
+    /// See [`Client::search_manga_by_genres`](crate::Client::search_manga_by_genres).
+    ///
+    /// # Arguments
+    ///
+    /// * `genres` - The genres the mangas must have.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of mangas to get per page.
+    pub fn search_manga_by_genres(
+        &self,
+        genres: &[Genre],
+        page: u16,
+        limit: u16,
+    ) -> Option<Page<Manga>> {
+        self.block_on(self.inner.search_manga_by_genres(genres, page, limit))
+    }
+
+    /// Search for users.
+    ///
+    /// See [`Client::search_user`](crate::Client::search_user).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the user to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of users to get per page.
+    pub fn search_user(&self, name: &str, page: u16, limit: u16) -> Option<Page<User>> {
+        self.block_on(self.inner.search_user(name, page, limit))
+    }
+
+    /// Search for studios.
+    ///
+    /// See [`Client::search_studio`](crate::Client::search_studio).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the studio to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of studios to get per page.
+    pub fn search_studio(&self, name: &str, page: u16, limit: u16) -> Option<Page<Studio>> {
+        self.block_on(self.inner.search_studio(name, page, limit))
+    }
+
+    /// Get a studio by its name.
+    ///
+    /// See [`Client::get_studio_by_name`](crate::Client::get_studio_by_name).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the studio.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoMatch`](crate::Error::NoMatch) if no studio
+    /// matches the name, or an error if a request fails.
+    pub fn get_studio_by_name(&self, name: &str) -> Result<Studio> {
+        self.block_on(self.inner.get_studio_by_name(name))
+    }
+
+    /// Search animes, mangas, characters, staff and studios at once.
+    ///
+    /// See [`Client::search_all`](crate::Client::search_all).
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The text to search.
+    /// * `per_type` - The maximum number of results of each type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn search_all(&self, query: &str, per_type: u16) -> Result<SearchResults> {
+        self.block_on(self.inner.search_all(query, per_type))
+    }
+
+    /// Gets the characters with the most favourites, most favourited
+    /// first.
+    ///
+    /// See [`Client::get_top_characters`](crate::Client::get_top_characters).
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of characters to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_top_characters(&self, page: u16, per_page: u16) -> Result<Page<Character>> {
+        self.block_on(self.inner.get_top_characters(page, per_page))
+    }
+
+    /// Gets the staff with the most favourites, most favourited first.
+    ///
+    /// See [`Client::get_top_staff`](crate::Client::get_top_staff).
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of people to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn get_top_staff(&self, page: u16, per_page: u16) -> Result<Page<Person>> {
+        self.block_on(self.inner.get_top_staff(page, per_page))
+    }
+
+    /// Fetches the page following the given one.
+    ///
+    /// See [`Page::next`].
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The current page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn next_page<T>(&self, page: &Page<T>) -> Result<Option<Page<T>>> {
+        self.block_on(page.next())
+    }
+
+    /// Check whether several medias are favourites of the authenticated user.
+    ///
+    /// See [`Client::are_favourites`](crate::Client::are_favourites).
+    ///
+    /// # Arguments
+    ///
+    /// * `media_ids` - The IDs of the medias.
+    /// * `media_type` - The type of the medias.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no token or the request fails.
+    pub fn are_favourites(
+        &self,
+        media_ids: &[i64],
+        media_type: MediaType,
+    ) -> Result<HashMap<i64, bool>> {
+        self.block_on(self.inner.are_favourites(media_ids, media_type))
+    }
+
+    /// Get the previews of medias: their title, cover, format and year.
+    ///
+    /// See [`Client::get_media_previews`](crate::Client::get_media_previews).
+    ///
+    /// # Arguments
+    ///
+    /// * `media_ids` - The IDs of the medias.
+    /// * `media_type` - The type of the medias, either anime or manga.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the media type is neither anime nor manga, or
+    /// if a request fails.
+    pub fn get_media_previews(
+        &self,
+        media_ids: &[i64],
+        media_type: MediaType,
+    ) -> Result<Vec<MediaPreview>> {
+        self.block_on(self.inner.get_media_previews(media_ids, media_type))
+    }
+
+    /// Get the list entries of a user for the given medias.
+    ///
+    /// See [`Client::get_media_list_entries`](crate::Client::get_media_list_entries).
+    ///
+    /// # Arguments
+    ///
+    /// * `media_ids` - The IDs of the animes or mangas.
+    /// * `user_id` - The ID of the user owning the list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails.
+    pub fn get_media_list_entries(
+        &self,
+        media_ids: &[i64],
+        user_id: impl Into<UserId>,
+    ) -> Result<Vec<MediaListEntry>> {
+        self.block_on(self.inner.get_media_list_entries(media_ids, user_id))
+    }
+
+    /// Get the whole anime or manga list of a user.
+    ///
+    /// See [`Client::get_media_list_collection`](crate::Client::get_media_list_collection).
+    ///
+    /// # Arguments
+    ///
+    /// * `user_name` - The name of the user.
+    /// * `media_type` - Whether to get the anime or the manga list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `media_type` is neither `Anime` nor `Manga`,
+    /// if the user doesn't exist or their list is private, or if a
+    /// request fails.
+    pub fn get_media_list_collection(
+        &self,
+        user_name: &str,
+        media_type: MediaType,
+    ) -> Result<MediaListCollection> {
+        self.block_on(self.inner.get_media_list_collection(user_name, media_type))
+    }
+
+    /// Compare the lists of two users.
+    ///
+    /// See [`Client::compare_lists`](crate::Client::compare_lists).
+    ///
+    /// # Arguments
+    ///
+    /// * `first_user` - The name of the first user.
+    /// * `second_user` - The name of the second user.
+    /// * `media_type` - Whether to compare the anime or the manga lists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `media_type` is neither `Anime` nor `Manga`,
+    /// if a user doesn't exist or their list is private, or if a request
+    /// fails.
+    pub fn compare_lists(
+        &self,
+        first_user: &str,
+        second_user: &str,
+        media_type: MediaType,
+    ) -> Result<ListComparison> {
+        self.block_on(
+            self.inner
+                .compare_lists(first_user, second_user, media_type),
+        )
+    }
+
+    /// Import list entries into the list of the authenticated user.
+    ///
+    /// See [`Client::import_entries`](crate::Client::import_entries).
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - The entries to import.
+    /// * `options` - The options of the import.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no token, if the media type
+    /// of an entry is neither anime nor manga, or if a request fails.
+    pub fn import_entries(
+        &self,
+        entries: &[ImportedEntry],
+        options: ImportOptions,
+    ) -> Result<ImportReport> {
+        self.block_on(self.inner.import_entries(entries, options))
+    }
+
+    /// Get the episodes airing in a time window, grouped by day.
+    ///
+    /// See [`Client::get_airing_calendar`](crate::Client::get_airing_calendar).
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The start of the window, as a Unix timestamp.
+    /// * `days` - The length of the window, in days.
+    /// * `offset` - The offset from UTC of the time zone of the days.
+    /// * `only_list` - Whether to keep only the animes in the list of the
+    ///   authenticated user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `only_list` is set and the client has no
+    /// token, or if a request fails.
+    pub fn get_airing_calendar(
+        &self,
+        start: i64,
+        days: u8,
+        offset: FixedOffset,
+        only_list: bool,
+    ) -> Result<BTreeMap<NaiveDate, Vec<AiringEntry>>> {
+        self.block_on(
+            self.inner
+                .get_airing_calendar(start, days, offset, only_list),
+        )
+    }
+
+    /// Watch animes for new episodes.
+    ///
+    /// See [`Client::watch_airing`](crate::Client::watch_airing). Each
+    /// call to `next` blocks until the next event.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_ids` - The IDs of the animes to watch.
+    /// * `poll_interval` - The time to wait between two checks.
+    pub fn watch_airing(
+        &self,
+        media_ids: Vec<i64>,
+        poll_interval: Duration,
+    ) -> impl Iterator<Item = Result<AiringEvent>> + '_ {
+        let mut events = Box::pin(self.inner.watch_airing(media_ids, poll_interval));
+
+        std::iter::from_fn(move || self.block_on(events.next()))
+    }
+
+    /// Watch animes for new episodes, resuming a previous watch.
+    ///
+    /// See [`Client::watch_airing_after`](crate::Client::watch_airing_after).
+    /// Each call to `next` blocks until the next event.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_ids` - The IDs of the animes to watch.
+    /// * `poll_interval` - The time to wait between two checks.
+    /// * `after` - The Unix timestamp from which to report the episodes.
+    /// * `seen` - The IDs of the schedules already reported.
+    pub fn watch_airing_after(
+        &self,
+        media_ids: Vec<i64>,
+        poll_interval: Duration,
+        after: i64,
+        seen: HashSet<u32>,
+    ) -> impl Iterator<Item = Result<AiringEvent>> + '_ {
+        let mut events =
+            Box::pin(
+                self.inner
+                    .watch_airing_after(media_ids, poll_interval, after, seen),
+            );
+
+        std::iter::from_fn(move || self.block_on(events.next()))
+    }
+
+    /// Get the notifications of the authenticated user, newest first.
+    ///
+    /// See [`Client::get_notifications`](crate::Client::get_notifications).
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of notifications to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no token or the request fails.
+    pub fn get_notifications(&self, page: u16, per_page: u16) -> Result<Page<Notification>> {
+        self.block_on(self.inner.get_notifications(page, per_page))
+    }
+
+    /// Post a status on the profile of the authenticated user.
+    ///
+    /// See [`Client::post_text_activity`](crate::Client::post_text_activity).
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text of the status, in AniList markdown.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no token or the request fails.
+    pub fn post_text_activity(&self, text: &str) -> Result<Activity> {
+        self.block_on(self.inner.post_text_activity(text))
+    }
+
+    /// Send a message to the profile of another user.
+    ///
+    /// See [`Client::post_message_activity`](crate::Client::post_message_activity).
+    ///
+    /// # Arguments
+    ///
+    /// * `recipient_id` - The ID of the user receiving the message.
+    /// * `message` - The text of the message, in AniList markdown.
+    /// * `private` - Whether only the recipient can see the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no token or the request fails.
+    pub fn post_message_activity(
+        &self,
+        recipient_id: impl Into<UserId>,
+        message: &str,
+        private: bool,
+    ) -> Result<Activity> {
+        self.block_on(
+            self.inner
+                .post_message_activity(recipient_id, message, private),
+        )
+    }
+
+    /// Runs a future of the async client to completion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within an async runtime.
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        if tokio::runtime::Handle::try_current().is_ok() {
+            panic!(
+                "the blocking client of rust-anilist cannot be used from within an async \
+                 runtime, use `rust_anilist::Client` instead"
+            );
+        }
+
+        self.runtime.block_on(future)
+    }
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Self::new(crate::Client::default())
+    }
+}
+
+impl From<crate::Client> for Client {
+    fn from(client: crate::Client) -> Self {
+        Self::new(client)
+    }
+}
@@ -0,0 +1,393 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `ClientBuilder` struct.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::Url;
+
+use super::{
+    Cache, CacheStore, Client, ConcurrencyLimit, Operation, RequestInfo, ResponseInfo, RetryPolicy,
+    Transport,
+};
+use crate::models::TitleLanguage;
+use crate::Result;
+
+/// A builder for configuring and creating a [`Client`].
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use rust_anilist::Client;
+/// let client = Client::builder()
+///     .timeout(Duration::from_secs(10))
+///     .user_agent("my-app/1.0")
+///     .build();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ClientBuilder {
+    /// The client being configured.
+    client: Client,
+}
+
+impl ClientBuilder {
+    /// Creates a new builder with the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the timeout duration for requests.
+    ///
+    /// The default timeout is 20 seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The timeout duration.
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.client.timeout = duration;
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request.
+    ///
+    /// AniList asks API consumers to identify themselves, so setting the
+    /// name of your application here is recommended. The default is
+    /// `rust-anilist/<version>`.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_agent` - The user agent.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.client.user_agent = user_agent.to_string();
+        self
+    }
+
+    /// Sets the URL of the GraphQL endpoint.
+    ///
+    /// The default endpoint is `https://graphql.anilist.co`. Pointing it
+    /// to a local server is useful for deterministic tests.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL of the endpoint.
+    pub fn api_url(mut self, url: Url) -> Self {
+        self.client.api_url = url;
+        self
+    }
+
+    /// Sets the API token used to authenticate requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - A string slice that holds the API token.
+    pub fn token(mut self, token: &str) -> Self {
+        self.client.api_token = Some(token.to_string());
+        self
+    }
+
+    /// Sets the HTTP client used to send every request.
+    ///
+    /// This lets the client reuse an existing connection pool, proxy
+    /// configuration or set of TLS roots. The timeout and the user agent
+    /// configured on this builder are still applied to every request, on
+    /// top of the defaults of the supplied client.
+    ///
+    /// # Arguments
+    ///
+    /// * `http_client` - The HTTP client.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::Client;
+    /// let http_client = reqwest::Client::new();
+    /// let client = Client::builder().with_http_client(http_client).build();
+    /// ```
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.client.http_client = Some(http_client);
+        self
+    }
+
+    /// Sets the transport used to send every request instead of HTTP.
+    ///
+    /// When a transport is set, the endpoint, user agent, timeout and
+    /// HTTP client options are not used; retries still apply.
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The transport to use.
+    pub fn transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.client.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Enables retrying of rate-limited requests.
+    ///
+    /// See [`Client::retry_on_rate_limit`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_attempts` - The maximum number of attempts, including the first one.
+    /// * `max_wait` - The longest time to wait before a retry.
+    pub fn retry_on_rate_limit(mut self, max_attempts: u32, max_wait: Duration) -> Self {
+        self.client = self.client.retry_on_rate_limit(max_attempts, max_wait);
+        self
+    }
+
+    /// Sets the policy used to retry failed requests.
+    ///
+    /// See [`Client::retry_policy`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The retry policy.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.client.retry_policy = policy;
+        self
+    }
+
+    /// Limits the number of requests sent at the same time.
+    ///
+    /// Requests beyond the limit wait for a running one to complete, no
+    /// matter how many futures are spawned. The limit is shared by the
+    /// clones of the client. By default, requests are not limited.
+    ///
+    /// The limit caps the requests in flight, not their rate: AniList
+    /// still rate limits a burst of fast requests, so combine it with
+    /// [`ClientBuilder::retry_on_rate_limit`] to stay under the quota. A
+    /// request waiting before a retry doesn't count against the limit.
+    /// Responses served from the cache or shared with an identical
+    /// in-flight request don't count against it either.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - The maximum number of requests in flight, at least 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use rust_anilist::Client;
+    /// let client = Client::builder()
+    ///     .max_concurrent(4)
+    ///     .retry_on_rate_limit(3, Duration::from_secs(60))
+    ///     .build();
+    /// ```
+    pub fn max_concurrent(mut self, max: usize) -> Self {
+        self.client.concurrency_limit = Some(ConcurrencyLimit::new(max));
+        self
+    }
+
+    /// Sets the language titles are displayed in.
+    ///
+    /// [`Title::preferred`] returns the title in this language for every
+    /// anime and manga fetched by the client. Without it, the title
+    /// preferred by the authenticated user is returned, which is Romaji
+    /// for anonymous requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The language of the titles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::{models::TitleLanguage, Client};
+    /// let client = Client::builder()
+    ///     .title_language(TitleLanguage::English)
+    ///     .build();
+    /// ```
+    ///
+    /// [`Title::preferred`]: crate::models::Title::preferred
+    pub fn title_language(mut self, language: TitleLanguage) -> Self {
+        self.client.title_language = Some(language);
+        self
+    }
+
+    /// Enables caching of the responses for the given duration.
+    ///
+    /// Responses are kept in memory unless a store is set with
+    /// [`ClientBuilder::cache_store`]. Mutations and responses holding
+    /// errors are never cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl` - How long the responses stay valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use rust_anilist::Client;
+    /// let client = Client::builder().cache(Duration::from_secs(60)).build();
+    /// ```
+    pub fn cache(mut self, ttl: Duration) -> Self {
+        self.client.cache.get_or_insert_with(Cache::default).ttl = ttl;
+        self
+    }
+
+    /// Enables caching of the responses in the given store.
+    ///
+    /// Responses stay valid for 5 minutes unless another duration is set
+    /// with [`ClientBuilder::cache`].
+    ///
+    /// # Arguments
+    ///
+    /// * `store` - The store holding the responses.
+    pub fn cache_store<S: CacheStore + 'static>(mut self, store: S) -> Self {
+        self.client.cache.get_or_insert_with(Cache::default).store = Arc::new(store);
+        self
+    }
+
+    /// Sets a callback called before every request.
+    ///
+    /// Retried requests call it once per attempt, requests answered
+    /// from the cache or by an identical in-flight request don't call it.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - The callback to call.
+    pub fn on_request<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&RequestInfo) + Send + Sync + 'static,
+    {
+        self.client.hooks.on_request = Some(Arc::new(callback));
+        self
+    }
+
+    /// Sets a callback called after every response or failed request.
+    ///
+    /// This is the place to record metrics, such as the number of
+    /// requests, their latency or the remaining rate-limit budget.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - The callback to call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::Client;
+    /// let client = Client::builder()
+    ///     .on_response(|info| {
+    ///         eprintln!(
+    ///             "{} took {:?} ({:?})",
+    ///             info.operation, info.duration, info.status
+    ///         )
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn on_response<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&ResponseInfo) + Send + Sync + 'static,
+    {
+        self.client.hooks.on_response = Some(Arc::new(callback));
+        self
+    }
+
+    /// Replaces the GraphQL document sent for an operation.
+    ///
+    /// This lets a query select fields AniList added before this crate
+    /// did. The extra fields flow into the models having them and are
+    /// ignored otherwise. The variables, errors, retries, cache and hooks
+    /// of the operation stay the same; only its default document is
+    /// replaced, not the documents sent through [`Client::query_raw`].
+    ///
+    /// Overrides are at your own risk: the document must still take the
+    /// variables of the operation and select the fields its models
+    /// require, which is only partly checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The operation to override.
+    /// * `query` - The replacing document.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidQuery`](crate::Error::InvalidQuery) if
+    /// the document doesn't define an operation of the same name, or
+    /// doesn't select its top-level field, e.g. `Media` for
+    /// [`Operation::GetAnime`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::{Client, Operation};
+    /// # fn f() -> rust_anilist::Result<()> {
+    /// let query = rust_anilist::queries::GET_STUDIO.replacen(
+    ///     "favourites",
+    ///     "favourites isFavouriteBlocked",
+    ///     1,
+    /// );
+    /// let client = Client::builder()
+    ///     .override_query(Operation::GetStudio, &query)?
+    ///     .build();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn override_query(mut self, operation: Operation, query: &str) -> Result<Self> {
+        operation.validate(query)?;
+        self.client.query_overrides.insert(operation, query);
+
+        Ok(self)
+    }
+
+    /// Creates the configured client.
+    pub fn build(self) -> Client {
+        self.client
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let client = ClientBuilder::new().build();
+
+        assert_eq!(client, Client::default());
+        assert_eq!(client.api_url.as_str(), "https://graphql.anilist.co/");
+        assert!(client.user_agent.starts_with("rust-anilist/"));
+    }
+
+    #[test]
+    fn test_build() {
+        let url = Url::parse("http://localhost:8080/graphql").unwrap();
+        let client = ClientBuilder::new()
+            .timeout(Duration::from_secs(5))
+            .user_agent("test-agent")
+            .api_url(url.clone())
+            .token("test_token")
+            .build();
+
+        assert_eq!(client.timeout, Duration::from_secs(5));
+        assert_eq!(client.user_agent, "test-agent");
+        assert_eq!(client.api_url, url);
+        assert_eq!(client.api_token, Some("test_token".to_string()));
+    }
+
+    #[test]
+    fn test_with_http_client() {
+        let client = ClientBuilder::new()
+            .with_http_client(reqwest::Client::new())
+            .build();
+
+        assert!(client.http_client.is_some());
+        assert!(Client::default().http_client.is_none());
+    }
+
+    #[test]
+    fn test_cache() {
+        let client = ClientBuilder::new()
+            .cache_store(crate::MemoryCache::new())
+            .cache(Duration::from_secs(60))
+            .build();
+
+        assert_eq!(client.cache.unwrap().ttl, Duration::from_secs(60));
+        assert!(Client::default().cache.is_none());
+    }
+}
@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `CacheStore` trait and its in-memory
+//! implementation.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use super::time::Instant;
+use super::transport::operation_name;
+
+/// A cached value and the instant it expires.
+type Entry = (Vec<u8>, Instant);
+
+/// Represents a storage for cached API responses.
+///
+/// Keys start with the name of the GraphQL operation followed by a
+/// colon, e.g. `GetAnime:5f0c...`, so the entries of an operation can
+/// be dropped with [`CacheStore::invalidate`]. Values are the raw JSON
+/// responses.
+///
+/// Implementations are expected to be best-effort: a store failing to
+/// read or write an entry should behave as if the entry was missing
+/// rather than fail the request.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::{Duration, Instant};
+/// # use rust_anilist::{CacheStore, Client};
+/// struct NoCache;
+///
+/// impl CacheStore for NoCache {
+///     fn get(&self, _key: &str) -> Option<(Vec<u8>, Instant)> {
+///         None
+///     }
+///
+///     fn put(&self, _key: &str, _value: Vec<u8>, _ttl: Duration) {}
+///
+///     fn invalidate(&self, _prefix: &str) {}
+/// }
+///
+/// let client = Client::builder().cache_store(NoCache).build();
+/// ```
+pub trait CacheStore: Send + Sync {
+    /// Returns the value stored under the key and the instant it expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the entry.
+    fn get(&self, key: &str) -> Option<(Vec<u8>, Instant)>;
+
+    /// Stores a value under the key for the given duration.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the entry.
+    /// * `value` - The value to store.
+    /// * `ttl` - How long the value stays valid.
+    fn put(&self, key: &str, value: Vec<u8>, ttl: Duration);
+
+    /// Removes every entry whose key starts with the prefix.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix of the keys to remove.
+    fn invalidate(&self, prefix: &str);
+}
+
+/// A cache store keeping the entries in memory.
+///
+/// This is the store used when caching is enabled without a custom
+/// store. Clones of a store share the same entries.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryCache {
+    /// The stored values and the instants they expire, keyed by key.
+    entries: Arc<Mutex<HashMap<String, Entry>>>,
+}
+
+impl MemoryCache {
+    /// Creates a new empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CacheStore for MemoryCache {
+    fn get(&self, key: &str) -> Option<Entry> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+
+        match entries.get(key) {
+            Some((_, expires_at)) if *expires_at <= Instant::now() => {
+                entries.remove(key);
+                None
+            }
+            entry => entry.cloned(),
+        }
+    }
+
+    fn put(&self, key: &str, value: Vec<u8>, ttl: Duration) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key.to_string(), (value, Instant::now() + ttl));
+    }
+
+    fn invalidate(&self, prefix: &str) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|key, _| !key.starts_with(prefix));
+    }
+}
+
+/// The response cache of a client.
+#[derive(Clone)]
+pub(crate) struct Cache {
+    /// The store holding the responses.
+    pub store: Arc<dyn CacheStore>,
+    /// How long the responses stay valid.
+    pub ttl: Duration,
+}
+
+impl Cache {
+    /// The time responses stay valid when no duration is configured.
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(300);
+
+    /// Returns the cached response stored under the key, if still valid.
+    pub fn get(&self, key: &str) -> Option<serde_json::Value> {
+        let (value, expires_at) = self.store.get(key)?;
+
+        if expires_at <= Instant::now() {
+            return None;
+        }
+
+        serde_json::from_slice(&value).ok()
+    }
+
+    /// Stores a response under the key.
+    pub fn put(&self, key: &str, response: &serde_json::Value) {
+        if let Ok(value) = serde_json::to_vec(response) {
+            self.store.put(key, value, self.ttl);
+        }
+    }
+}
+
+impl Default for Cache {
+    fn default() -> Self {
+        Self {
+            store: Arc::new(MemoryCache::new()),
+            ttl: Self::DEFAULT_TTL,
+        }
+    }
+}
+
+impl std::fmt::Debug for Cache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cache").field("ttl", &self.ttl).finish()
+    }
+}
+
+/// Returns the cache key of a request.
+///
+/// The key is the name of the operation followed by a hash of the
+/// document, the token and the variables, so responses are never shared
+/// between two users.
+///
+/// # Arguments
+///
+/// * `query` - The GraphQL document.
+/// * `token` - The API token, if any.
+/// * `variables` - The variables of the request.
+pub(crate) fn key(query: &str, token: Option<&str>, variables: &serde_json::Value) -> String {
+    let hash = hash(&[query, token.unwrap_or_default(), &variables.to_string()]);
+
+    format!("{}:{hash:016x}", operation_name(query).unwrap_or("query"))
+}
+
+/// Returns a hash of the parts, stable across builds so persisted
+/// entries stay valid.
+///
+/// # Arguments
+///
+/// * `parts` - The parts to hash.
+pub(crate) fn hash(parts: &[&str]) -> u64 {
+    // FNV-1a.
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in parts.join("\0").bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_cache() {
+        let store = MemoryCache::new();
+        store.put("GetAnime:1", b"anime".to_vec(), Duration::from_secs(60));
+        store.put("GetManga:1", b"manga".to_vec(), Duration::from_secs(60));
+        store.put("GetManga:2", b"manga".to_vec(), Duration::ZERO);
+
+        assert_eq!(store.get("GetAnime:1").unwrap().0, b"anime");
+        assert!(store.get("GetManga:2").is_none());
+
+        store.invalidate("GetAnime:");
+
+        assert!(store.get("GetAnime:1").is_none());
+        assert!(store.get("GetManga:1").is_some());
+    }
+
+    #[test]
+    fn test_key() {
+        let query = "query GetAnime($id: Int) { Media(id: $id) { id } }";
+        let variables = serde_json::json!({ "id": 1 });
+        let key = key(query, None, &variables);
+
+        assert!(key.starts_with("GetAnime:"));
+        assert_eq!(key, super::key(query, None, &variables));
+        assert_ne!(key, super::key(query, Some("token"), &variables));
+        assert_ne!(
+            key,
+            super::key(query, None, &serde_json::json!({ "id": 2 }))
+        );
+        assert!(super::key("{ Viewer { id } }", None, &variables).starts_with("query:"));
+    }
+
+    #[test]
+    fn test_cache() {
+        let cache = Cache::default();
+        let response = serde_json::json!({ "data": { "Media": { "id": 1 } } });
+        cache.put("GetAnime:1", &response);
+
+        assert_eq!(cache.get("GetAnime:1"), Some(response));
+        assert_eq!(cache.get("GetAnime:2"), None);
+    }
+}
@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `AnimeFields` struct.
+
+use serde_json::{Map, Value};
+
+/// Represents the optional blocks requested by [`Client::get_anime_with`].
+///
+/// Every block is requested by default. Leaving out the blocks you don't
+/// need shrinks the response and the complexity of the query, which
+/// AniList limits. Blocks not requested are `None` in the returned
+/// [`Anime`](crate::models::Anime).
+///
+/// [`Client::get_anime_with`]: crate::Client::get_anime_with
+///
+/// # Example
+///
+/// ```
+/// # use rust_anilist::AnimeFields;
+/// let fields = AnimeFields::basic().studios(true);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct AnimeFields {
+    /// Whether to request the characters.
+    pub(crate) characters: bool,
+    /// Whether to request the staff.
+    pub(crate) staff: bool,
+    /// Whether to request the studios.
+    pub(crate) studios: bool,
+    /// Whether to request the relations.
+    pub(crate) relations: bool,
+    /// Whether to request the tags.
+    pub(crate) tags: bool,
+    /// Whether to request the rankings.
+    pub(crate) rankings: bool,
+    /// Whether to request the stats.
+    pub(crate) stats: bool,
+    /// Whether to request the external links and streaming episodes.
+    pub(crate) links: bool,
+}
+
+impl AnimeFields {
+    /// Creates a selection with every block requested.
+    pub fn all() -> Self {
+        Self {
+            characters: true,
+            staff: true,
+            studios: true,
+            relations: true,
+            tags: true,
+            rankings: true,
+            stats: true,
+            links: true,
+        }
+    }
+
+    /// Creates a selection with no optional block requested.
+    ///
+    /// The anime still holds its title, cover, format, status, dates,
+    /// scores and other scalar fields.
+    pub fn basic() -> Self {
+        Self {
+            characters: false,
+            staff: false,
+            studios: false,
+            relations: false,
+            tags: false,
+            rankings: false,
+            stats: false,
+            links: false,
+        }
+    }
+
+    /// Sets whether the characters are requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether to request the block.
+    pub fn characters(mut self, include: bool) -> Self {
+        self.characters = include;
+        self
+    }
+
+    /// Sets whether the staff is requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether to request the block.
+    pub fn staff(mut self, include: bool) -> Self {
+        self.staff = include;
+        self
+    }
+
+    /// Sets whether the studios are requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether to request the block.
+    pub fn studios(mut self, include: bool) -> Self {
+        self.studios = include;
+        self
+    }
+
+    /// Sets whether the relations are requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether to request the block.
+    pub fn relations(mut self, include: bool) -> Self {
+        self.relations = include;
+        self
+    }
+
+    /// Sets whether the tags are requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether to request the block.
+    pub fn tags(mut self, include: bool) -> Self {
+        self.tags = include;
+        self
+    }
+
+    /// Sets whether the rankings are requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether to request the block.
+    pub fn rankings(mut self, include: bool) -> Self {
+        self.rankings = include;
+        self
+    }
+
+    /// Sets whether the score and status distributions are requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether to request the block.
+    pub fn stats(mut self, include: bool) -> Self {
+        self.stats = include;
+        self
+    }
+
+    /// Sets whether the external links and streaming episodes are requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether to request the block.
+    pub fn links(mut self, include: bool) -> Self {
+        self.links = include;
+        self
+    }
+
+    /// Returns whether every block is requested.
+    pub(crate) fn is_all(&self) -> bool {
+        *self == Self::all()
+    }
+
+    /// Adds the `@include` variables of the query to `variables`.
+    pub(crate) fn apply(&self, variables: &mut Map<String, Value>) {
+        let blocks = [
+            ("withCharacters", self.characters),
+            ("withStaff", self.staff),
+            ("withStudios", self.studios),
+            ("withRelations", self.relations),
+            ("withTags", self.tags),
+            ("withRankings", self.rankings),
+            ("withStats", self.stats),
+            ("withLinks", self.links),
+        ];
+
+        for (name, include) in blocks {
+            variables.insert(name.to_string(), Value::Bool(include));
+        }
+    }
+}
+
+impl Default for AnimeFields {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(AnimeFields::default(), AnimeFields::all());
+        assert!(AnimeFields::default().is_all());
+        assert!(!AnimeFields::basic().is_all());
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut variables = Map::new();
+        AnimeFields::basic().studios(true).apply(&mut variables);
+
+        assert_eq!(variables["withStudios"], Value::Bool(true));
+        assert_eq!(variables["withCharacters"], Value::Bool(false));
+        assert_eq!(variables.len(), 8);
+    }
+}
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `FileCache` struct.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use super::time::{Instant, SystemTime, UNIX_EPOCH};
+use super::CacheStore;
+
+/// A cache store keeping every entry in a file of a directory.
+///
+/// Entries survive restarts, so a program can answer recently made
+/// requests while offline. Read and write failures are ignored and
+/// behave as missing entries.
+///
+/// This type is only available with the `file-cache` feature.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use rust_anilist::{Client, FileCache};
+/// let client = Client::builder()
+///     .cache_store(FileCache::new("/tmp/anilist"))
+///     .cache(Duration::from_secs(3600))
+///     .build();
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FileCache {
+    /// The directory holding the entries.
+    dir: PathBuf,
+}
+
+impl FileCache {
+    /// Creates a store keeping its entries in the given directory.
+    ///
+    /// The directory is created on the first write.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - The directory holding the entries.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Returns the path of the file holding the entry of the key.
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(file_name(key))
+    }
+}
+
+impl CacheStore for FileCache {
+    fn get(&self, key: &str) -> Option<(Vec<u8>, Instant)> {
+        let path = self.path(key);
+        let content = fs::read(&path).ok()?;
+        let (expires_at, value) = content.split_first_chunk::<8>()?;
+        let expires_at = UNIX_EPOCH + Duration::from_secs(u64::from_le_bytes(*expires_at));
+
+        match expires_at.duration_since(SystemTime::now()) {
+            Ok(remaining) => Some((value.to_vec(), Instant::now() + remaining)),
+            Err(_) => {
+                let _ = fs::remove_file(path);
+                None
+            }
+        }
+    }
+
+    fn put(&self, key: &str, value: Vec<u8>, ttl: Duration) {
+        let expires_at = (SystemTime::now() + ttl)
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let mut content = expires_at.to_le_bytes().to_vec();
+        content.extend(value);
+
+        // Written to a temporary file first, so readers never see a
+        // partially written entry.
+        let path = self.path(key);
+        let temp = path.with_extension("tmp");
+        let _ = fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(&temp, content))
+            .and_then(|_| fs::rename(&temp, &path));
+    }
+
+    fn invalidate(&self, prefix: &str) {
+        let prefix = file_name(prefix);
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return;
+        };
+
+        for entry in entries.flatten() {
+            if entry.file_name().to_string_lossy().starts_with(&prefix) {
+                let _ = fs::remove_file(entry.path());
+            }
+        }
+    }
+}
+
+/// Returns a file name safe on every platform for the key.
+///
+/// Characters other than ASCII letters, digits, `-` and `_` are
+/// percent-encoded, which keeps the prefixes of the keys.
+///
+/// # Arguments
+///
+/// * `key` - The key of the entry.
+fn file_name(key: &str) -> String {
+    key.bytes()
+        .map(|byte| match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' => (byte as char).to_string(),
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(file_name("GetAnime:00ff"), "GetAnime%3A00ff");
+        assert_eq!(file_name("a/b.c"), "a%2Fb%2Ec");
+    }
+
+    #[test]
+    fn test_file_cache() {
+        let dir = std::env::temp_dir().join(format!("rust-anilist-{}", std::process::id()));
+        let store = FileCache::new(&dir);
+        store.put("GetAnime:1", b"anime".to_vec(), Duration::from_secs(60));
+        store.put("GetManga:1", b"manga".to_vec(), Duration::from_secs(60));
+
+        let (value, expires_at) = store.get("GetAnime:1").unwrap();
+
+        assert_eq!(value, b"anime");
+        assert!(expires_at > Instant::now());
+        assert!(store.get("GetAnime:2").is_none());
+
+        store.invalidate("GetAnime:");
+
+        assert!(store.get("GetAnime:1").is_none());
+        assert!(store.get("GetManga:1").is_some());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the request and response hooks of the client.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::RateLimitStatus;
+use crate::ErrorKind;
+
+/// The callback called before every request.
+type RequestCallback = Arc<dyn Fn(&RequestInfo) + Send + Sync>;
+
+/// The callback called after every response.
+type ResponseCallback = Arc<dyn Fn(&ResponseInfo) + Send + Sync>;
+
+/// Represents a request about to be sent by the client.
+///
+/// Retried requests are reported once per attempt.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestInfo {
+    /// The name of the GraphQL operation, e.g. `GetAnime`.
+    pub operation: String,
+    /// The number of the attempt, starting at `1`.
+    pub attempt: u32,
+    /// Whether the request is a mutation.
+    pub is_mutation: bool,
+}
+
+/// Represents the outcome of a request sent by the client.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseInfo {
+    /// The name of the GraphQL operation, e.g. `GetAnime`.
+    pub operation: String,
+    /// The number of the attempt, starting at `1`.
+    pub attempt: u32,
+    /// The time taken by the request.
+    pub duration: Duration,
+    /// The HTTP status of the response, if one was received.
+    pub status: Option<u16>,
+    /// The rate-limit status reported by the response, if any.
+    pub rate_limit: Option<RateLimitStatus>,
+    /// The kind of error the request failed with, if any.
+    pub error: Option<ErrorKind>,
+    /// Whether the response holds GraphQL errors.
+    pub has_errors: bool,
+}
+
+/// The callbacks called around every request.
+#[derive(Clone, Default)]
+pub(crate) struct Hooks {
+    /// The callback called before every request.
+    pub on_request: Option<RequestCallback>,
+    /// The callback called after every response.
+    pub on_response: Option<ResponseCallback>,
+}
+
+impl Hooks {
+    /// Calls the request callback, if any.
+    pub fn request(&self, info: impl FnOnce() -> RequestInfo) {
+        if let Some(on_request) = &self.on_request {
+            on_request(&info());
+        }
+    }
+
+    /// Calls the response callback, if any.
+    pub fn response(&self, info: impl FnOnce() -> ResponseInfo) {
+        if let Some(on_response) = &self.on_response {
+            on_response(&info());
+        }
+    }
+}
+
+impl std::fmt::Debug for Hooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Hooks")
+            .field("on_request", &self.on_request.is_some())
+            .field("on_response", &self.on_response.is_some())
+            .finish()
+    }
+}
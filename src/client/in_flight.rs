@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `InFlight` struct, which coalesces identical
+//! concurrent requests.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::watch;
+
+use crate::Result;
+
+/// The channel through which the outcome of a request is shared.
+type Outcome = watch::Receiver<Option<Result<serde_json::Value>>>;
+
+/// The requests being sent, keyed by cache key.
+///
+/// Clones share the same requests.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct InFlight {
+    /// The outcomes of the requests being sent, keyed by cache key.
+    requests: Arc<Mutex<HashMap<String, Outcome>>>,
+}
+
+/// The role of a caller in the sending of a request.
+pub(crate) enum Slot {
+    /// The caller sends the request and shares its outcome.
+    Leader(Leader),
+    /// The caller waits for the outcome of the request sent by another one.
+    Follower(Outcome),
+}
+
+impl InFlight {
+    /// Joins the sending of the request with the given key.
+    ///
+    /// The first caller becomes the leader of the request, every caller
+    /// joining before the leader finishes becomes a follower.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The cache key of the request.
+    pub fn join(&self, key: &str) -> Slot {
+        let mut requests = self.requests.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(outcome) = requests.get(key) {
+            return Slot::Follower(outcome.clone());
+        }
+
+        let (sender, outcome) = watch::channel(None);
+        requests.insert(key.to_string(), outcome);
+
+        Slot::Leader(Leader {
+            in_flight: self.clone(),
+            key: key.to_string(),
+            sender,
+        })
+    }
+}
+
+/// The caller sending a request on behalf of the others.
+///
+/// If dropped before finishing, e.g. because its future was cancelled,
+/// the request is forgotten and the followers send it again.
+pub(crate) struct Leader {
+    /// The requests being sent.
+    in_flight: InFlight,
+    /// The cache key of the request.
+    key: String,
+    /// The channel through which the outcome is shared.
+    sender: watch::Sender<Option<Result<serde_json::Value>>>,
+}
+
+impl Leader {
+    /// Shares the outcome of the request with the followers.
+    ///
+    /// The request is forgotten first, so any later caller sends it
+    /// again instead of reusing this outcome.
+    ///
+    /// # Arguments
+    ///
+    /// * `outcome` - The outcome of the request.
+    pub fn finish(self, outcome: &Result<serde_json::Value>) {
+        self.forget();
+        self.sender.send_replace(Some(share(outcome)));
+    }
+
+    /// Removes the request from the requests being sent.
+    fn forget(&self) {
+        let mut requests = self
+            .in_flight
+            .requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+
+        if requests
+            .get(&self.key)
+            .is_some_and(|outcome| outcome.same_channel(&self.sender.subscribe()))
+        {
+            requests.remove(&self.key);
+        }
+    }
+}
+
+impl Drop for Leader {
+    fn drop(&mut self) {
+        self.forget();
+    }
+}
+
+/// Waits for the outcome shared by the leader of a request.
+///
+/// Returns `None` if the leader was dropped before finishing.
+///
+/// # Arguments
+///
+/// * `outcome` - The channel through which the outcome is shared.
+pub(crate) async fn wait(mut outcome: Outcome) -> Option<Result<serde_json::Value>> {
+    let outcome = outcome.wait_for(Option::is_some).await.ok()?;
+
+    outcome.as_ref().map(share)
+}
+
+/// Returns a copy of an outcome, to deliver it to another caller.
+///
+/// # Arguments
+///
+/// * `outcome` - The outcome to copy.
+fn share(outcome: &Result<serde_json::Value>) -> Result<serde_json::Value> {
+    match outcome {
+        Ok(response) => Ok(response.clone()),
+        Err(error) => Err(error.duplicate()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[tokio::test]
+    async fn test_join() {
+        let in_flight = InFlight::default();
+
+        let Slot::Leader(leader) = in_flight.join("GetAnime:1") else {
+            panic!("the first caller should lead");
+        };
+        let Slot::Follower(outcome) = in_flight.join("GetAnime:1") else {
+            panic!("the second caller should follow");
+        };
+
+        leader.finish(&Err(Error::HttpError(502)));
+
+        assert!(matches!(
+            wait(outcome).await,
+            Some(Err(Error::HttpError(502)))
+        ));
+        assert!(matches!(in_flight.join("GetAnime:1"), Slot::Leader(_)));
+    }
+
+    #[tokio::test]
+    async fn test_dropped_leader() {
+        let in_flight = InFlight::default();
+
+        let leader = in_flight.join("GetAnime:1");
+        let Slot::Follower(outcome) = in_flight.join("GetAnime:1") else {
+            panic!("the second caller should follow");
+        };
+        drop(leader);
+
+        assert!(wait(outcome).await.is_none());
+        assert!(matches!(in_flight.join("GetAnime:1"), Slot::Leader(_)));
+    }
+}
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `ConcurrencyLimit` struct, which caps the
+//! number of requests in flight.
+
+use std::sync::Arc;
+
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+/// The maximum number of requests sent at the same time.
+///
+/// Clones share the same permits.
+#[derive(Clone, Debug)]
+pub(crate) struct ConcurrencyLimit {
+    /// The maximum number of requests in flight.
+    max: usize,
+    /// The permits of the requests in flight.
+    semaphore: Arc<Semaphore>,
+}
+
+impl ConcurrencyLimit {
+    /// Creates a limit allowing `max` requests in flight.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - The maximum number of requests in flight, at least 1.
+    pub fn new(max: usize) -> Self {
+        let max = max.clamp(1, Semaphore::MAX_PERMITS);
+
+        Self {
+            max,
+            semaphore: Arc::new(Semaphore::new(max)),
+        }
+    }
+
+    /// Waits until a request can be sent.
+    ///
+    /// The request is counted as in flight until the permit is dropped.
+    pub async fn acquire(&self) -> SemaphorePermit<'_> {
+        self.semaphore
+            .acquire()
+            .await
+            .expect("the semaphore is never closed")
+    }
+}
+
+impl PartialEq for ConcurrencyLimit {
+    fn eq(&self, other: &Self) -> bool {
+        self.max == other.max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_acquire() {
+        let limit = ConcurrencyLimit::new(2);
+        let clone = limit.clone();
+
+        let first = limit.acquire().await;
+        let _second = clone.acquire().await;
+
+        assert_eq!(limit.semaphore.available_permits(), 0);
+
+        drop(first);
+
+        assert_eq!(clone.semaphore.available_permits(), 1);
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(ConcurrencyLimit::new(0).max, 1);
+        assert_eq!(ConcurrencyLimit::new(8), ConcurrencyLimit::new(8));
+    }
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `MockTransport` struct.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use super::transport::operation_name;
+use super::{Transport, TransportFuture};
+use crate::Error;
+
+/// A transport answering requests with canned responses.
+///
+/// Responses are keyed by the name of the GraphQL operation, such as
+/// `GetAnime` or `SearchManga`. Clones of a mock share the same
+/// responses and recorded requests, so a handle can be kept to inspect
+/// the requests after giving the mock to a client.
+///
+/// This type is only available with the `test-utils` feature.
+///
+/// # Example
+///
+/// ```
+/// # use rust_anilist::{Client, MockTransport};
+/// # async fn f() -> rust_anilist::Result<()> {
+/// let mock = MockTransport::new().with_response(
+///     "GetAnime",
+///     serde_json::json!({ "data": { "Media": { "id": 1 } } }),
+/// );
+/// let client = Client::with_transport(mock.clone());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MockTransport {
+    /// The canned responses, keyed by operation name.
+    responses: Arc<Mutex<HashMap<String, serde_json::Value>>>,
+    /// The requests received so far.
+    requests: Arc<Mutex<Vec<serde_json::Value>>>,
+}
+
+impl MockTransport {
+    /// Creates a new mock without any response.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the response returned for the given operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The name of the GraphQL operation.
+    /// * `response` - The GraphQL response, holding the `data` object.
+    pub fn with_response(self, operation: &str, response: serde_json::Value) -> Self {
+        self.responses
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(operation.to_string(), response);
+        self
+    }
+
+    /// Returns the requests received so far.
+    pub fn requests(&self) -> Vec<serde_json::Value> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+impl Transport for MockTransport {
+    fn execute(&self, body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let operation = body["query"]
+                .as_str()
+                .and_then(operation_name)
+                .unwrap_or_default()
+                .to_string();
+
+            self.requests
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(body);
+
+            self.responses
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .get(&operation)
+                .cloned()
+                .ok_or_else(|| Error::ApiError(format!("no mock response for `{operation}`")))
+        })
+    }
+}
@@ -0,0 +1,3817 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Client` struct and its related types.
+
+mod builder;
+mod cache;
+mod fields;
+#[cfg(feature = "file-cache")]
+mod file_cache;
+mod hooks;
+mod in_flight;
+mod limit;
+#[cfg(feature = "test-utils")]
+mod mock;
+mod overrides;
+mod rate_limit;
+mod retry;
+mod time;
+#[cfg(feature = "tracing")]
+mod trace;
+mod transport;
+
+use serde::{de::DeserializeOwned, Deserialize};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use chrono::{FixedOffset, NaiveDate};
+use futures_util::stream::{self, StreamExt};
+use reqwest::Url;
+
+pub use builder::ClientBuilder;
+use cache::Cache;
+pub use cache::{CacheStore, MemoryCache};
+pub use fields::AnimeFields;
+#[cfg(feature = "file-cache")]
+pub use file_cache::FileCache;
+use hooks::Hooks;
+pub use hooks::{RequestInfo, ResponseInfo};
+use in_flight::{InFlight, Slot};
+use limit::ConcurrencyLimit;
+#[cfg(feature = "test-utils")]
+pub use mock::MockTransport;
+pub use overrides::Operation;
+use overrides::QueryOverrides;
+use rate_limit::RateLimitRetry;
+pub use rate_limit::RateLimitStatus;
+pub use retry::{RetryEvent, RetryPolicy};
+use time::Instant;
+use transport::operation_name;
+pub use transport::{Transport, TransportFuture};
+
+use crate::{
+    import::{
+        ConflictPolicy, ImportOptions, ImportOutcome, ImportProgress, ImportReport, ImportedEntry,
+    },
+    models::{
+        character_edges, staff_edges, Activity, AiringEntry, AiringEvent, AiringSchedule, Anime,
+        AnimeId, Character, CharacterId, CharacterMedia, CharacterMediaEdge, CharacterMediaFilter,
+        Cover, Favourites, Format, Genre, Image, ListComparison, Manga, MangaId,
+        MediaListCollection, MediaListEntry, MediaPreview, MediaSearchOptions, MediaType,
+        Notification, Page, PageInfo, PageRequest, ParseItems, Person, PersonId, Relation,
+        SearchResults, Status, Studio, StudioId, Title, TitleLanguage, User, UserId,
+    },
+    queries, Error, Result,
+};
+
+/// The default URL of the AniList GraphQL endpoint.
+const API_URL: &str = "https://graphql.anilist.co";
+
+/// The default `User-Agent` sent with every request.
+const USER_AGENT: &str = concat!("rust-anilist/", env!("CARGO_PKG_VERSION"));
+
+/// The maximum number of aliased fields sent in a single batched query.
+const BATCH_SIZE: usize = 50;
+/// The maximum number of animes [`Client::get_relation_chain`] fetches
+/// before and after the given anime.
+const RELATION_CHAIN_LIMIT: usize = 50;
+/// The maximum number of aliased media previews sent in a single query.
+///
+/// A preview selects nested objects, so fewer of them fit under the
+/// query complexity limit of AniList than plain fields.
+const PREVIEW_BATCH_SIZE: usize = 25;
+/// The number of favourites fetched per page of each connection, the
+/// maximum AniList allows.
+const FAVOURITES_PER_PAGE: u16 = 25;
+/// The number of studios [`Client::get_studio_by_name`] picks the match
+/// from.
+const STUDIO_CANDIDATES: u16 = 10;
+/// The favourites connections of a user, named as in the API.
+const FAVOURITES_CONNECTIONS: [&str; 5] = ["anime", "manga", "characters", "staff", "studios"];
+
+/// The HTTP client shared by the clients not given one, built on their
+/// first request so creating a client (or a model) stays cheap.
+static DEFAULT_HTTP_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+/// Represents a client for interacting with an API.
+///
+/// The `Client` struct contains the necessary configuration for making
+/// requests to an API, including the API token and the timeout duration.
+///
+/// Clones of a client share the same rate-limit state, response cache
+/// and in-flight queries: identical queries sent concurrently are only
+/// sent once.
+///
+/// Use [`Client::builder`] to configure the endpoint, the user agent and
+/// the other options of a client.
+///
+/// A client and the models it returns are `Send + Sync + 'static`, and so
+/// are the futures of its methods once the client is moved into them,
+/// so requests can be spawned onto a multi-threaded runtime or made from
+/// a web handler. This doesn't hold on WebAssembly, where the futures of
+/// the browser are not `Send`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use rust_anilist::Client;
+/// # async fn f() {
+/// let client = Client::default();
+/// let handle = tokio::spawn(async move { client.get_anime(1).await });
+/// let anime = handle.await.unwrap();
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Client {
+    /// The API token to use for requests.
+    api_token: Option<String>,
+    /// The timeout for requests (in seconds).
+    timeout: Duration,
+    /// The URL of the GraphQL endpoint.
+    api_url: Url,
+    /// The `User-Agent` sent with every request.
+    user_agent: String,
+    /// The HTTP client supplied by the user, if any.
+    ///
+    /// Without one, the client shares a default HTTP client built on the
+    /// first request, see [`Client::http_client`].
+    http_client: Option<reqwest::Client>,
+    /// The transport used instead of HTTP, if any.
+    transport: Option<Arc<dyn Transport>>,
+    /// How rate-limited requests are retried.
+    rate_limit_retry: RateLimitRetry,
+    /// How failed requests are retried.
+    retry_policy: RetryPolicy,
+    /// The last rate-limit status reported by the API.
+    rate_limit_status: Arc<Mutex<Option<RateLimitStatus>>>,
+    /// The cache of the responses, if enabled.
+    cache: Option<Cache>,
+    /// The queries being sent, shared with concurrent identical calls.
+    in_flight: InFlight,
+    /// The maximum number of requests in flight, if limited.
+    concurrency_limit: Option<ConcurrencyLimit>,
+    /// The callbacks called around every request.
+    hooks: Hooks,
+    /// The language titles are displayed in, if set.
+    title_language: Option<TitleLanguage>,
+    /// The documents replacing the default ones of some operations.
+    query_overrides: QueryOverrides,
+    /// Whether the client refuses to send requests.
+    detached: bool,
+}
+
+impl Client {
+    /// Creates a builder to configure a new client.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use rust_anilist::Client;
+    /// let client = Client::builder()
+    ///     .timeout(Duration::from_secs(10))
+    ///     .user_agent("my-app/1.0")
+    ///     .token("your_api_key")
+    ///     .build();
+    /// ```
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    /// Creates a new client instance that sends its requests through the
+    /// specified transport instead of HTTP.
+    ///
+    /// This is mainly useful to run code that uses the client offline,
+    /// for example with [`MockTransport`] in tests.
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The transport to use.
+    pub fn with_transport<T: Transport + 'static>(transport: T) -> Self {
+        Self {
+            transport: Some(Arc::new(transport)),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a client that refuses to send requests.
+    ///
+    /// This is the client held by detached models, see
+    /// [`Anime::detach`](crate::models::Anime::detach). Every request made
+    /// through it fails with [`Error::Detached`](crate::Error::Detached)
+    /// without reaching the API or the cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::Client;
+    /// let client = Client::detached();
+    /// assert!(client.is_detached());
+    /// ```
+    pub fn detached() -> Self {
+        Self {
+            detached: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns whether the client refuses to send requests.
+    ///
+    /// See [`Client::detached`] for details.
+    pub fn is_detached(&self) -> bool {
+        self.detached
+    }
+
+    /// Creates a new client instance with the specified timeout duration.
+    ///
+    /// This method initializes a new `Client` instance with the provided
+    /// timeout duration.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The timeout duration for requests, in seconds.
+    pub fn with_timeout(duration: Duration) -> Self {
+        Self {
+            timeout: duration,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new client instance with the specified API token.
+    ///
+    /// This method initializes a new `Client` instance with the provided
+    /// API token and a default timeout duration of 20 seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - A string slice that holds the API token.
+    pub fn with_token(token: &str) -> Self {
+        Self {
+            api_token: Some(token.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the timeout duration for the client.
+    ///
+    /// This method allows you to set the timeout duration for the client.
+    /// The timeout duration determines how long the client will wait for
+    /// a response before timing out.
+    ///
+    /// Clones of a client share their state, so setting the timeout of a
+    /// clone overrides it for some requests only, e.g. the slow ones,
+    /// without giving up the rate-limit state and the cache of the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The timeout duration.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let studio = client
+    ///     .clone()
+    ///     .timeout(Duration::from_secs(60))
+    ///     .get_studio(14)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout = duration;
+        self
+    }
+
+    /// Sets the API token for the client.
+    ///
+    /// This method allows you to set the API token for the client, which
+    /// will be used for authenticating API requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - A string slice that holds the API token.
+    pub fn token(mut self, token: &str) -> Self {
+        self.api_token = Some(token.to_string());
+        self
+    }
+
+    /// Enables retrying of rate-limited requests.
+    ///
+    /// When the API answers with `429 Too Many Requests`, the client
+    /// sleeps for the duration given by the `Retry-After` header and
+    /// tries again. If the requested wait is longer than `max_wait`, or
+    /// `max_attempts` is reached, [`Error::RateLimited`] is returned.
+    ///
+    /// Retrying is disabled by default, so rate-limited requests fail
+    /// immediately.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_attempts` - The maximum number of attempts, including the first one.
+    /// * `max_wait` - The longest time to wait before a retry.
+    pub fn retry_on_rate_limit(mut self, max_attempts: u32, max_wait: Duration) -> Self {
+        self.rate_limit_retry = RateLimitRetry {
+            max_attempts: max_attempts.max(1),
+            max_wait,
+        };
+        self
+    }
+
+    /// Sets the policy used to retry failed requests.
+    ///
+    /// The policy is applied to every query and mutation made by the
+    /// client. Failed requests are not retried by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The retry policy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::{Client, RetryPolicy};
+    /// let client = Client::default().retry_policy(RetryPolicy::default());
+    /// ```
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Returns the last rate-limit status reported by the API.
+    ///
+    /// Returns `None` if no request has been made yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let anime = client.get_anime(1).await?;
+    /// if let Some(status) = client.rate_limit_status() {
+    ///     println!("{:?} requests remaining", status.remaining);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        *self
+            .rate_limit_status
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns the language titles are displayed in, if set.
+    ///
+    /// See [`ClientBuilder::title_language`] for details.
+    pub fn title_language(&self) -> Option<TitleLanguage> {
+        self.title_language
+    }
+
+    /// Removes the cached responses of the operations starting with the prefix.
+    ///
+    /// Operations are named after the method making them, e.g. `GetAnime`
+    /// or `SearchManga`. An empty prefix clears the whole cache. This
+    /// does nothing when caching is disabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix of the operation names.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use rust_anilist::Client;
+    /// let client = Client::builder().cache(Duration::from_secs(60)).build();
+    /// client.invalidate_cache("GetAnime");
+    /// ```
+    pub fn invalidate_cache(&self, prefix: &str) {
+        if let Some(cache) = &self.cache {
+            cache.store.invalidate(prefix);
+        }
+    }
+
+    /// Returns an unloaded anime with the given ID.
+    ///
+    /// This doesn't send any request: only the ID is set, the other
+    /// fields hold their default values until [`Anime::load_full`] is
+    /// called.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the anime.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let anime = client.anime(1).load_full().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn anime(&self, id: impl Into<AnimeId>) -> Anime {
+        Anime {
+            id: id.into(),
+            client: self.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns an unloaded manga with the given ID.
+    ///
+    /// This doesn't send any request: only the ID is set, the other
+    /// fields hold their default values until [`Manga::load_full`] is
+    /// called.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the manga.
+    pub fn manga(&self, id: impl Into<MangaId>) -> Manga {
+        Manga {
+            id: id.into(),
+            client: self.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns an unloaded character with the given ID.
+    ///
+    /// This doesn't send any request: only the ID is set, the other
+    /// fields hold their default values until [`Character::load_full`] is
+    /// called.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the character.
+    pub fn character(&self, id: impl Into<CharacterId>) -> Character {
+        Character {
+            id: id.into(),
+            client: self.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns an unloaded person with the given ID.
+    ///
+    /// This doesn't send any request: only the ID is set, the other
+    /// fields hold their default values until [`Person::load_full`] is
+    /// called.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the person.
+    pub fn person(&self, id: impl Into<PersonId>) -> Person {
+        Person {
+            id: id.into(),
+            client: self.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns an unloaded studio with the given ID.
+    ///
+    /// This doesn't send any request: only the ID is set, the other
+    /// fields hold their default values until [`Studio::load_full`] is
+    /// called.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the studio.
+    pub fn studio(&self, id: impl Into<StudioId>) -> Studio {
+        Studio {
+            id: id.into(),
+            client: self.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Get an anime by its ID or MAL ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the anime.
+    /// * `mal_id` - The MAL ID of the anime.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let anime = client.get_anime(1).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_anime(&self, id: impl Into<AnimeId>) -> Result<Anime> {
+        self.get_anime_with(id, AnimeFields::all()).await
+    }
+
+    /// Get an anime by its ID, requesting only the given blocks.
+    ///
+    /// Blocks not requested are `None` in the returned anime, which can
+    /// be completed later with [`Anime::load_full`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the anime.
+    /// * `fields` - The optional blocks to request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::AnimeFields;
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let anime = client.get_anime_with(1, AnimeFields::basic()).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_anime_with(
+        &self,
+        id: impl Into<AnimeId>,
+        fields: AnimeFields,
+    ) -> Result<Anime> {
+        let mut variables = serde_json::Map::new();
+        variables.insert("id".to_string(), id.into().get().into());
+        if !fields.is_all() {
+            fields.apply(&mut variables);
+        }
+
+        let data = self
+            .request(MediaType::Anime, Action::Get, variables.into())
+            .await?;
+
+        match serde_json::from_str::<Anime>(&data["data"]["Media"].to_string()) {
+            Ok(mut anime) => {
+                anime.title.language = self.title_language;
+                anime.set_client(self);
+                anime.fill_url();
+                anime.is_full_loaded = fields.is_all();
+
+                Ok(anime)
+            }
+            Err(e) => Err(crate::Error::ApiError(e.to_string())),
+        }
+    }
+
+    /// Get the animes of a franchise in watch order, following the
+    /// prequels and the sequels of an anime.
+    ///
+    /// The first anime prequel is followed back to the start of the
+    /// franchise, then the first anime sequel forward from the given
+    /// anime. An anime already in the chain is never visited twice, and
+    /// at most 50 animes are fetched each way.
+    ///
+    /// The animes hold their relations and scalar fields only; call
+    /// [`Anime::load_full`] on them for the rest.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of an anime of the franchise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// for (i, anime) in client.get_relation_chain(5081).await?.iter().enumerate() {
+    ///     println!("{}. {}", i + 1, anime.title.preferred());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_relation_chain(&self, id: impl Into<AnimeId>) -> Result<Vec<Anime>> {
+        let fields = AnimeFields::basic().relations(true);
+        let anime = self.get_anime_with(id, fields).await?;
+        let mut seen = HashSet::from([anime.id]);
+
+        let mut prequels = self
+            .follow_relations(&anime, Anime::prequels, &mut seen)
+            .await?;
+        let sequels = self
+            .follow_relations(&anime, Anime::sequels, &mut seen)
+            .await?;
+
+        prequels.reverse();
+        prequels.push(anime);
+        prequels.extend(sequels);
+
+        Ok(prequels)
+    }
+
+    /// Follows the first unseen anime of a kind of relation, from anime
+    /// to anime, for [`Client::get_relation_chain`].
+    ///
+    /// # Arguments
+    ///
+    /// * `anime` - The anime to start from, left out of the result.
+    /// * `relations` - The relations to follow, such as the sequels.
+    /// * `seen` - The IDs of the animes already in the chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails.
+    async fn follow_relations(
+        &self,
+        anime: &Anime,
+        relations: fn(&Anime) -> Vec<Relation>,
+        seen: &mut HashSet<AnimeId>,
+    ) -> Result<Vec<Anime>> {
+        let fields = AnimeFields::basic().relations(true);
+        let mut chain: Vec<Anime> = Vec::new();
+
+        while chain.len() < RELATION_CHAIN_LIMIT {
+            let current = chain.last().unwrap_or(anime);
+            let Some(id) = relations(current)
+                .iter()
+                .filter_map(Relation::anime_id)
+                .find(|id| !seen.contains(id))
+            else {
+                break;
+            };
+
+            seen.insert(id);
+            chain.push(self.get_anime_with(id, fields).await?);
+        }
+
+        Ok(chain)
+    }
+
+    /// Get a page of the characters of an anime, most favourited first.
+    ///
+    /// The characters hold their role in the anime and their voice
+    /// actors, like the ones loaded with the anime.
+    ///
+    /// See [`Anime::get_all_characters`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the anime.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of characters to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// for character in client.get_characters_of_anime(1, 1, 25).await? {
+    ///     println!("{}: {:?}", character.name, character.role);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_characters_of_anime(
+        &self,
+        id: impl Into<AnimeId>,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Character>> {
+        let query = queries::GET_ANIME_CHARACTERS;
+        let variables = serde_json::json!({ "id": id.into(), "page": page, "per_page": per_page });
+
+        self.get_page_at(query, variables, "/Media/characters", parse_character_edges)
+            .await
+    }
+
+    /// Get a page of the staff of an anime, by relevance.
+    ///
+    /// The people hold their role in the anime in
+    /// [`Person::staff_role`].
+    ///
+    /// See [`Anime::get_all_staff`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the anime.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of people to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// for person in client.get_staff_of_anime(1, 1, 25).await? {
+    ///     println!("{}: {:?}", person.name, person.staff_role);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_staff_of_anime(
+        &self,
+        id: impl Into<AnimeId>,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Person>> {
+        let query = queries::GET_ANIME_STAFF;
+        let variables = serde_json::json!({ "id": id.into(), "page": page, "per_page": per_page });
+
+        self.get_page_at(query, variables, "/Media/staff", parse_staff_edges)
+            .await
+    }
+
+    /// Get a manga by its ID or MAL ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the manga.
+    /// * `mal_id` - The MAL ID of the manga.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let manga = client.get_manga(1).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_manga(&self, id: impl Into<MangaId>) -> Result<Manga> {
+        let id: MangaId = id.into();
+        let data = self
+            .request(
+                MediaType::Manga,
+                Action::Get,
+                serde_json::json!({ "id": id }),
+            )
+            .await?;
+
+        match serde_json::from_str::<Manga>(&data["data"]["Media"].to_string()) {
+            Ok(mut manga) => {
+                manga.title.language = self.title_language;
+                manga.set_client(self);
+                manga.is_full_loaded = true;
+
+                Ok(manga)
+            }
+            Err(e) => Err(crate::Error::ApiError(e.to_string())),
+        }
+    }
+
+    /// Get a character by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the character.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let character = client.get_character(1).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_character(&self, id: impl Into<CharacterId>) -> Result<Character> {
+        let id: CharacterId = id.into();
+        let data = self
+            .request(
+                MediaType::Character,
+                Action::Get,
+                serde_json::json!({ "id": id }),
+            )
+            .await?;
+
+        match serde_json::from_str::<Character>(&data["data"]["Character"].to_string()) {
+            Ok(mut character) => {
+                character.client = self.clone();
+                character.is_full_loaded = true;
+                character.has_more_medias = data["data"]["Character"]["media"]["pageInfo"]
+                    ["hasNextPage"]
+                    .as_bool()
+                    .unwrap_or_default();
+                for media in character.medias.iter_mut().flatten() {
+                    attach_character_media(self, media);
+                }
+
+                Ok(character)
+            }
+            Err(e) => Err(crate::Error::ApiError(e.to_string())),
+        }
+    }
+
+    /// Get a character by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the character.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let character = client.get_char(1).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_char(&self, id: impl Into<CharacterId>) -> Result<Character> {
+        self.get_character(id).await
+    }
+
+    /// Get a page of the medias a character appears in, most popular
+    /// first.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the character.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of medias to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// for media in client.get_character_medias(1, 1, 25).await? {
+    ///     println!("{}: {:?}", media.title.romaji(), media.character_role);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_character_medias(
+        &self,
+        id: impl Into<CharacterId>,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<CharacterMedia>> {
+        let query = queries::GET_CHARACTER_MEDIAS;
+        let variables = serde_json::json!({ "id": id.into(), "page": page, "per_page": per_page });
+
+        self.get_page_at(query, variables, "/Character/media", parse_character_medias)
+            .await
+    }
+
+    /// Get a page of the medias a character appears in, in the order and
+    /// with the options of the filter.
+    ///
+    /// See [`Character::get_medias_filtered`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the character.
+    /// * `filter` - The options of the request.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of medias to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::models::{CharacterMediaFilter, MediaSort};
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let filter = CharacterMediaFilter::new().sort(MediaSort::StartDateDesc);
+    /// let newest = client.get_character_medias_filtered(1, filter, 1, 5).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_character_medias_filtered(
+        &self,
+        id: impl Into<CharacterId>,
+        filter: CharacterMediaFilter,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<CharacterMedia>> {
+        let query = queries::GET_CHARACTER_MEDIAS;
+        let mut variables = filter.variables();
+        variables["id"] = serde_json::json!(id.into());
+        variables["page"] = page.into();
+        variables["per_page"] = per_page.into();
+
+        self.get_page_at(query, variables, "/Character/media", parse_character_medias)
+            .await
+    }
+
+    /// Get a user by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let user = client.get_user(1).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user(&self, id: impl Into<UserId>) -> Result<User> {
+        let id: UserId = id.into();
+        let data = self
+            .request(
+                MediaType::User,
+                Action::Get,
+                serde_json::json!({ "id": id }),
+            )
+            .await?;
+
+        match serde_json::from_str::<User>(&data["data"]["User"].to_string()) {
+            Ok(user) => Ok(user),
+            Err(e) => Err(crate::Error::ApiError(e.to_string())),
+        }
+    }
+
+    /// Get a user by its name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let user = client.get_user_by_name("andrielfr").await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user_by_name<N: ToString>(&self, name: N) -> Result<User> {
+        let name = name.to_string();
+
+        let data = self
+            .request(
+                MediaType::User,
+                Action::Get,
+                serde_json::json!({ "name": name }),
+            )
+            .await?;
+
+        match serde_json::from_str::<User>(&data["data"]["User"].to_string()) {
+            Ok(mut user) => {
+                user.client = self.clone();
+                user.is_full_loaded = true;
+
+                Ok(user)
+            }
+            Err(e) => Err(crate::Error::ApiError(e.to_string())),
+        }
+    }
+
+    /// Get the favourites of a user.
+    ///
+    /// Every favourites connection is fetched in full, page by page; the
+    /// connections with no more pages are left out of the next requests.
+    /// The favourites are partially loaded and keep the order the user
+    /// gave them.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user doesn't exist or a request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let favourites = client.get_user_favourites(1).await?;
+    /// for anime in &favourites.anime {
+    ///     println!("{}", anime.title.romaji());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user_favourites(&self, user_id: impl Into<UserId>) -> Result<Favourites> {
+        let query = queries::GET_USER_FAVOURITES;
+        let user_id: UserId = user_id.into();
+
+        let mut favourites = Favourites::default();
+        let mut remaining = FAVOURITES_CONNECTIONS.to_vec();
+        let mut page = 1;
+
+        while !remaining.is_empty() {
+            let mut variables = serde_json::json!({
+                "id": user_id,
+                "page": page,
+                "per_page": FAVOURITES_PER_PAGE,
+            });
+            for connection in FAVOURITES_CONNECTIONS {
+                variables[connection] = remaining.contains(&connection).into();
+            }
+
+            let data = self.query_raw_value(query, variables).await?;
+            let connections = &data["User"]["favourites"];
+
+            favourites
+                .anime
+                .extend(parse_animes(self, &connections["anime"]));
+            favourites
+                .manga
+                .extend(parse_mangas(self, &connections["manga"]));
+            favourites
+                .characters
+                .extend(parse_characters(self, &connections["characters"]));
+            favourites
+                .staff
+                .extend(parse_people(self, &connections["staff"]));
+            favourites
+                .studios
+                .extend(parse_studios(self, &connections["studios"]));
+
+            remaining.retain(|connection| {
+                connections[*connection]["pageInfo"]["hasNextPage"]
+                    .as_bool()
+                    .unwrap_or_default()
+            });
+            page += 1;
+        }
+
+        Ok(favourites)
+    }
+
+    /// Get a person by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the person.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let person = client.get_person(1).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_person(&self, id: impl Into<PersonId>) -> Result<Person> {
+        let id: PersonId = id.into();
+        let data = self
+            .request(
+                MediaType::Person,
+                Action::Get,
+                serde_json::json!({ "id": id }),
+            )
+            .await?;
+
+        match serde_json::from_str::<Person>(&data["data"]["Staff"].to_string()) {
+            Ok(mut person) => {
+                person.set_client(self);
+                person.is_full_loaded = true;
+
+                Ok(person)
+            }
+            Err(e) => Err(crate::Error::ApiError(e.to_string())),
+        }
+    }
+
+    /// Get a page of the characters voiced by a person, by relevance.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the person.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of characters to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// for character in client.get_person_characters(95011, 1, 25).await? {
+    ///     println!("{}", character.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_person_characters(
+        &self,
+        id: impl Into<PersonId>,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Character>> {
+        let query = queries::GET_PERSON_CHARACTERS;
+        let variables = serde_json::json!({ "id": id.into(), "page": page, "per_page": per_page });
+
+        self.get_page_at(query, variables, "/Staff/characters", parse_characters)
+            .await
+    }
+
+    /// Get a studio by its ID.
+    ///
+    /// The studio is returned with its medias, available through
+    /// [`Studio::get_medias`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the studio.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let studio = client.get_studio(14).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_studio(&self, id: impl Into<StudioId>) -> Result<Studio> {
+        let id: StudioId = id.into();
+        let data = self
+            .request(
+                MediaType::Studio,
+                Action::Get,
+                serde_json::json!({ "id": id }),
+            )
+            .await?;
+
+        match serde_json::from_str::<Studio>(&data["data"]["Studio"].to_string()) {
+            Ok(mut studio) => {
+                studio.client = self.clone();
+                studio.is_full_loaded = true;
+
+                Ok(studio)
+            }
+            Err(e) => Err(crate::Error::ApiError(e.to_string())),
+        }
+    }
+
+    /// Get a page of the animes produced by a studio, most popular
+    /// first.
+    ///
+    /// The [`PageInfo`] of the page holds the total number of animes of
+    /// the studio, so a single page is enough to count them.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the studio.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of animes to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let page = client.get_studio_animes(14, 1, 10).await?;
+    ///
+    /// if let Some(total) = page.page_info.total {
+    ///     println!("{total} productions");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_studio_animes(
+        &self,
+        id: impl Into<StudioId>,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<Anime>> {
+        let query = queries::GET_STUDIO_ANIMES;
+        let variables = serde_json::json!({ "id": id.into(), "page": page, "per_page": per_page });
+
+        self.get_page_at(query, variables, "/Studio/media", parse_animes)
+            .await
+    }
+
+    /// Search for animes.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the anime to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of animes to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let animes = client.search_anime("Naruto", 1, 10).await.unwrap();
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_anime(&self, title: &str, page: u16, limit: u16) -> Option<Page<Anime>> {
+        let query = Client::get_query(MediaType::Anime, Action::Search).ok()?;
+        let options = MediaSearchOptions::new().search(title);
+        let variables = search_variables(&options, page, limit);
+
+        self.get_page(query, variables, parse_animes).await.ok()
+    }
+
+    /// Search for animes having all the given genres, most popular first.
+    ///
+    /// # Arguments
+    ///
+    /// * `genres` - The genres the animes must have.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of animes to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::models::Genre;
+    /// # async fn f(client: rust_anilist::Client) {
+    /// let animes = client
+    ///     .search_anime_by_genres(&[Genre::SciFi, Genre::Mecha], 1, 10)
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn search_anime_by_genres(
+        &self,
+        genres: &[Genre],
+        page: u16,
+        limit: u16,
+    ) -> Option<Page<Anime>> {
+        let query = Client::get_query(MediaType::Anime, Action::Search).ok()?;
+        let options = MediaSearchOptions::new().genres(genres);
+        let variables = search_variables(&options, page, limit);
+
+        self.get_page(query, variables, parse_animes).await.ok()
+    }
+
+    /// Search for mangas.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the manga to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of mangas to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let mangas = client.search_manga("Naruto", 1, 10).await.unwrap();
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_manga(&self, title: &str, page: u16, limit: u16) -> Option<Page<Manga>> {
+        let query = Client::get_query(MediaType::Manga, Action::Search).ok()?;
+        let options = MediaSearchOptions::new().search(title);
+        let variables = search_variables(&options, page, limit);
+
+        self.get_page(query, variables, parse_mangas).await.ok()
+    }
+
+    /// Search for mangas having all the given genres, most popular first.
+    ///
+    /// # Arguments
+    ///
+    /// * `genres` - The genres the mangas must have.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of mangas to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::models::Genre;
+    /// # async fn f(client: rust_anilist::Client) {
+    /// let mangas = client
+    ///     .search_manga_by_genres(&[Genre::SciFi, Genre::Mecha], 1, 10)
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn search_manga_by_genres(
+        &self,
+        genres: &[Genre],
+        page: u16,
+        limit: u16,
+    ) -> Option<Page<Manga>> {
+        let query = Client::get_query(MediaType::Manga, Action::Search).ok()?;
+        let options = MediaSearchOptions::new().genres(genres);
+        let variables = search_variables(&options, page, limit);
+
+        self.get_page(query, variables, parse_mangas).await.ok()
+    }
+
+    /// Counts the medias matching the given filters, without fetching
+    /// them.
+    ///
+    /// The filters are the ones of the searches, so a count matches the
+    /// results of the search with the same options.
+    ///
+    /// AniList caps the total of some queries at 5000, in which case the
+    /// capped value is returned as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The filters of the medias.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidMediaType`] if the type of the medias is
+    /// neither anime nor manga, or an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::models::{Genre, MediaSearchOptions};
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let options = MediaSearchOptions::new().genres(&[Genre::Mecha]);
+    /// let total = client.count_media(options).await?;
+    ///
+    /// println!("{total} mecha animes");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn count_media(&self, options: MediaSearchOptions) -> Result<u32> {
+        let media_type = options
+            .media_type
+            .as_graphql()
+            .ok_or(Error::InvalidMediaType)?;
+        let mut variables = options.variables();
+        variables["type"] = media_type.into();
+
+        let data = self
+            .query_raw_value(queries::COUNT_MEDIA, variables)
+            .await?;
+        let page_info = PageInfo::deserialize(&data["Page"]["pageInfo"]).unwrap_or_default();
+
+        Ok(page_info.total.unwrap_or_default())
+    }
+
+    /// Search for users.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the user to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of users to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let users = client.search_user("andrielfr", 1, 10).await.unwrap();
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_user(&self, name: &str, page: u16, limit: u16) -> Option<Page<User>> {
+        let query = Client::get_query(MediaType::User, Action::Search).ok()?;
+        let variables = serde_json::json!({ "search": name, "page": page, "per_page": limit });
+
+        self.get_page(query, variables, parse_users).await.ok()
+    }
+
+    /// Search for studios.
+    ///
+    /// The studios are partially loaded, use [`Client::get_studio`] to
+    /// get their medias.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the studio to search.
+    /// * `page` - The page number to get.
+    /// * `limit` - The number of studios to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) {
+    /// let studios = client.search_studio("Pictures", 1, 10).await;
+    /// # }
+    /// ```
+    pub async fn search_studio(&self, name: &str, page: u16, limit: u16) -> Option<Page<Studio>> {
+        let query = Client::get_query(MediaType::Studio, Action::Search).ok()?;
+        let variables = serde_json::json!({ "search": name, "page": page, "per_page": limit });
+
+        self.get_page(query, variables, parse_studios).await.ok()
+    }
+
+    /// Get a studio by its name.
+    ///
+    /// The name is searched, and the studio whose name is the same
+    /// regardless of case is preferred, then the one whose name only
+    /// differs by punctuation or spacing, e.g. `"A1 Pictures"` for
+    /// `"A-1 Pictures"`. The match is then loaded like with
+    /// [`Client::get_studio`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the studio.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoMatch`] if no studio matches the name, in
+    /// which case [`Client::search_studio`] lists the candidates, or an
+    /// error if a request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let studio = client.get_studio_by_name("mappa").await?;
+    ///
+    /// assert_eq!(studio.name, "MAPPA");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_studio_by_name(&self, name: &str) -> Result<Studio> {
+        let query = Client::get_query(MediaType::Studio, Action::Search)?;
+        let variables =
+            serde_json::json!({ "search": name, "page": 1, "per_page": STUDIO_CANDIDATES });
+        let candidates = self.get_page(query, variables, parse_studios).await?;
+
+        let lowercase = name.trim().to_lowercase();
+        let studio = candidates
+            .iter()
+            .find(|studio| studio.name.to_lowercase() == lowercase)
+            .or_else(|| candidates.iter().find(|studio| studio.matches_name(name)))
+            .ok_or_else(|| Error::NoMatch(name.to_string()))?;
+
+        self.get_studio(studio.id).await
+    }
+
+    /// Search animes, mangas, characters, staff and studios at once.
+    ///
+    /// The five searches are aliased pages of a single request, so a
+    /// combined search costs one request of the rate limit. Each list
+    /// holds up to `per_type` results, best matches first, partially
+    /// loaded. The fields missing from a result are left to their
+    /// default, and the results without an ID are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The text to search.
+    /// * `per_type` - The maximum number of results of each type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let results = client.search_all("bebop", 5).await?;
+    /// for anime in &results.anime {
+    ///     println!("anime: {}", anime.title.romaji());
+    /// }
+    /// for studio in &results.studios {
+    ///     println!("studio: {}", studio.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_all(&self, query: &str, per_type: u16) -> Result<SearchResults> {
+        let variables = serde_json::json!({ "search": query, "per_page": per_type });
+        let data = self.query_raw_value(queries::SEARCH_ALL, variables).await?;
+
+        Ok(SearchResults {
+            anime: parse_animes(self, &data["anime"]),
+            manga: parse_mangas(self, &data["manga"]),
+            characters: parse_characters(self, &data["characters"]),
+            staff: parse_people(self, &data["staff"]),
+            studios: parse_studios(self, &data["studios"]),
+        })
+    }
+
+    /// Gets the characters with the most favourites, most favourited
+    /// first.
+    ///
+    /// The characters are partially loaded: they only hold their ID,
+    /// name, image, site URL and number of favourites.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of characters to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let page = client.get_top_characters(1, 10).await?;
+    ///
+    /// for (rank, character) in page.iter().enumerate() {
+    ///     println!("{}. {:?}", rank + 1, character.name.full());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_top_characters(&self, page: u16, per_page: u16) -> Result<Page<Character>> {
+        let variables = serde_json::json!({ "page": page, "per_page": per_page });
+
+        self.get_page(queries::GET_TOP_CHARACTERS, variables, parse_characters)
+            .await
+    }
+
+    /// Gets the staff with the most favourites, most favourited first.
+    ///
+    /// The people are partially loaded: they only hold their ID, name,
+    /// language, image, gender, site URL and number of favourites.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of people to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let page = client.get_top_staff(1, 10).await?;
+    ///
+    /// for (rank, person) in page.iter().enumerate() {
+    ///     println!("{}. {:?}", rank + 1, person.name.full());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_top_staff(&self, page: u16, per_page: u16) -> Result<Page<Person>> {
+        let variables = serde_json::json!({ "page": page, "per_page": per_page });
+
+        self.get_page(queries::GET_TOP_STAFF, variables, parse_people)
+            .await
+    }
+
+    /// Check whether medias are marked as favourite by the authenticated user.
+    ///
+    /// The lookups are batched into as few requests as possible. Medias
+    /// that cannot be found are left out of the returned map.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_ids` - The IDs of the medias.
+    /// * `media_type` - The type of the medias, either anime or manga.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no API token, if the media type
+    /// is neither anime nor manga, or if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::MediaType;
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let favourites = client.are_favourites(&[1, 20], MediaType::Anime).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn are_favourites(
+        &self,
+        media_ids: &[i64],
+        media_type: MediaType,
+    ) -> Result<HashMap<i64, bool>> {
+        if self.api_token.is_none() {
+            return Err(Error::MissingToken);
+        }
+
+        let media_type = media_type.as_graphql().ok_or(Error::InvalidMediaType)?;
+
+        let mut favourites = HashMap::with_capacity(media_ids.len());
+
+        for chunk in media_ids.chunks(BATCH_SIZE) {
+            let fields = chunk
+                .iter()
+                .enumerate()
+                .map(|(i, id)| {
+                    format!("m{i}: Media(id: {id}, type: {media_type}) {{ id isFavourite }}")
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let query = format!("query AreFavourites {{\n{fields}\n}}");
+
+            let data = self.send_query(&query, serde_json::json!({})).await?;
+
+            for i in 0..chunk.len() {
+                let media = &data["data"][format!("m{i}")];
+
+                if let (Some(id), Some(is_favourite)) =
+                    (media["id"].as_i64(), media["isFavourite"].as_bool())
+                {
+                    favourites.insert(id, is_favourite);
+                }
+            }
+        }
+
+        Ok(favourites)
+    }
+
+    /// Get the previews of medias: their title, cover, format and year.
+    ///
+    /// This is much lighter than getting the full medias, for example to
+    /// render link cards. The lookups are batched into as few requests as
+    /// possible. The previews are returned in the order of `media_ids`,
+    /// and medias that cannot be found are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_ids` - The IDs of the medias.
+    /// * `media_type` - The type of the medias, either anime or manga.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the media type is neither anime nor manga, or
+    /// if a request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::models::MediaType;
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// for preview in client.get_media_previews(&[1, 5, 20], MediaType::Anime).await? {
+    ///     println!("{} ({:?})", preview.title.preferred(), preview.season_year);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_media_previews(
+        &self,
+        media_ids: &[i64],
+        media_type: MediaType,
+    ) -> Result<Vec<MediaPreview>> {
+        let media_type = media_type.as_graphql().ok_or(Error::InvalidMediaType)?;
+        let fragment = queries::MEDIA_PREVIEW;
+
+        let mut previews = Vec::with_capacity(media_ids.len());
+
+        for chunk in media_ids.chunks(PREVIEW_BATCH_SIZE) {
+            let fields = chunk
+                .iter()
+                .enumerate()
+                .map(|(i, id)| {
+                    format!("m{i}: Media(id: {id}, type: {media_type}) {{ ...preview }}")
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let query = format!("query GetMediaPreviews {{\n{fields}\n}}\n\n{fragment}");
+
+            // An unknown ID comes with a `Not Found.` error next to the
+            // other medias, so the errors are ignored.
+            let data = self.send_query(&query, serde_json::json!({})).await?;
+
+            previews.extend(
+                (0..chunk.len())
+                    .map(|i| &data["data"][format!("m{i}")])
+                    .filter(|media| media.is_object())
+                    .map(|media| parse_media_preview(self, media)),
+            );
+        }
+
+        Ok(previews)
+    }
+
+    /// Get the list entries of a user for the given medias.
+    ///
+    /// The IDs are sent in batches through the `mediaId_in` argument and
+    /// every page of each batch is fetched, so hundreds of entries only
+    /// take a few requests. Medias missing from the list of the user are
+    /// left out of the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_ids` - The IDs of the animes or mangas.
+    /// * `user_id` - The ID of the user owning the list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// for entry in client.get_media_list_entries(&[1, 20, 21], 1).await? {
+    ///     println!("{}: {} episodes", entry.media_id, entry.progress);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_media_list_entries(
+        &self,
+        media_ids: &[i64],
+        user_id: impl Into<UserId>,
+    ) -> Result<Vec<MediaListEntry>> {
+        let query = queries::GET_MEDIA_LIST_ENTRIES;
+        let user_id: UserId = user_id.into();
+        let mut entries = Vec::new();
+
+        for chunk in media_ids.chunks(BATCH_SIZE) {
+            let mut page = 1;
+
+            loop {
+                let data = self
+                    .query_raw_value(
+                        query,
+                        serde_json::json!({ "userId": user_id, "mediaIds": chunk, "page": page }),
+                    )
+                    .await?;
+
+                let list: Option<Vec<MediaListEntry>> =
+                    serde_json::from_value(data["Page"]["mediaList"].clone())?;
+                entries.extend(list.unwrap_or_default());
+
+                if !data["Page"]["pageInfo"]["hasNextPage"]
+                    .as_bool()
+                    .unwrap_or_default()
+                {
+                    break;
+                }
+
+                page += 1;
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Get the whole anime or manga list of a user.
+    ///
+    /// The list is fetched in chunks of 500 entries. The scores of the
+    /// entries are on the 100-point scale, as stored by AniList, and each
+    /// entry holds the ID, MyAnimeList ID and title of its media.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_name` - The name of the user.
+    /// * `media_type` - Whether to get the anime or the manga list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `media_type` is neither `Anime` nor `Manga`,
+    /// if the user doesn't exist or their list is private, or if a
+    /// request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::models::MediaType;
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let list = client
+    ///     .get_media_list_collection("andrielfr", MediaType::Manga)
+    ///     .await?;
+    /// println!("{} mangas", list.entries.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_media_list_collection(
+        &self,
+        user_name: &str,
+        media_type: MediaType,
+    ) -> Result<MediaListCollection> {
+        let query = queries::GET_MEDIA_LIST_COLLECTION;
+        let media_type = media_type.as_graphql().ok_or(Error::InvalidMediaType)?;
+
+        let mut collection = MediaListCollection::default();
+        let mut seen = HashSet::new();
+        let mut chunk = 1;
+
+        loop {
+            let data = self
+                .query_raw_value(
+                    query,
+                    serde_json::json!({ "userName": user_name, "type": media_type, "chunk": chunk }),
+                )
+                .await?;
+            let data = &data["MediaListCollection"];
+
+            collection.score_format =
+                serde_json::from_value(data["user"]["mediaListOptions"]["scoreFormat"].clone())
+                    .unwrap_or_default();
+
+            for list in data["lists"].as_array().into_iter().flatten() {
+                let entries: Vec<MediaListEntry> = serde_json::from_value(list["entries"].clone())?;
+
+                // An entry in custom lists is also in its status list.
+                collection
+                    .entries
+                    .extend(entries.into_iter().filter(|entry| seen.insert(entry.id)));
+            }
+
+            if !data["hasNextChunk"].as_bool().unwrap_or_default() {
+                break;
+            }
+
+            chunk += 1;
+        }
+
+        Ok(collection)
+    }
+
+    /// Compare the lists of two users.
+    ///
+    /// Both lists are fetched in full with
+    /// [`Client::get_media_list_collection`], then the medias in both
+    /// lists, the medias in only one of them and the scores of the medias
+    /// scored by both users are gathered. The scores are on the 100-point
+    /// scale, so users with different score formats can be compared.
+    ///
+    /// # Arguments
+    ///
+    /// * `first_user` - The name of the first user.
+    /// * `second_user` - The name of the second user.
+    /// * `media_type` - Whether to compare the anime or the manga lists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `media_type` is neither `Anime` nor `Manga`,
+    /// if a user doesn't exist or their list is private, or if a request
+    /// fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::models::MediaType;
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let comparison = client
+    ///     .compare_lists("andrielfr", "josh", MediaType::Anime)
+    ///     .await?;
+    /// println!("{} medias in common", comparison.shared.len());
+    /// if let Some(difference) = comparison.mean_score_difference() {
+    ///     println!("{difference:.1} points apart on average");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn compare_lists(
+        &self,
+        first_user: &str,
+        second_user: &str,
+        media_type: MediaType,
+    ) -> Result<ListComparison> {
+        let (first, second) = futures_util::future::try_join(
+            self.get_media_list_collection(first_user, media_type.clone()),
+            self.get_media_list_collection(second_user, media_type),
+        )
+        .await?;
+
+        Ok(ListComparison::new(first.entries, second.entries))
+    }
+
+    /// Import list entries into the list of the authenticated user.
+    ///
+    /// The entries, usually read with [`import::parse_mal_xml`], are
+    /// matched to AniList medias by their MyAnimeList ID, in batches.
+    /// Entries whose media isn't on AniList are left out, and entries
+    /// whose media is already in the list are handled according to the
+    /// [`ConflictPolicy`] of `options`. The others are saved one by one,
+    /// calling the progress callback of `options` after each entry.
+    ///
+    /// In a dry run, the medias are looked up but nothing is saved, and
+    /// the report tells what would have been done.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - The entries to import.
+    /// * `options` - The options of the import.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no token, if the media type
+    /// of an entry is neither anime nor manga, or if a request fails.
+    /// The entries saved before a failure stay in the list.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::import::{self, ImportOptions};
+    /// # async fn f(client: rust_anilist::Client, xml: &[u8]) -> rust_anilist::Result<()> {
+    /// let entries = import::parse_mal_xml(xml)?;
+    /// let report = client
+    ///     .import_entries(&entries, ImportOptions::new().dry_run(true))
+    ///     .await?;
+    /// println!("{} entries would be created", report.created.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn import_entries(
+        &self,
+        entries: &[ImportedEntry],
+        options: ImportOptions,
+    ) -> Result<ImportReport> {
+        if self.api_token.is_none() {
+            return Err(Error::MissingToken);
+        }
+
+        let mut mal_ids: HashMap<&str, Vec<i64>> = HashMap::new();
+        for entry in entries {
+            let media_type = entry
+                .media_type
+                .as_graphql()
+                .ok_or(Error::InvalidMediaType)?;
+            mal_ids.entry(media_type).or_default().push(entry.mal_id);
+        }
+
+        // The AniList ID of every media, with the progress of its entry
+        // if it's already in the list.
+        let mut medias = HashMap::new();
+        for (media_type, mut mal_ids) in mal_ids {
+            mal_ids.sort_unstable();
+            mal_ids.dedup();
+
+            for chunk in mal_ids.chunks(BATCH_SIZE) {
+                let variables = serde_json::json!({
+                    "idMal": chunk,
+                    "type": media_type,
+                    "perPage": BATCH_SIZE,
+                });
+                let data = self
+                    .query_raw_value(queries::GET_MEDIAS_BY_MAL_ID, variables)
+                    .await?;
+
+                for media in data["Page"]["media"].as_array().into_iter().flatten() {
+                    if let (Some(id), Some(mal_id)) =
+                        (media["id"].as_i64(), media["idMal"].as_i64())
+                    {
+                        let entry = &media["mediaListEntry"];
+                        let progress = entry
+                            .is_object()
+                            .then(|| entry["progress"].as_u64().unwrap_or_default());
+                        medias.entry((media_type, mal_id)).or_insert((id, progress));
+                    }
+                }
+            }
+        }
+
+        let mut report = ImportReport::default();
+        for (i, entry) in entries.iter().enumerate() {
+            let media_type = entry.media_type.as_graphql().unwrap_or_default();
+            let media = medias.get(&(media_type, entry.mal_id));
+
+            let outcome = match media {
+                None => ImportOutcome::NotFound,
+                Some((_, None)) => ImportOutcome::Created,
+                Some((_, Some(progress))) => match options.conflict_policy {
+                    ConflictPolicy::Overwrite => ImportOutcome::Updated,
+                    ConflictPolicy::MergeHigherProgress
+                        if u64::from(entry.progress) > *progress =>
+                    {
+                        ImportOutcome::Updated
+                    }
+                    ConflictPolicy::Skip | ConflictPolicy::MergeHigherProgress => {
+                        ImportOutcome::Skipped
+                    }
+                },
+            };
+
+            if let (Some((media_id, _)), false) = (media, options.dry_run) {
+                if matches!(outcome, ImportOutcome::Created | ImportOutcome::Updated) {
+                    self.query_raw_value(
+                        queries::SAVE_MEDIA_LIST_ENTRY,
+                        entry.variables(*media_id),
+                    )
+                    .await?;
+                }
+            }
+
+            report.record(entry.mal_id, outcome);
+            options.progress(ImportProgress {
+                done: i + 1,
+                total: entries.len(),
+                mal_id: entry.mal_id,
+                outcome,
+            });
+        }
+
+        Ok(report)
+    }
+
+    /// Get the episodes airing in a time window, grouped by day.
+    ///
+    /// Every episode airing from `start` and for `days` days is fetched,
+    /// page by page, along with its anime. The episodes are grouped by
+    /// the day they air on in the time zone of `offset`, in airing order;
+    /// the days without episodes are left out, and so are the episodes
+    /// whose anime has no ID.
+    ///
+    /// With `only_list`, only the animes in the list of the authenticated
+    /// user are kept: their IDs are fetched first and given to the
+    /// schedule query.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The start of the window, as a Unix timestamp.
+    /// * `days` - The length of the window, in days.
+    /// * `offset` - The offset from UTC of the time zone of the days.
+    /// * `only_list` - Whether to keep only the animes in the list of the
+    ///   authenticated user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `only_list` is set and the client has no
+    /// token, or if a request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chrono::{FixedOffset, Utc};
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+    /// let calendar = client
+    ///     .get_airing_calendar(Utc::now().timestamp(), 7, tokyo, false)
+    ///     .await?;
+    /// for (day, entries) in calendar {
+    ///     println!("{day}");
+    ///     for entry in entries {
+    ///         println!("  {} #{}", entry.anime.title.romaji(), entry.schedule.episode);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_airing_calendar(
+        &self,
+        start: i64,
+        days: u8,
+        offset: FixedOffset,
+        only_list: bool,
+    ) -> Result<BTreeMap<NaiveDate, Vec<AiringEntry>>> {
+        let media_ids = match only_list {
+            true => Some(self.viewer_list_media_ids("ANIME").await?),
+            false => None,
+        };
+        if media_ids.as_ref().is_some_and(Vec::is_empty) {
+            return Ok(BTreeMap::new());
+        }
+
+        let end = start + i64::from(days) * 86_400;
+        let entries = self
+            .get_airing_between(start - 1, end, media_ids.as_deref())
+            .await?;
+
+        let mut calendar = BTreeMap::<NaiveDate, Vec<AiringEntry>>::new();
+        for entry in entries {
+            if let Some(day) = entry.schedule.local_date(offset) {
+                calendar.entry(day).or_default().push(entry);
+            }
+        }
+
+        Ok(calendar)
+    }
+
+    /// Watch animes for new episodes.
+    ///
+    /// Every `poll_interval`, the airing schedules of all the animes are
+    /// checked with a single query, and an event is yielded for each
+    /// episode whose airing time has passed since the previous check.
+    /// The first check is done right away and reports the episodes
+    /// airing from now on; use [`Client::watch_airing_after`] to resume
+    /// a previous watch.
+    ///
+    /// A failed check yields the error and is retried at the next poll,
+    /// so no episode is missed. The stream never ends, unless
+    /// `media_ids` is empty, and stops polling once dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_ids` - The IDs of the animes to watch.
+    /// * `poll_interval` - The time to wait between two checks.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use futures_util::StreamExt;
+    /// # async fn f(client: rust_anilist::Client) {
+    /// let mut events = Box::pin(client.watch_airing(vec![1, 21], Duration::from_secs(300)));
+    /// while let Some(event) = events.next().await {
+    ///     match event {
+    ///         Ok(event) => println!(
+    ///             "Episode {} of {} is out!",
+    ///             event.schedule.episode,
+    ///             event.anime.title.romaji()
+    ///         ),
+    ///         Err(e) => eprintln!("{e}"),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn watch_airing(
+        &self,
+        media_ids: Vec<i64>,
+        poll_interval: Duration,
+    ) -> impl futures_util::Stream<Item = Result<AiringEvent>> + 'static {
+        self.watch_airing_after(media_ids, poll_interval, unix_now(), HashSet::new())
+    }
+
+    /// Watch animes for new episodes, resuming a previous watch.
+    ///
+    /// This is [`Client::watch_airing`], reporting the episodes aired
+    /// after `after` instead of now, so the episodes aired while the
+    /// watch was stopped are reported too. An episode whose schedule ID
+    /// is in `seen` is never reported: give it the IDs already reported,
+    /// such as the ones saved before a restart, so every episode is
+    /// reported once.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_ids` - The IDs of the animes to watch.
+    /// * `poll_interval` - The time to wait between two checks.
+    /// * `after` - The Unix timestamp from which to report the episodes.
+    /// * `seen` - The IDs of the schedules already reported.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::collections::HashSet;
+    /// # use std::time::Duration;
+    /// # use futures_util::StreamExt;
+    /// # async fn f(client: rust_anilist::Client, last_event_at: i64, seen: HashSet<u32>) {
+    /// let events = client.watch_airing_after(vec![1], Duration::from_secs(300), last_event_at, seen);
+    /// let mut events = Box::pin(events);
+    /// while let Some(Ok(event)) = events.next().await {
+    ///     println!("{} is out!", event.anime.title.romaji());
+    /// }
+    /// # }
+    /// ```
+    pub fn watch_airing_after(
+        &self,
+        media_ids: Vec<i64>,
+        poll_interval: Duration,
+        after: i64,
+        seen: HashSet<u32>,
+    ) -> impl futures_util::Stream<Item = Result<AiringEvent>> + 'static {
+        struct Watch {
+            client: Client,
+            media_ids: Vec<i64>,
+            poll_interval: Duration,
+            after: i64,
+            seen: HashSet<u32>,
+            events: VecDeque<AiringEvent>,
+            is_first_check: bool,
+        }
+
+        let watch = Watch {
+            client: self.clone(),
+            media_ids,
+            poll_interval,
+            after,
+            seen,
+            events: VecDeque::new(),
+            is_first_check: true,
+        };
+
+        stream::unfold(watch, |mut watch| async move {
+            loop {
+                if let Some(event) = watch.events.pop_front() {
+                    return Some((Ok(event), watch));
+                }
+                // Without IDs, the query would return every anime.
+                if watch.media_ids.is_empty() {
+                    return None;
+                }
+
+                if !watch.is_first_check {
+                    time::sleep(watch.poll_interval).await;
+                }
+                watch.is_first_check = false;
+
+                let now = unix_now();
+                let entries = watch
+                    .client
+                    .get_airing_between(watch.after, now + 1, Some(&watch.media_ids))
+                    .await;
+
+                match entries {
+                    Ok(entries) => {
+                        watch.after = now;
+                        // A delayed episode may air in a later check again.
+                        let seen = &mut watch.seen;
+                        watch.events.extend(
+                            entries
+                                .into_iter()
+                                .filter(|entry| seen.insert(entry.schedule.id)),
+                        );
+                    }
+                    Err(e) => return Some((Err(e), watch)),
+                }
+            }
+        })
+    }
+
+    /// Returns the episodes airing between two times, in airing order.
+    ///
+    /// Every page of the schedules is fetched. The schedules whose anime
+    /// has no ID are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `after` - The Unix timestamp the episodes air after.
+    /// * `before` - The Unix timestamp the episodes air before.
+    /// * `media_ids` - The IDs of the animes to keep, if not all of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails.
+    async fn get_airing_between(
+        &self,
+        after: i64,
+        before: i64,
+        media_ids: Option<&[i64]>,
+    ) -> Result<Vec<AiringEntry>> {
+        let mut variables = serde_json::json!({ "start": after, "end": before });
+        if let Some(media_ids) = media_ids {
+            variables["mediaIds"] = media_ids.into();
+        }
+
+        let mut entries = Vec::new();
+        let mut page = 1;
+
+        loop {
+            variables["page"] = page.into();
+            let data = self
+                .query_raw_value(queries::GET_AIRING_CALENDAR, variables.clone())
+                .await?;
+            let data = &data["Page"];
+
+            for node in data["airingSchedules"].as_array().into_iter().flatten() {
+                // A schedule whose anime has no ID can't be told apart from
+                // the others, so it is skipped.
+                if let Ok(anime) = parse_anime(self, &node["media"]) {
+                    entries.push(AiringEntry {
+                        schedule: AiringSchedule::deserialize(node)?,
+                        anime,
+                    });
+                }
+            }
+
+            if !data["pageInfo"]["hasNextPage"]
+                .as_bool()
+                .unwrap_or_default()
+            {
+                break;
+            }
+
+            page += 1;
+        }
+
+        Ok(entries)
+    }
+
+    /// Returns the IDs of the medias in the list of the authenticated
+    /// user.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_type` - The type of the medias, as named by the API.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no token or a request fails.
+    async fn viewer_list_media_ids(&self, media_type: &str) -> Result<Vec<i64>> {
+        if self.api_token.is_none() {
+            return Err(Error::MissingToken);
+        }
+
+        let viewer = self
+            .query_raw_value(queries::GET_VIEWER, serde_json::json!({}))
+            .await?;
+        let user_id = viewer["Viewer"]["id"].as_i64();
+
+        let mut media_ids = Vec::new();
+        let mut chunk = 1;
+
+        loop {
+            let variables =
+                serde_json::json!({ "userId": user_id, "type": media_type, "chunk": chunk });
+            let data = self
+                .query_raw_value(queries::GET_LIST_MEDIA_IDS, variables)
+                .await?;
+            let data = &data["MediaListCollection"];
+
+            media_ids.extend(
+                data["lists"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .flat_map(|list| list["entries"].as_array().into_iter().flatten())
+                    .filter_map(|entry| entry["mediaId"].as_i64()),
+            );
+
+            if !data["hasNextChunk"].as_bool().unwrap_or_default() {
+                break;
+            }
+
+            chunk += 1;
+        }
+
+        // An entry in custom lists is also in its status list.
+        media_ids.sort_unstable();
+        media_ids.dedup();
+
+        Ok(media_ids)
+    }
+
+    /// Get the notifications of the authenticated user, newest first.
+    ///
+    /// Airing, following and activity notifications are returned as
+    /// their own variants; the other types as [`Notification::Other`].
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of notifications to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no token or the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::models::Notification;
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// for notification in client.get_notifications(1, 25).await? {
+    ///     if let Notification::Airing(airing) = notification {
+    ///         println!("{}", airing.message());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_notifications(&self, page: u16, per_page: u16) -> Result<Page<Notification>> {
+        if self.api_token.is_none() {
+            return Err(Error::MissingToken);
+        }
+
+        let query = queries::GET_NOTIFICATIONS;
+        let variables = serde_json::json!({ "page": page, "per_page": per_page });
+
+        self.get_page(query, variables, parse_notifications).await
+    }
+
+    /// Post a status on the profile of the authenticated user.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text of the status, in AniList markdown.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no token or the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let activity = client.post_text_activity("Hello, AniList!").await?;
+    ///
+    /// println!("Posted at {}", activity.url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn post_text_activity(&self, text: &str) -> Result<Activity> {
+        if self.api_token.is_none() {
+            return Err(Error::MissingToken);
+        }
+
+        let query = queries::SAVE_TEXT_ACTIVITY;
+        let data = self
+            .query_raw_value(query, serde_json::json!({ "text": text }))
+            .await?;
+
+        serde_json::from_value(data["SaveTextActivity"].clone()).map_err(Error::from)
+    }
+
+    /// Send a message to the profile of another user.
+    ///
+    /// # Arguments
+    ///
+    /// * `recipient_id` - The ID of the user receiving the message.
+    /// * `message` - The text of the message, in AniList markdown.
+    /// * `private` - Whether only the recipient can see the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client has no token or the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let activity = client.post_message_activity(1, "Hi there!", false).await?;
+    ///
+    /// println!("Sent at {}", activity.url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn post_message_activity(
+        &self,
+        recipient_id: impl Into<UserId>,
+        message: &str,
+        private: bool,
+    ) -> Result<Activity> {
+        if self.api_token.is_none() {
+            return Err(Error::MissingToken);
+        }
+
+        let query = queries::SAVE_MESSAGE_ACTIVITY;
+        let variables = serde_json::json!({
+            "recipientId": recipient_id.into(),
+            "message": message,
+            "private": private,
+        });
+        let data = self.query_raw_value(query, variables).await?;
+
+        serde_json::from_value(data["SaveMessageActivity"].clone()).map_err(Error::from)
+    }
+
+    /// Get several animes by their IDs, sending up to `concurrency`
+    /// requests at a time.
+    ///
+    /// A failed request doesn't stop the others: every ID is returned
+    /// along with its own result, in the order of `ids`. When the API
+    /// reports that the rate-limit budget is exhausted, new requests wait
+    /// for the next window; enable [`Client::retry_on_rate_limit`] to also
+    /// retry the requests rejected meanwhile.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the animes.
+    /// * `concurrency` - The maximum number of requests in flight.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f(client: rust_anilist::Client) {
+    /// for (id, anime) in client.get_animes_concurrent(&[1, 5, 20], 4).await {
+    ///     match anime {
+    ///         Ok(anime) => println!("{id}: {}", anime.title.romaji()),
+    ///         Err(e) => eprintln!("{id}: {e}"),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_animes_concurrent(
+        &self,
+        ids: &[impl Into<AnimeId> + Copy],
+        concurrency: usize,
+    ) -> Vec<(AnimeId, Result<Anime>)> {
+        let ids = ids.iter().map(|&id| id.into()).collect();
+        self.get_concurrent(ids, concurrency, |id| self.get_anime(id))
+            .await
+    }
+
+    /// Get several mangas by their IDs, sending up to `concurrency`
+    /// requests at a time.
+    ///
+    /// See [`Client::get_animes_concurrent`].
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the mangas.
+    /// * `concurrency` - The maximum number of requests in flight.
+    pub async fn get_mangas_concurrent(
+        &self,
+        ids: &[impl Into<MangaId> + Copy],
+        concurrency: usize,
+    ) -> Vec<(MangaId, Result<Manga>)> {
+        let ids = ids.iter().map(|&id| id.into()).collect();
+        self.get_concurrent(ids, concurrency, |id| self.get_manga(id))
+            .await
+    }
+
+    /// Get several characters by their IDs, sending up to `concurrency`
+    /// requests at a time.
+    ///
+    /// See [`Client::get_animes_concurrent`].
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the characters.
+    /// * `concurrency` - The maximum number of requests in flight.
+    pub async fn get_characters_concurrent(
+        &self,
+        ids: &[impl Into<CharacterId> + Copy],
+        concurrency: usize,
+    ) -> Vec<(CharacterId, Result<Character>)> {
+        let ids = ids.iter().map(|&id| id.into()).collect();
+        self.get_concurrent(ids, concurrency, |id| self.get_character(id))
+            .await
+    }
+
+    /// Get several entities by their IDs with the given getter, sending
+    /// up to `concurrency` requests at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the entities.
+    /// * `concurrency` - The maximum number of requests in flight.
+    /// * `get` - The getter of a single entity.
+    async fn get_concurrent<I, T, F, Fut>(
+        &self,
+        ids: Vec<I>,
+        concurrency: usize,
+        get: F,
+    ) -> Vec<(I, Result<T>)>
+    where
+        I: Copy,
+        F: Fn(I) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let get = &get;
+
+        stream::iter(ids)
+            .map(|id| async move {
+                self.wait_for_rate_limit().await;
+                (id, get(id).await)
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Waits for the next rate-limit window if the budget is exhausted.
+    async fn wait_for_rate_limit(&self) {
+        let Some(status) = self.rate_limit_status() else {
+            return;
+        };
+        let Some(wait) = status.wait() else {
+            return;
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(?wait, "rate-limit budget exhausted, waiting");
+
+        time::sleep(wait).await;
+
+        // The budget of the new window is unknown until the next response.
+        let mut stored = self
+            .rate_limit_status
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if *stored == Some(status) {
+            *stored = None;
+        }
+    }
+
+    /// Send a GraphQL document and deserialize its `data` object.
+    ///
+    /// This goes through the same authentication, rate-limit handling,
+    /// retries and cache as the other methods, which makes it useful to
+    /// reach parts of the API this crate doesn't wrap yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The GraphQL document to send.
+    /// * `variables` - The variables to send with the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, the API answers with
+    /// GraphQL errors or `data` doesn't match `T`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use serde::Deserialize;
+    /// #[derive(Deserialize)]
+    /// struct Data {
+    ///     #[serde(rename = "SiteStatistics")]
+    ///     stats: serde_json::Value,
+    /// }
+    ///
+    /// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+    /// let data: Data = client
+    ///     .query_raw(
+    ///         "query SiteStatistics { SiteStatistics { users { nodes { count } } } }",
+    ///         serde_json::json!({}),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_raw<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> Result<T> {
+        let data = self.query_raw_value(query, variables).await?;
+
+        serde_json::from_value(data).map_err(Error::from)
+    }
+
+    /// Send a GraphQL document and return its `data` object as JSON.
+    ///
+    /// See [`Client::query_raw`].
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The GraphQL document to send.
+    /// * `variables` - The variables to send with the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the API answers with
+    /// GraphQL errors.
+    pub async fn query_raw_value(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let response = self.send_query(query, variables).await?;
+
+        graphql_data(response)
+    }
+
+    /// Send a paginated request and parse the items of its `Page` object.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The GraphQL document, taking a `page` variable.
+    /// * `variables` - The variables to send with the request.
+    /// * `parse` - The parser of the items.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the API answers with
+    /// GraphQL errors.
+    pub(crate) async fn get_page<T>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+        parse: ParseItems<T>,
+    ) -> Result<Page<T>> {
+        self.get_page_at(query, variables, "/Page", parse).await
+    }
+
+    /// Send a paginated request and parse the items of the paginated
+    /// object at `path`, such as the connection of a character's medias.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The GraphQL document, taking a `page` variable.
+    /// * `variables` - The variables to send with the request.
+    /// * `path` - The JSON pointer to the paginated object, holding the
+    ///   `pageInfo` and the items.
+    /// * `parse` - The parser of the items.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the API answers with
+    /// GraphQL errors.
+    pub(crate) async fn get_page_at<T>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+        path: &'static str,
+        parse: ParseItems<T>,
+    ) -> Result<Page<T>> {
+        let data = self.query_raw_value(query, variables.clone()).await?;
+        let page = data.pointer(path).unwrap_or(&serde_json::Value::Null);
+
+        Ok(Page {
+            items: parse(self, page),
+            page_info: PageInfo::deserialize(&page["pageInfo"]).unwrap_or_default(),
+            request: Some(PageRequest {
+                client: self.clone(),
+                query: query.to_string(),
+                variables,
+                path,
+                parse,
+            }),
+        })
+    }
+
+    /// Send a request to the AniList API.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_type` - The type of media to request.
+    /// * `action` - The action to perform.
+    /// * `variables` - The variables to send with the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or is rate limited.
+    async fn request(
+        &self,
+        media_type: MediaType,
+        action: Action,
+        variables: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let query = Client::get_query(media_type, action)?;
+
+        self.send_query(query, variables).await
+    }
+
+    /// Send a GraphQL document to the AniList API.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The GraphQL document to send.
+    /// * `variables` - The variables to send with the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or is rate limited.
+    async fn send_query(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        if self.detached {
+            return Err(crate::Error::Detached);
+        }
+
+        let query = self.query_overrides.resolve(query);
+
+        #[cfg(feature = "tracing")]
+        let span = trace::span(query, &variables, self.api_token.is_some());
+
+        let future = self.dispatch(query, variables);
+
+        #[cfg(feature = "tracing")]
+        let future = trace::instrument(future, span);
+
+        future.await
+    }
+
+    /// Answer a GraphQL document from the cache or send it.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The GraphQL document to send.
+    /// * `variables` - The variables to send with the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or is rate limited.
+    async fn dispatch(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let json = serde_json::json!({"query": query, "variables": variables});
+
+        if is_mutation(query) {
+            return self.execute(&json, true).await;
+        }
+
+        let key = cache::key(query, self.api_token.as_deref(), &variables);
+
+        if let Some(response) = self.cache.as_ref().and_then(|cache| cache.get(&key)) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("response served from the cache");
+
+            return Ok(response);
+        }
+
+        let response = self.execute_shared(&key, &json).await?;
+
+        if let Some(cache) = &self.cache {
+            if response["errors"].is_null() {
+                cache.put(&key, &response);
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Execute a query, sharing its outcome with identical concurrent calls.
+    ///
+    /// Only the first of the identical calls sends the query, the others
+    /// wait for its response or its error. A call made once the query
+    /// completed sends it again.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The cache key of the query.
+    /// * `json` - The body of the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or is rate limited.
+    async fn execute_shared(
+        &self,
+        key: &str,
+        json: &serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        loop {
+            match self.in_flight.join(key) {
+                Slot::Leader(leader) => {
+                    let result = self.execute(json, false).await;
+                    leader.finish(&result);
+
+                    return result;
+                }
+                Slot::Follower(outcome) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!("waiting for an identical in-flight request");
+
+                    // The leader was cancelled, so the query is sent again.
+                    if let Some(result) = in_flight::wait(outcome).await {
+                        return result;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Execute a request, retrying it as dictated by the retry policies.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The body of the request.
+    /// * `is_mutation` - Whether the request is a mutation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or is rate limited.
+    async fn execute(
+        &self,
+        json: &serde_json::Value,
+        is_mutation: bool,
+    ) -> Result<serde_json::Value> {
+        let operation = json["query"]
+            .as_str()
+            .and_then(operation_name)
+            .unwrap_or("query");
+
+        let mut rate_limit_attempt = 1;
+        let mut attempt = 1;
+        loop {
+            // The permit is released before sleeping between attempts.
+            let permit = match &self.concurrency_limit {
+                Some(limit) => Some(limit.acquire().await),
+                None => None,
+            };
+
+            let total_attempt = attempt + rate_limit_attempt - 1;
+            self.hooks.request(|| RequestInfo {
+                operation: operation.to_string(),
+                attempt: total_attempt,
+                is_mutation,
+            });
+
+            let start = Instant::now();
+            let (result, response) = match &self.transport {
+                Some(transport) => (transport.execute(json.clone()).await, None),
+                None => self.send_http(json).await,
+            };
+            drop(permit);
+
+            self.hooks.response(|| ResponseInfo {
+                operation: operation.to_string(),
+                attempt: total_attempt,
+                duration: start.elapsed(),
+                status: response.map(|(status, _)| status).or(match &result {
+                    Err(Error::HttpError(status)) => Some(*status),
+                    Err(Error::RateLimited { .. }) => Some(429),
+                    _ => None,
+                }),
+                rate_limit: response.map(|(_, rate_limit)| rate_limit),
+                error: result.as_ref().err().map(Error::kind),
+                has_errors: result
+                    .as_ref()
+                    .is_ok_and(|response| !response["errors"].is_null()),
+            });
+
+            match result {
+                Err(Error::RateLimited { retry_after }) => {
+                    let wait = retry_after.unwrap_or(Duration::from_secs(60));
+
+                    if rate_limit_attempt < self.rate_limit_retry.max_attempts
+                        && wait <= self.rate_limit_retry.max_wait
+                    {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(
+                            attempt = rate_limit_attempt + 1,
+                            ?wait,
+                            "rate limited, retrying"
+                        );
+
+                        time::sleep(wait).await;
+                        rate_limit_attempt += 1;
+                        continue;
+                    }
+
+                    return Err(Error::RateLimited { retry_after });
+                }
+                Err(Error::HttpError(status))
+                    if status >= 500
+                        && self.retry_policy.retry_server_errors
+                        && self.retry_policy.allows(attempt, is_mutation) =>
+                {
+                    let reason = Error::HttpError(status).to_string();
+                    self.backoff(attempt, Some(status), reason).await;
+                    attempt += 1;
+                }
+                Err(Error::ConnectionError(reason))
+                    if self.retry_policy.retry_connection_errors
+                        && self.retry_policy.allows(attempt, is_mutation) =>
+                {
+                    self.backoff(attempt, None, reason).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Returns the HTTP client sending the requests.
+    ///
+    /// This is the one supplied by the user, or else the default HTTP
+    /// client, built on the first call and shared by every client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the default HTTP client cannot be built, e.g.
+    /// when the TLS backend fails to initialize.
+    fn http_client(&self) -> Result<reqwest::Client> {
+        if let Some(http_client) = self.http_client.as_ref().or(DEFAULT_HTTP_CLIENT.get()) {
+            return Ok(http_client.clone());
+        }
+
+        let http_client = reqwest::Client::builder()
+            .build()
+            .map_err(|e| Error::ConnectionError(e.to_string()))?;
+
+        Ok(DEFAULT_HTTP_CLIENT.get_or_init(|| http_client).clone())
+    }
+
+    /// Send a request body to the GraphQL endpoint over HTTP.
+    ///
+    /// This is the default transport of the client. Along with the
+    /// result, returns the HTTP status and the rate-limit status of the
+    /// response, if one was received.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The body of the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, is rate limited or the
+    /// server answers with a `5xx` status.
+    async fn send_http(
+        &self,
+        json: &serde_json::Value,
+    ) -> (Result<serde_json::Value>, Option<(u16, RateLimitStatus)>) {
+        let http_client = match self.http_client() {
+            Ok(http_client) => http_client,
+            Err(e) => return (Err(e), None),
+        };
+        let mut body = http_client
+            .post(self.api_url.clone())
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .timeout(self.timeout)
+            .body(json.to_string());
+
+        // Browsers don't let scripts set the `User-Agent` header.
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            body = body.header("User-Agent", &self.user_agent);
+        }
+
+        if let Some(token) = &self.api_token {
+            body = body.bearer_auth(token);
+        }
+
+        let response = match body.send().await {
+            Ok(response) => response,
+            Err(e) if is_connection_error(&e) => {
+                return (Err(Error::ConnectionError(e.to_string())), None);
+            }
+            Err(e) => return (Err(Error::ApiError(e.to_string())), None),
+        };
+
+        let status = RateLimitStatus::from_headers(response.headers());
+        let http_status = response.status();
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            status = http_status.as_u16(),
+            rate_limit_remaining = status.remaining,
+            "response received"
+        );
+
+        *self
+            .rate_limit_status
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = Some(status);
+
+        let result = if http_status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            Err(Error::RateLimited {
+                retry_after: status.retry_after,
+            })
+        } else if http_status.is_server_error() {
+            Err(Error::HttpError(http_status.as_u16()))
+        } else {
+            match response.text().await {
+                Ok(response) => serde_json::from_str(&response).map_err(Error::from),
+                Err(e) => Err(Error::ApiError(e.to_string())),
+            }
+        };
+
+        (result, Some((http_status.as_u16(), status)))
+    }
+
+    /// Waits before retrying a failed attempt, as dictated by the retry policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - The number of the failed attempt.
+    /// * `status` - The HTTP status of the failed attempt, if any.
+    /// * `reason` - A description of the failure.
+    async fn backoff(&self, attempt: u32, status: Option<u16>, reason: String) {
+        let delay = self.retry_policy.delay(attempt);
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(attempt = attempt + 1, ?delay, status, %reason, "retrying");
+
+        if let Some(on_retry) = &self.retry_policy.on_retry {
+            on_retry(&RetryEvent {
+                attempt: attempt + 1,
+                delay,
+                status,
+                reason,
+            });
+        }
+
+        time::sleep(delay).await;
+    }
+
+    /// Get the GraphQL query for a specific media type.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_type` - The type of media to get the query for.
+    /// * `action` - The action to perform.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the media type is not valid.
+    fn get_query(media_type: MediaType, action: Action) -> Result<&'static str> {
+        let graphql_query = match action {
+            Action::Get => match media_type {
+                MediaType::Anime => queries::GET_ANIME,
+                MediaType::Manga => queries::GET_MANGA,
+                MediaType::Character => queries::GET_CHARACTER,
+                MediaType::User => queries::GET_USER,
+                MediaType::Person => queries::GET_PERSON,
+                MediaType::Studio => queries::GET_STUDIO,
+                _ => return Err(Error::InvalidMediaType),
+            },
+            Action::Search => {
+                match media_type {
+                    MediaType::Anime => queries::SEARCH_ANIME,
+                    MediaType::Manga => queries::SEARCH_MANGA,
+                    // MediaType::Character => {
+                    //     include_str!("../../queries/search_character.graphql").to_string()
+                    // }
+                    MediaType::User => queries::SEARCH_USER,
+                    // MediaType::Person => {
+                    //     include_str!("../../queries/search_person.graphql").to_string()
+                    // }
+                    MediaType::Studio => queries::SEARCH_STUDIO,
+                    _ => return Err(Error::InvalidMediaType),
+                }
+            }
+        };
+
+        Ok(graphql_query)
+    }
+}
+
+impl Default for Client {
+    /// Creates a client with the default settings.
+    ///
+    /// This is cheap and cannot fail: the HTTP client is only built when
+    /// the first request is sent, so models deriving `Default` can be
+    /// created freely.
+    fn default() -> Self {
+        Client {
+            api_token: None,
+            timeout: Duration::from_secs(20),
+            api_url: Url::parse(API_URL).expect("the default API URL is valid"),
+            user_agent: USER_AGENT.to_string(),
+            http_client: None,
+            transport: None,
+            rate_limit_retry: RateLimitRetry::default(),
+            retry_policy: RetryPolicy::none(),
+            rate_limit_status: Arc::new(Mutex::new(None)),
+            cache: None,
+            in_flight: InFlight::default(),
+            concurrency_limit: None,
+            hooks: Hooks::default(),
+            title_language: None,
+            query_overrides: QueryOverrides::default(),
+            detached: false,
+        }
+    }
+}
+
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("api_token", &self.api_token.as_ref().map(|_| "<redacted>"))
+            .field("timeout", &self.timeout)
+            .field("api_url", &self.api_url)
+            .field("user_agent", &self.user_agent)
+            .field("http_client", &self.http_client)
+            .field("transport", &self.transport.is_some())
+            .field("rate_limit_retry", &self.rate_limit_retry)
+            .field("retry_policy", &self.retry_policy)
+            .field("rate_limit_status", &self.rate_limit_status)
+            .field("cache", &self.cache)
+            .field("concurrency_limit", &self.concurrency_limit)
+            .field("hooks", &self.hooks)
+            .field("title_language", &self.title_language)
+            .field("query_overrides", &self.query_overrides)
+            .field("detached", &self.detached)
+            .finish()
+    }
+}
+
+impl PartialEq for Client {
+    fn eq(&self, other: &Self) -> bool {
+        self.api_token == other.api_token
+            && self.timeout == other.timeout
+            && self.api_url == other.api_url
+            && self.user_agent == other.user_agent
+            && self.rate_limit_retry == other.rate_limit_retry
+            && self.retry_policy == other.retry_policy
+            && self.concurrency_limit == other.concurrency_limit
+            && self.title_language == other.title_language
+            && self.query_overrides == other.query_overrides
+            && self.detached == other.detached
+    }
+}
+
+/// Returns whether the error happened before a response was received.
+///
+/// # Arguments
+///
+/// * `error` - The error returned by `reqwest`.
+fn is_connection_error(error: &reqwest::Error) -> bool {
+    #[cfg(not(target_arch = "wasm32"))]
+    if error.is_connect() {
+        return true;
+    }
+
+    error.is_timeout() || error.is_request()
+}
+
+/// Returns whether the GraphQL document is a mutation.
+///
+/// # Arguments
+///
+/// * `query` - The GraphQL document.
+fn is_mutation(query: &str) -> bool {
+    query
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#'))
+        .is_some_and(|line| line.starts_with("mutation"))
+}
+
+/// Returns the variables of a search page with the given filters.
+///
+/// # Arguments
+///
+/// * `options` - The filters of the medias.
+/// * `page` - The page number to get.
+/// * `per_page` - The number of medias to get per page.
+fn search_variables(options: &MediaSearchOptions, page: u16, per_page: u16) -> serde_json::Value {
+    let mut variables = options.variables();
+    variables["page"] = page.into();
+    variables["per_page"] = per_page.into();
+
+    variables
+}
+
+/// Returns the current time as a Unix timestamp.
+fn unix_now() -> i64 {
+    time::SystemTime::now()
+        .duration_since(time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+/// Parses the animes of a search page, or of a media connection whose
+/// nodes are aliased to `media`.
+///
+/// # Arguments
+///
+/// * `client` - The client given to the animes.
+/// * `page` - The `Page` object or the connection of the response.
+fn parse_animes(client: &Client, page: &serde_json::Value) -> Vec<Anime> {
+    page["media"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|media| parse_anime(client, media).ok())
+        .collect()
+}
+
+/// Parses an anime holding only the fields of a search result.
+///
+/// The fields missing from the object are left to their default.
+///
+/// # Arguments
+///
+/// * `client` - The client given to the anime.
+/// * `media` - The `Media` object of the anime.
+///
+/// # Errors
+///
+/// Returns an error if the object has no ID.
+fn parse_anime(client: &Client, media: &serde_json::Value) -> Result<Anime> {
+    let mut anime = Anime {
+        id: i64::deserialize(&media["id"])?.into(),
+        id_mal: media["idMal"].as_i64(),
+        title: Title::deserialize(&media["title"])
+            .unwrap_or_default()
+            .with_language(client.title_language),
+        format: Format::deserialize(&media["format"]).unwrap_or_default(),
+        status: Status::deserialize(&media["status"]).unwrap_or_default(),
+        description: media["description"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        cover: Cover::deserialize(&media["coverImage"]).unwrap_or_default(),
+        banner: media["bannerImage"].as_str().map(String::from),
+        average_score: media["averageScore"].as_u64().map(|x| x as u8),
+        mean_score: media["meanScore"].as_u64().map(|x| x as u8),
+        is_adult: media["isAdult"].as_bool().unwrap_or_default(),
+        url: media["siteUrl"].as_str().unwrap_or_default().to_string(),
+
+        client: client.clone(),
+        ..Default::default()
+    };
+    anime.fill_url();
+
+    Ok(anime)
+}
+
+/// Parses the preview of a media.
+///
+/// # Arguments
+///
+/// * `client` - The client whose title language is applied.
+/// * `media` - The `Media` object of the preview.
+fn parse_media_preview(client: &Client, media: &serde_json::Value) -> MediaPreview {
+    MediaPreview {
+        id: media["id"].as_i64().unwrap_or_default(),
+        title: Title::deserialize(&media["title"])
+            .unwrap_or_default()
+            .with_language(client.title_language),
+        cover: media["coverImage"]["large"].as_str().map(String::from),
+        format: Format::deserialize(&media["format"]).ok(),
+        season_year: media["seasonYear"].as_u64().map(|year| year as u32),
+    }
+}
+
+/// Parses the mangas of a search page.
+///
+/// # Arguments
+///
+/// * `client` - The client given to the mangas.
+/// * `page` - The `Page` object of the response.
+fn parse_mangas(client: &Client, page: &serde_json::Value) -> Vec<Manga> {
+    page["media"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|media| parse_manga(client, media).ok())
+        .collect()
+}
+
+/// Parses a manga holding only the fields of a search result.
+///
+/// The fields missing from the object are left to their default.
+///
+/// # Arguments
+///
+/// * `client` - The client given to the manga.
+/// * `media` - The `Media` object of the manga.
+///
+/// # Errors
+///
+/// Returns an error if the object has no ID.
+fn parse_manga(client: &Client, media: &serde_json::Value) -> Result<Manga> {
+    Ok(Manga {
+        id: i64::deserialize(&media["id"])?.into(),
+        id_mal: media["idMal"].as_i64(),
+        title: Title::deserialize(&media["title"])
+            .unwrap_or_default()
+            .with_language(client.title_language),
+        format: Format::deserialize(&media["format"]).unwrap_or_default(),
+        status: Status::deserialize(&media["status"]).unwrap_or_default(),
+        description: media["description"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        cover: Cover::deserialize(&media["coverImage"]).unwrap_or_default(),
+        banner: media["bannerImage"].as_str().map(String::from),
+        average_score: media["averageScore"].as_u64().map(|x| x as u8),
+        mean_score: media["meanScore"].as_u64().map(|x| x as u8),
+        is_adult: media["isAdult"].as_bool().unwrap_or_default(),
+        url: media["siteUrl"].as_str().unwrap_or_default().to_string(),
+
+        client: client.clone(),
+        ..Default::default()
+    })
+}
+
+/// Parses the users of a search page.
+///
+/// # Arguments
+///
+/// * `client` - The client given to the users.
+/// * `page` - The `Page` object of the response.
+fn parse_users(client: &Client, page: &serde_json::Value) -> Vec<User> {
+    page["users"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|user| parse_user(client, user).ok())
+        .collect()
+}
+
+/// Parses a user holding only the fields of a search result.
+///
+/// The fields missing from the object are left to their default.
+///
+/// # Arguments
+///
+/// * `client` - The client given to the user.
+/// * `user` - The `User` object.
+///
+/// # Errors
+///
+/// Returns an error if the object has no ID.
+fn parse_user(client: &Client, user: &serde_json::Value) -> Result<User> {
+    Ok(User {
+        id: i32::deserialize(&user["id"])?.into(),
+        name: user["name"].as_str().unwrap_or_default().to_string(),
+        about: user["about"].as_str().map(String::from),
+        avatar: Image::deserialize(&user["avatar"]).ok(),
+        banner: user["bannerImage"].as_str().map(String::from),
+
+        client: client.clone(),
+        ..Default::default()
+    })
+}
+
+/// Parses the characters of a character connection.
+///
+/// # Arguments
+///
+/// * `client` - The client given to the characters.
+/// * `connection` - The character connection of the response.
+fn parse_characters(client: &Client, connection: &serde_json::Value) -> Vec<Character> {
+    connection["nodes"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|node| Character::deserialize(node).ok())
+        .map(|mut character| {
+            character.client = client.clone();
+            character
+        })
+        .collect()
+}
+
+/// Parses the characters of a media's character connection, with their
+/// role and voice actors.
+///
+/// # Arguments
+///
+/// * `client` - The client given to the characters.
+/// * `connection` - The character connection of the response.
+fn parse_character_edges(client: &Client, connection: &serde_json::Value) -> Vec<Character> {
+    character_edges(connection)
+        .into_iter()
+        .map(|mut character| {
+            character.set_client(client);
+            character
+        })
+        .collect()
+}
+
+/// Parses the people of a staff connection.
+///
+/// # Arguments
+///
+/// * `client` - The client given to the people.
+/// * `connection` - The staff connection of the response.
+fn parse_people(client: &Client, connection: &serde_json::Value) -> Vec<Person> {
+    connection["nodes"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|node| Person::deserialize(node).ok())
+        .map(|mut person| {
+            person.client = client.clone();
+            person
+        })
+        .collect()
+}
+
+/// Parses the people of a media's staff connection, with their role.
+///
+/// # Arguments
+///
+/// * `client` - The client given to the people.
+/// * `connection` - The staff connection of the response.
+fn parse_staff_edges(client: &Client, connection: &serde_json::Value) -> Vec<Person> {
+    staff_edges(connection)
+        .into_iter()
+        .map(|mut person| {
+            person.set_client(client);
+            person
+        })
+        .collect()
+}
+
+/// Parses the studios of a studio connection.
+///
+/// # Arguments
+///
+/// * `client` - The client given to the studios.
+/// * `connection` - The studio connection of the response.
+fn parse_studios(client: &Client, connection: &serde_json::Value) -> Vec<Studio> {
+    connection["nodes"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|node| Studio::deserialize(node).ok())
+        .map(|mut studio| {
+            studio.client = client.clone();
+            studio
+        })
+        .collect()
+}
+
+/// Parses the medias of a character from its media connection.
+///
+/// # Arguments
+///
+/// * `client` - The client given to the medias.
+/// * `connection` - The media connection of the character.
+fn parse_character_medias(client: &Client, connection: &serde_json::Value) -> Vec<CharacterMedia> {
+    connection["edges"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|edge| CharacterMediaEdge::deserialize(edge).ok())
+        .map(|edge| {
+            let mut media = CharacterMedia::from(edge);
+            attach_character_media(client, &mut media);
+
+            media
+        })
+        .collect()
+}
+
+/// Gives the client to the voice actors of a character media and applies
+/// its title language.
+///
+/// # Arguments
+///
+/// * `client` - The client given to the voice actors.
+/// * `media` - The character media.
+fn attach_character_media(client: &Client, media: &mut CharacterMedia) {
+    media.title = media.title.clone().with_language(client.title_language());
+    for voice_actor in &mut media.voice_actors {
+        voice_actor.client = client.clone();
+    }
+}
+
+/// Parses the notifications of a page.
+///
+/// The users and the animes sent along with the notifications only hold
+/// the fields of a search result, and are left out when they have no ID.
+///
+/// # Arguments
+///
+/// * `client` - The client given to the users and the animes.
+/// * `page` - The `Page` object of the response.
+fn parse_notifications(client: &Client, page: &serde_json::Value) -> Vec<Notification> {
+    page["notifications"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .map(|node| {
+            let mut fields = node.clone();
+            let (media, user) = match fields.as_object_mut() {
+                Some(fields) => (fields.remove("media"), fields.remove("user")),
+                None => (None, None),
+            };
+            let media = media
+                .filter(serde_json::Value::is_object)
+                .and_then(|media| parse_anime(client, &media).ok());
+            let user = user
+                .filter(serde_json::Value::is_object)
+                .and_then(|user| parse_user(client, &user).ok());
+
+            match Notification::deserialize(&fields) {
+                Ok(Notification::Airing(mut notification)) => {
+                    notification.media = media;
+                    Notification::Airing(notification)
+                }
+                Ok(Notification::Following(mut notification)) => {
+                    notification.user = user;
+                    Notification::Following(notification)
+                }
+                Ok(Notification::Other(_)) | Err(_) => Notification::Other(node.clone()),
+                Ok(mut notification) => {
+                    if let Some(activity) = notification.activity_mut() {
+                        activity.user = user;
+                    }
+                    notification
+                }
+            }
+        })
+        .collect()
+}
+
+/// Returns the `data` object of a GraphQL response.
+///
+/// # Arguments
+///
+/// * `response` - The GraphQL response.
+///
+/// # Errors
+///
+/// Returns an error holding the messages of the `errors` array, if any.
+fn graphql_data(mut response: serde_json::Value) -> Result<serde_json::Value> {
+    if let Some(messages) = graphql_error_messages(&response) {
+        return Err(Error::ApiError(messages));
+    }
+
+    Ok(response["data"].take())
+}
+
+/// Returns the messages of the `errors` array of a GraphQL response,
+/// joined by semicolons, or `None` if there are none.
+///
+/// # Arguments
+///
+/// * `response` - The GraphQL response.
+fn graphql_error_messages(response: &serde_json::Value) -> Option<String> {
+    let errors = response["errors"].as_array().filter(|e| !e.is_empty())?;
+
+    let messages = errors
+        .iter()
+        .map(|error| error["message"].as_str().unwrap_or("unknown error"))
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    Some(messages)
+}
+
+/// Represents an action that can be performed by the client.
+///
+/// The `Action` enum defines various actions that the client can perform,
+/// such as getting media by ID or searching for media.
+enum Action {
+    /// Get media by ID.
+    Get,
+    /// Search for media.
+    Search,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_with_timeout() {
+        let duration = Duration::from_secs(30);
+        let client = Client::with_timeout(duration);
+
+        assert_eq!(client.timeout, duration);
+        assert!(client.api_token.is_none());
+    }
+
+    #[test]
+    fn test_with_token() {
+        let api_token = "test_token";
+        let client = Client::with_token(api_token);
+
+        assert_eq!(client.timeout, Duration::from_secs(20));
+        assert_eq!(client.api_token, Some(api_token.to_string()));
+    }
+
+    #[test]
+    fn test_timeout_of_a_clone() {
+        let client = Client::default();
+        let slow = client.clone().timeout(Duration::from_secs(60));
+
+        assert_eq!(client.timeout, Duration::from_secs(20));
+        assert_eq!(slow.timeout, Duration::from_secs(60));
+        assert!(Arc::ptr_eq(
+            &client.rate_limit_status,
+            &slow.rate_limit_status
+        ));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let initial_duration = Duration::from_secs(30);
+        let new_duration = Duration::from_secs(60);
+        let client = Client::with_timeout(initial_duration).timeout(new_duration);
+
+        assert_eq!(client.timeout, new_duration);
+    }
+
+    #[test]
+    fn test_token() {
+        let initial_token = "initial_token";
+        let new_token = "new_token";
+        let client = Client::with_token(initial_token).token(new_token);
+
+        assert_eq!(client.api_token, Some(new_token.to_string()));
+    }
+
+    #[test]
+    fn test_retry_on_rate_limit() {
+        let client = Client::default().retry_on_rate_limit(3, Duration::from_secs(90));
+
+        assert_eq!(client.rate_limit_retry.max_attempts, 3);
+        assert_eq!(client.rate_limit_retry.max_wait, Duration::from_secs(90));
+        assert!(client.rate_limit_status().is_none());
+    }
+
+    #[test]
+    fn test_retry_policy() {
+        let policy = RetryPolicy::default().max_attempts(5);
+        let client = Client::default().retry_policy(policy.clone());
+
+        assert_eq!(client.retry_policy, policy);
+        assert_eq!(Client::default().retry_policy, RetryPolicy::none());
+    }
+
+    #[tokio::test]
+    async fn test_detached() {
+        let client = Client::detached();
+
+        assert!(client.is_detached());
+        assert!(!Client::default().is_detached());
+        assert!(matches!(client.get_anime(1).await, Err(Error::Detached)));
+    }
+
+    #[tokio::test]
+    async fn test_are_favourites_without_token() {
+        let result = Client::default()
+            .are_favourites(&[1], MediaType::Anime)
+            .await;
+
+        assert!(matches!(result, Err(Error::MissingToken)));
+    }
+
+    #[test]
+    fn test_is_mutation() {
+        assert!(is_mutation("# comment\nmutation ($id: Int) { }"));
+        assert!(!is_mutation("# comment\nquery ($id: Int) { }"));
+        assert!(!is_mutation(queries::GET_ANIME));
+        assert!(is_mutation(queries::SAVE_TEXT_ACTIVITY));
+    }
+
+    #[test]
+    fn test_unloaded_handles() {
+        let client = Client::with_token("token");
+        let anime = client.anime(1);
+        let studio = client.studio(14);
+
+        assert_eq!(anime.id, 1);
+        assert!(!anime.is_full_loaded);
+        assert_eq!(anime.client, client);
+        assert_eq!(client.character(2).id, 2);
+        assert_eq!(client.person(3).client, client);
+        assert_eq!(studio.id, 14);
+        assert!(studio.medias.is_none());
+    }
+
+    #[test]
+    fn test_graphql_data() {
+        let data = graphql_data(serde_json::json!({ "data": { "Media": { "id": 1 } } }));
+
+        assert_eq!(data.unwrap()["Media"]["id"], 1);
+    }
+
+    #[test]
+    fn test_graphql_data_with_errors() {
+        let error = graphql_data(serde_json::json!({
+            "data": null,
+            "errors": [
+                { "message": "Not Found.", "status": 404 },
+                { "message": "Invalid token", "status": 400 }
+            ]
+        }))
+        .unwrap_err();
+
+        assert_eq!(error.to_string(), "api error: `Not Found.; Invalid token`");
+    }
+
+    #[test]
+    fn test_clones_share_rate_limit_status() {
+        let client = Client::default();
+        let clone = client.clone();
+        *client.rate_limit_status.lock().unwrap() = Some(RateLimitStatus {
+            remaining: Some(42),
+            ..Default::default()
+        });
+
+        assert_eq!(clone.rate_limit_status().unwrap().remaining, Some(42));
+    }
+}
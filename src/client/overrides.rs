@@ -0,0 +1,244 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Operation` enum, naming the GraphQL
+//! documents a client can be configured to replace.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::transport::operation_name;
+use crate::{queries, Error, Result};
+
+/// Represents a GraphQL operation sent by the client, whose document can
+/// be replaced with [`ClientBuilder::override_query`].
+///
+/// [`ClientBuilder::override_query`]: super::ClientBuilder::override_query
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Operation {
+    /// Gets an anime, used by `Client::get_anime`.
+    GetAnime,
+    /// Gets a manga, used by `Client::get_manga`.
+    GetManga,
+    /// Gets a character, used by `Client::get_character`.
+    GetCharacter,
+    /// Gets a person, used by `Client::get_person`.
+    GetPerson,
+    /// Gets a studio, used by `Client::get_studio`.
+    GetStudio,
+    /// Gets a user, used by `Client::get_user`.
+    GetUser,
+    /// Searches animes, used by `Client::search_anime`.
+    SearchAnime,
+    /// Searches mangas, used by `Client::search_manga`.
+    SearchManga,
+    /// Searches studios, used by `Client::search_studio`.
+    SearchStudio,
+    /// Searches users, used by `Client::search_user`.
+    SearchUser,
+}
+
+impl Operation {
+    /// Returns the name of the operation, as written in its document.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::Operation;
+    /// assert_eq!(Operation::GetAnime.name(), "GetAnime");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            Operation::GetAnime => "GetAnime",
+            Operation::GetManga => "GetManga",
+            Operation::GetCharacter => "GetCharacter",
+            Operation::GetPerson => "GetPerson",
+            Operation::GetStudio => "GetStudio",
+            Operation::GetUser => "GetUser",
+            Operation::SearchAnime => "SearchAnime",
+            Operation::SearchManga => "SearchManga",
+            Operation::SearchStudio => "SearchStudio",
+            Operation::SearchUser => "SearchUser",
+        }
+    }
+
+    /// Returns the document sent for the operation by default.
+    pub fn default_query(&self) -> &'static str {
+        match self {
+            Operation::GetAnime => queries::GET_ANIME,
+            Operation::GetManga => queries::GET_MANGA,
+            Operation::GetCharacter => queries::GET_CHARACTER,
+            Operation::GetPerson => queries::GET_PERSON,
+            Operation::GetStudio => queries::GET_STUDIO,
+            Operation::GetUser => queries::GET_USER,
+            Operation::SearchAnime => queries::SEARCH_ANIME,
+            Operation::SearchManga => queries::SEARCH_MANGA,
+            Operation::SearchStudio => queries::SEARCH_STUDIO,
+            Operation::SearchUser => queries::SEARCH_USER,
+        }
+    }
+
+    /// Returns the top-level field the client reads the response from.
+    pub fn required_field(&self) -> &'static str {
+        match self {
+            Operation::GetAnime | Operation::GetManga => "Media",
+            Operation::GetCharacter => "Character",
+            Operation::GetPerson => "Staff",
+            Operation::GetStudio => "Studio",
+            Operation::GetUser => "User",
+            Operation::SearchAnime
+            | Operation::SearchManga
+            | Operation::SearchStudio
+            | Operation::SearchUser => "Page",
+        }
+    }
+
+    /// Checks that a document can replace the one of the operation.
+    ///
+    /// The document must keep the name of the operation, so hooks,
+    /// traces and mock transports see the same operation, and select
+    /// its top-level field.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The replacing document.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidQuery`] if the document doesn't qualify.
+    pub(crate) fn validate(&self, query: &str) -> Result<()> {
+        if operation_name(query) != Some(self.name()) {
+            return Err(Error::InvalidQuery(format!(
+                "the document must define the `{}` operation",
+                self.name()
+            )));
+        }
+
+        if !selects_field(query, self.required_field()) {
+            return Err(Error::InvalidQuery(format!(
+                "the `{}` operation must select the `{}` field",
+                self.name(),
+                self.required_field()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns whether a GraphQL document selects a field, with arguments or
+/// a selection set.
+///
+/// # Arguments
+///
+/// * `query` - The GraphQL document.
+/// * `field` - The name of the field.
+fn selects_field(query: &str, field: &str) -> bool {
+    let is_name = |c: char| c.is_alphanumeric() || c == '_';
+
+    query.match_indices(field).any(|(start, _)| {
+        let before = query[..start].chars().next_back();
+        let after = query[start + field.len()..].trim_start();
+
+        !before.is_some_and(is_name) && (after.starts_with('(') || after.starts_with('{'))
+    })
+}
+
+/// The documents replacing the default ones of some operations.
+///
+/// Clones share the same documents.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct QueryOverrides {
+    /// The replacing documents, by operation.
+    queries: Arc<HashMap<Operation, String>>,
+}
+
+impl QueryOverrides {
+    /// Replaces the document of an operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The operation to override.
+    /// * `query` - The replacing document.
+    pub fn insert(&mut self, operation: Operation, query: &str) {
+        Arc::make_mut(&mut self.queries).insert(operation, query.to_string());
+    }
+
+    /// Returns the document to send in place of the given one.
+    ///
+    /// Only the default documents of the overridden operations are
+    /// replaced, so documents sent through `Client::query_raw` are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The document about to be sent.
+    pub fn resolve<'a>(&'a self, query: &'a str) -> &'a str {
+        self.queries
+            .iter()
+            .find(|(operation, _)| operation.default_query() == query)
+            .map_or(query, |(_, replacement)| replacement)
+    }
+}
+
+impl PartialEq for QueryOverrides {
+    fn eq(&self, other: &Self) -> bool {
+        self.queries == other.queries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_queries_are_valid() {
+        let operations = [
+            Operation::GetAnime,
+            Operation::GetManga,
+            Operation::GetCharacter,
+            Operation::GetPerson,
+            Operation::GetStudio,
+            Operation::GetUser,
+            Operation::SearchAnime,
+            Operation::SearchManga,
+            Operation::SearchStudio,
+            Operation::SearchUser,
+        ];
+
+        for operation in operations {
+            assert!(operation.validate(operation.default_query()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let operation = Operation::GetStudio;
+
+        assert!(operation
+            .validate("query GetStudio($id: Int) { Studio(id: $id) { id name } }")
+            .is_ok());
+        assert!(matches!(
+            operation.validate("query GetStudios($id: Int) { Studio(id: $id) { id } }"),
+            Err(Error::InvalidQuery(_))
+        ));
+        assert!(matches!(
+            operation.validate("query GetStudio($id: Int) { Studios(id: $id) { id } }"),
+            Err(Error::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_resolve() {
+        let query = "query GetStudio($id: Int) { Studio(id: $id) { id } }";
+        let mut overrides = QueryOverrides::default();
+
+        assert_eq!(overrides.resolve(queries::GET_STUDIO), queries::GET_STUDIO);
+
+        overrides.insert(Operation::GetStudio, query);
+
+        assert_eq!(overrides.resolve(queries::GET_STUDIO), query);
+        assert_eq!(overrides.resolve(queries::GET_ANIME), queries::GET_ANIME);
+        assert_eq!(overrides.resolve(query), query);
+    }
+}
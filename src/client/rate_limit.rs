@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `RateLimitStatus` struct and its related types.
+
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+
+use super::time::{SystemTime, UNIX_EPOCH};
+
+/// Represents the last rate-limit state reported by the AniList API.
+///
+/// AniList allows a fixed number of requests per minute and reports
+/// the remaining budget through the `X-RateLimit-*` headers of every
+/// response.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct RateLimitStatus {
+    /// The maximum number of requests allowed per minute.
+    pub limit: Option<u32>,
+    /// The number of requests remaining in the current window.
+    pub remaining: Option<u32>,
+    /// The unix timestamp at which the rate limit resets.
+    pub reset: Option<i64>,
+    /// The time to wait before retrying, if the last request was rate limited.
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimitStatus {
+    /// Reads the rate-limit status from the headers of a response.
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Self {
+        fn header<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse().ok())
+        }
+
+        Self {
+            limit: header(headers, "X-RateLimit-Limit"),
+            remaining: header(headers, "X-RateLimit-Remaining"),
+            reset: header(headers, "X-RateLimit-Reset"),
+            retry_after: header(headers, "Retry-After").map(Duration::from_secs),
+        }
+    }
+
+    /// Returns whether the request budget of the current window is exhausted.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == Some(0)
+    }
+
+    /// Returns how long to wait before the next request, if the budget
+    /// is exhausted.
+    ///
+    /// The wait lasts until the reset of the window when it is known,
+    /// and a whole window of one minute otherwise.
+    pub(crate) fn wait(&self) -> Option<Duration> {
+        if !self.is_exhausted() {
+            return None;
+        }
+
+        let window = Duration::from_secs(60);
+        let wait = match (self.retry_after, self.reset) {
+            (Some(retry_after), _) => retry_after,
+            (None, Some(reset)) => {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs() as i64;
+                Duration::from_secs(reset.saturating_sub(now).max(0) as u64)
+            }
+            (None, None) => window,
+        };
+
+        Some(wait.min(window))
+    }
+}
+
+/// Represents how the client reacts to rate-limited (`429`) responses.
+///
+/// By default the client does not retry, returning
+/// [`Error::RateLimited`](crate::Error::RateLimited) immediately.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub(crate) struct RateLimitRetry {
+    /// The maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// The longest `Retry-After` the client is willing to sleep for.
+    pub max_wait: Duration,
+}
+
+impl Default for RateLimitRetry {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            max_wait: Duration::ZERO,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-RateLimit-Limit", HeaderValue::from_static("90"));
+        headers.insert("X-RateLimit-Remaining", HeaderValue::from_static("0"));
+        headers.insert("X-RateLimit-Reset", HeaderValue::from_static("1501762800"));
+        headers.insert("Retry-After", HeaderValue::from_static("30"));
+
+        let status = RateLimitStatus::from_headers(&headers);
+
+        assert_eq!(status.limit, Some(90));
+        assert_eq!(status.remaining, Some(0));
+        assert_eq!(status.reset, Some(1501762800));
+        assert_eq!(status.retry_after, Some(Duration::from_secs(30)));
+        assert!(status.is_exhausted());
+    }
+
+    #[test]
+    fn test_from_headers_missing() {
+        let status = RateLimitStatus::from_headers(&HeaderMap::new());
+
+        assert_eq!(status, RateLimitStatus::default());
+        assert!(!status.is_exhausted());
+    }
+
+    #[test]
+    fn test_wait() {
+        let status = RateLimitStatus {
+            remaining: Some(0),
+            ..Default::default()
+        };
+
+        assert_eq!(status.wait(), Some(Duration::from_secs(60)));
+        assert_eq!(
+            RateLimitStatus {
+                retry_after: Some(Duration::from_secs(5)),
+                ..status
+            }
+            .wait(),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            RateLimitStatus {
+                reset: Some(0),
+                ..status
+            }
+            .wait(),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(RateLimitStatus::default().wait(), None);
+    }
+}
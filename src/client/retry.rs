@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `RetryPolicy` struct and its related types.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The callback called before every retry.
+type RetryCallback = Arc<dyn Fn(&RetryEvent) + Send + Sync>;
+
+/// Represents how the client retries failed requests.
+///
+/// Requests that fail because of a connection problem, a timeout or a
+/// `5xx` response are retried with an exponential backoff. Mutations
+/// are never retried unless explicitly allowed, to avoid double-writes.
+///
+/// Rate-limited (`429`) responses are handled separately, see
+/// [`Client::retry_on_rate_limit`](crate::Client::retry_on_rate_limit).
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use rust_anilist::{Client, RetryPolicy};
+/// let policy = RetryPolicy::default()
+///     .max_attempts(5)
+///     .base_delay(Duration::from_millis(250))
+///     .on_retry(|event| eprintln!("retrying (attempt {})", event.attempt));
+/// let client = Client::default().retry_policy(policy);
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one.
+    pub(crate) max_attempts: u32,
+    /// The delay before the first retry.
+    pub(crate) base_delay: Duration,
+    /// The maximum delay between two attempts.
+    pub(crate) max_delay: Duration,
+    /// Whether to randomize the delays.
+    pub(crate) jitter: bool,
+    /// Whether to retry `5xx` responses.
+    pub(crate) retry_server_errors: bool,
+    /// Whether to retry connection errors and timeouts.
+    pub(crate) retry_connection_errors: bool,
+    /// Whether to retry mutations.
+    pub(crate) retry_mutations: bool,
+    /// The callback called before every retry.
+    pub(crate) on_retry: Option<RetryCallback>,
+}
+
+impl RetryPolicy {
+    /// Creates a policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the maximum number of attempts, including the first one.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempts` - The maximum number of attempts.
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    ///
+    /// The delay doubles after every failed attempt.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - The delay before the first retry.
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Sets the maximum delay between two attempts.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - The maximum delay.
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Sets whether the delays are randomized.
+    ///
+    /// With jitter enabled, every delay is picked between half and the
+    /// full computed delay, so concurrent clients don't retry in lockstep.
+    ///
+    /// # Arguments
+    ///
+    /// * `jitter` - Whether to randomize the delays.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets whether `5xx` responses are retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry` - Whether to retry server errors.
+    pub fn retry_server_errors(mut self, retry: bool) -> Self {
+        self.retry_server_errors = retry;
+        self
+    }
+
+    /// Sets whether connection errors and timeouts are retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry` - Whether to retry connection errors.
+    pub fn retry_connection_errors(mut self, retry: bool) -> Self {
+        self.retry_connection_errors = retry;
+        self
+    }
+
+    /// Sets whether mutations are retried.
+    ///
+    /// Retrying a mutation whose response was lost may apply it twice,
+    /// so this is disabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry` - Whether to retry mutations.
+    pub fn retry_mutations(mut self, retry: bool) -> Self {
+        self.retry_mutations = retry;
+        self
+    }
+
+    /// Sets a callback called before every retry.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - The callback to call.
+    pub fn on_retry<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&RetryEvent) + Send + Sync + 'static,
+    {
+        self.on_retry = Some(Arc::new(callback));
+        self
+    }
+
+    /// Returns whether another attempt is allowed after `attempt` attempts.
+    pub(crate) fn allows(&self, attempt: u32, is_mutation: bool) -> bool {
+        attempt < self.max_attempts && (!is_mutation || self.retry_mutations)
+    }
+
+    /// Returns the delay to wait after the given failed attempt.
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+
+        if self.jitter && !delay.is_zero() {
+            let random = RandomState::new().build_hasher().finish();
+            let half = delay / 2;
+            half + Duration::from_nanos(random % (half.as_nanos() as u64 + 1))
+        } else {
+            delay
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retry_server_errors: true,
+            retry_connection_errors: true,
+            retry_mutations: false,
+            on_retry: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .field("jitter", &self.jitter)
+            .field("retry_server_errors", &self.retry_server_errors)
+            .field("retry_connection_errors", &self.retry_connection_errors)
+            .field("retry_mutations", &self.retry_mutations)
+            .field("on_retry", &self.on_retry.is_some())
+            .finish()
+    }
+}
+
+impl PartialEq for RetryPolicy {
+    fn eq(&self, other: &Self) -> bool {
+        self.max_attempts == other.max_attempts
+            && self.base_delay == other.base_delay
+            && self.max_delay == other.max_delay
+            && self.jitter == other.jitter
+            && self.retry_server_errors == other.retry_server_errors
+            && self.retry_connection_errors == other.retry_connection_errors
+            && self.retry_mutations == other.retry_mutations
+    }
+}
+
+/// Represents a retry about to be made by the client.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryEvent {
+    /// The number of the attempt about to be made, starting at `2`.
+    pub attempt: u32,
+    /// The time the client waits before the attempt.
+    pub delay: Duration,
+    /// The HTTP status of the failed attempt, if a response was received.
+    pub status: Option<u16>,
+    /// A description of why the previous attempt failed.
+    pub reason: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_none() {
+        let policy = RetryPolicy::none();
+
+        assert!(!policy.allows(1, false));
+    }
+
+    #[test]
+    fn test_allows() {
+        let policy = RetryPolicy::default().max_attempts(3);
+
+        assert!(policy.allows(1, false));
+        assert!(policy.allows(2, false));
+        assert!(!policy.allows(3, false));
+        assert!(!policy.allows(1, true));
+        assert!(policy.retry_mutations(true).allows(1, true));
+    }
+
+    #[test]
+    fn test_delay_without_jitter() {
+        let policy = RetryPolicy::default()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(500))
+            .jitter(false);
+
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(3), Duration::from_millis(400));
+        assert_eq!(policy.delay(4), Duration::from_millis(500));
+        assert_eq!(policy.delay(100), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_delay_with_jitter() {
+        let policy = RetryPolicy::default()
+            .base_delay(Duration::from_millis(100))
+            .jitter(true);
+
+        for _ in 0..100 {
+            let delay = policy.delay(2);
+
+            assert!(delay >= Duration::from_millis(100));
+            assert!(delay <= Duration::from_millis(200));
+        }
+    }
+}
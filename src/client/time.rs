@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the clock and timer used by the client.
+//!
+//! `std::time::Instant` panics and tokio timers need a tokio runtime on
+//! `wasm32-unknown-unknown`, so the browser's clock and timers are used
+//! there instead.
+
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::Instant;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::{SystemTime, UNIX_EPOCH};
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web_time::Instant;
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web_time::{SystemTime, UNIX_EPOCH};
+
+/// Waits until the duration has elapsed.
+///
+/// # Arguments
+///
+/// * `duration` - The duration to wait.
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+}
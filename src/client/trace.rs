@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `tracing` instrumentation of the requests.
+
+use std::future::Future;
+use tracing::Instrument;
+
+use super::cache::hash;
+use super::graphql_error_messages;
+use super::time::Instant;
+use super::transport::operation_name;
+use crate::Result;
+
+/// The variables recorded at trace level. They hold IDs and paging only,
+/// never what users write, such as searches, messages or list notes.
+const TRACED_VARIABLES: &[&str] = &[
+    "chunk", "end", "id", "idMal", "mediaId", "mediaIds", "page", "perPage", "per_page", "start",
+    "type", "userId",
+];
+
+/// Creates the span of a GraphQL request.
+///
+/// The API token is never recorded, only whether one is used. The
+/// variables are recorded as a hash, to tell identical requests apart
+/// without logging what was searched, and only the ones in
+/// [`TRACED_VARIABLES`] are recorded in full at trace level.
+///
+/// # Arguments
+///
+/// * `query` - The GraphQL document.
+/// * `variables` - The variables of the request.
+/// * `authenticated` - Whether the request is sent with a token.
+pub(crate) fn span(
+    query: &str,
+    variables: &serde_json::Value,
+    authenticated: bool,
+) -> tracing::Span {
+    let span = tracing::debug_span!(
+        "graphql_request",
+        operation = operation_name(query).unwrap_or("query"),
+        variables_hash = %format_args!("{:016x}", hash(&[&variables.to_string()])),
+        authenticated,
+    );
+    span.in_scope(|| {
+        tracing::trace!(variables = %traced_variables(variables), "request variables");
+    });
+
+    span
+}
+
+/// Keeps the variables of a request that can be logged.
+///
+/// # Arguments
+///
+/// * `variables` - The variables of the request.
+fn traced_variables(variables: &serde_json::Value) -> serde_json::Value {
+    let variables = variables
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(name, _)| TRACED_VARIABLES.contains(&name.as_str()))
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+
+    serde_json::Value::Object(variables)
+}
+
+/// Runs a request inside its span, recording its outcome and duration.
+///
+/// Completed requests are recorded at debug level, failed requests and
+/// the messages of the GraphQL errors of a response at warn level.
+///
+/// # Arguments
+///
+/// * `future` - The request.
+/// * `span` - The span of the request.
+pub(crate) async fn instrument<F>(future: F, span: tracing::Span) -> Result<serde_json::Value>
+where
+    F: Future<Output = Result<serde_json::Value>>,
+{
+    async move {
+        let start = Instant::now();
+        let result = future.await;
+        let elapsed = start.elapsed();
+
+        match &result {
+            Ok(response) => match graphql_error_messages(response) {
+                Some(errors) => tracing::warn!(?elapsed, %errors, "request returned errors"),
+                None => tracing::debug!(?elapsed, "request completed"),
+            },
+            Err(error) => tracing::warn!(?elapsed, %error, "request failed"),
+        }
+
+        result
+    }
+    .instrument(span)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_traced_variables() {
+        let variables = serde_json::json!({
+            "recipientId": 7,
+            "message": "a private message",
+            "notes": "a list note",
+            "search": "bebop",
+            "id": 1,
+            "page": 2
+        });
+
+        assert_eq!(
+            traced_variables(&variables),
+            serde_json::json!({ "id": 1, "page": 2 })
+        );
+        assert_eq!(
+            traced_variables(&serde_json::Value::Null),
+            serde_json::json!({})
+        );
+    }
+}
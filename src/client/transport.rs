@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Transport` trait.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::Result;
+
+/// The future returned by [`Transport::execute`].
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = Result<serde_json::Value>> + Send + 'a>>;
+
+/// Represents the way GraphQL requests are delivered to AniList.
+///
+/// The client sends its requests over HTTP by default. Implementing this
+/// trait allows replacing that step, for example to answer requests
+/// with canned responses in tests.
+///
+/// The body passed to [`Transport::execute`] is the GraphQL request,
+/// holding the `query` and its `variables`, and the returned value is
+/// the GraphQL response, holding the `data` and the `errors`, if any.
+///
+/// Returning [`Error::RateLimited`](crate::Error::RateLimited),
+/// [`Error::HttpError`](crate::Error::HttpError) or
+/// [`Error::ConnectionError`](crate::Error::ConnectionError) lets the
+/// client apply its retry policies.
+///
+/// # Example
+///
+/// ```
+/// # use rust_anilist::{Client, Transport, TransportFuture};
+/// struct Offline;
+///
+/// impl Transport for Offline {
+///     fn execute(&self, _body: serde_json::Value) -> TransportFuture<'_> {
+///         Box::pin(async { Ok(serde_json::json!({ "data": null })) })
+///     }
+/// }
+///
+/// let client = Client::with_transport(Offline);
+/// ```
+pub trait Transport: Send + Sync {
+    /// Executes a GraphQL request and returns its response.
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - The GraphQL request.
+    fn execute(&self, body: serde_json::Value) -> TransportFuture<'_>;
+}
+
+/// Returns the name of the operation defined by a GraphQL document.
+///
+/// # Arguments
+///
+/// * `query` - The GraphQL document.
+pub(crate) fn operation_name(query: &str) -> Option<&str> {
+    let line = query
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#'))?;
+    let line = line
+        .strip_prefix("query")
+        .or_else(|| line.strip_prefix("mutation"))?;
+    let name = line
+        .trim_start()
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .next()?;
+
+    Some(name).filter(|name| !name.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operation_name() {
+        assert_eq!(
+            operation_name("# comment\nquery GetAnime($id: Int) {"),
+            Some("GetAnime")
+        );
+        assert_eq!(operation_name("mutation SaveEntry {"), Some("SaveEntry"));
+        assert_eq!(operation_name("query ($id: Int) {"), None);
+        assert_eq!(operation_name("{ Media { id } }"), None);
+    }
+}
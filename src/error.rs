@@ -18,10 +18,130 @@ pub enum Error {
     /// An error indicating that the ID is invalid.
     #[error("invalid ID")]
     InvalidId,
+    /// An error indicating that the operation requires an API token.
+    #[error("an API token is required for this operation")]
+    MissingToken,
+    /// An error indicating that the media type is not supported.
+    #[error("invalid media type")]
+    InvalidMediaType,
+    /// An error indicating that the URL is not a supported AniList URL.
+    #[error("invalid AniList URL: `{0}`")]
+    InvalidUrl(String),
+    /// An error indicating that a request was made through a detached
+    /// client.
+    ///
+    /// Attach a client to the model to send requests again.
+    #[error("the client is detached, attach one to send requests")]
+    Detached,
+    /// An error indicating that nothing matches a name confidently.
+    ///
+    /// Search for the candidates to pick one of them.
+    #[error("nothing matches `{0}` confidently")]
+    NoMatch(String),
+    /// An error indicating that a GraphQL document given to replace the
+    /// one of an operation is not suitable.
+    #[error("invalid query override: {0}")]
+    InvalidQuery(String),
     /// An error indicating that the API returned an error.
     #[error("api error: `{0}`")]
     ApiError(String),
+    /// An error indicating that the API could not be reached.
+    #[error("connection error: `{0}`")]
+    ConnectionError(String),
+    /// An error indicating that the API answered with an unexpected HTTP status.
+    #[error("http error: status {0}")]
+    HttpError(u16),
+    /// An error indicating that the API rate limit was exceeded.
+    #[error("rate limited, retry after {retry_after:?}")]
+    RateLimited {
+        /// The time to wait before retrying, if reported by the API.
+        retry_after: Option<std::time::Duration>,
+    },
+    /// An error indicating that a MyAnimeList XML export could not be
+    /// read.
+    #[error("invalid MyAnimeList XML: `{0}`")]
+    InvalidXml(String),
     /// An error indicating that the API returned an invalid response.
     #[error("Failed to parse JSON")]
     JsonParseError(#[from] serde_json::Error),
 }
+
+impl Error {
+    /// Returns the kind of the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::{Error, ErrorKind};
+    /// assert_eq!(Error::HttpError(503).kind(), ErrorKind::Server);
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::InvalidId
+            | Error::MissingToken
+            | Error::InvalidMediaType
+            | Error::InvalidUrl(_)
+            | Error::InvalidQuery(_)
+            | Error::Detached => ErrorKind::Usage,
+            Error::NoMatch(_) => ErrorKind::NotFound,
+            Error::ApiError(_) => ErrorKind::Api,
+            Error::ConnectionError(_) => ErrorKind::Connection,
+            Error::HttpError(status) if *status >= 500 => ErrorKind::Server,
+            Error::HttpError(_) => ErrorKind::Http,
+            Error::RateLimited { .. } => ErrorKind::RateLimited,
+            Error::InvalidXml(_) | Error::JsonParseError(_) => ErrorKind::Parse,
+        }
+    }
+
+    /// Returns a copy of the error, to deliver it to several callers.
+    ///
+    /// A JSON error keeps its message but loses its position.
+    #[cfg(feature = "client")]
+    pub(crate) fn duplicate(&self) -> Self {
+        match self {
+            Error::InvalidId => Error::InvalidId,
+            Error::MissingToken => Error::MissingToken,
+            Error::InvalidMediaType => Error::InvalidMediaType,
+            Error::InvalidUrl(url) => Error::InvalidUrl(url.clone()),
+            Error::InvalidQuery(reason) => Error::InvalidQuery(reason.clone()),
+            Error::Detached => Error::Detached,
+            Error::NoMatch(name) => Error::NoMatch(name.clone()),
+            Error::ApiError(message) => Error::ApiError(message.clone()),
+            Error::ConnectionError(reason) => Error::ConnectionError(reason.clone()),
+            Error::HttpError(status) => Error::HttpError(*status),
+            Error::RateLimited { retry_after } => Error::RateLimited {
+                retry_after: *retry_after,
+            },
+            Error::InvalidXml(reason) => Error::InvalidXml(reason.clone()),
+            Error::JsonParseError(error) => {
+                Error::JsonParseError(serde::de::Error::custom(error.to_string()))
+            }
+        }
+    }
+}
+
+/// Represents a broad classification of errors.
+///
+/// This is useful to label metrics without matching on every variant
+/// of [`Error`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The library was misused, e.g. an operation requiring a token was
+    /// called without one.
+    Usage,
+    /// Nothing matched the request.
+    NotFound,
+    /// The API answered with an error.
+    Api,
+    /// The API could not be reached or did not answer in time.
+    Connection,
+    /// The API answered with a `5xx` status.
+    Server,
+    /// The API answered with an unexpected HTTP status.
+    Http,
+    /// The API rate limit was exceeded.
+    RateLimited,
+    /// The response could not be parsed.
+    Parse,
+}
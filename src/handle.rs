@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Client` placeholder of models-only builds.
+
+use crate::models::TitleLanguage;
+
+/// Stands in for the HTTP client when the `client` feature is disabled.
+///
+/// The models keep a `client` field so their layout doesn't depend on
+/// the features; without the `client` feature it holds nothing.
+#[derive(Debug, Default, Clone, Eq, Hash, PartialEq)]
+pub(crate) struct Client;
+
+impl Client {
+    /// Returns the placeholder, which never sends requests anyway.
+    #[cfg(feature = "test-util")]
+    pub(crate) fn detached() -> Self {
+        Client
+    }
+
+    /// Returns the language titles are displayed in, which is never set.
+    pub(crate) fn title_language(&self) -> Option<TitleLanguage> {
+        None
+    }
+}
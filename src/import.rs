@@ -0,0 +1,678 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the types used to import a list exported by
+//! MyAnimeList.
+//!
+//! A list is read with [`parse_mal_xml`], then saved to the list of the
+//! authenticated user with `Client::import_entries`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use rust_anilist::import::{self, ConflictPolicy, ImportOptions};
+//! # async fn f(client: rust_anilist::Client) -> Result<(), Box<dyn std::error::Error>> {
+//! let entries = import::parse_mal_xml(std::fs::File::open("animelist.xml")?)?;
+//! let options = ImportOptions::new()
+//!     .conflict_policy(ConflictPolicy::MergeHigherProgress)
+//!     .on_progress(|progress| eprintln!("{}/{}", progress.done, progress.total));
+//! let report = client.import_entries(&entries, options).await?;
+//! println!("{} entries created", report.created.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::sync::Arc;
+
+use crate::models::{Date, MediaListStatus, MediaType};
+use crate::{Error, Result};
+
+/// The callback called after every imported entry.
+type ProgressCallback = Arc<dyn Fn(&ImportProgress) + Send + Sync>;
+
+/// Represents an entry of a list exported by MyAnimeList.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ImportedEntry {
+    /// The ID of the anime or manga on MyAnimeList.
+    pub mal_id: i64,
+    /// The type of the media, either anime or manga.
+    pub media_type: MediaType,
+    /// The title of the media.
+    pub title: String,
+    /// The status of the media in the list.
+    pub status: Option<MediaListStatus>,
+    /// The score given by the user, from 0 to 100.
+    ///
+    /// A score of `0` means the media was not scored.
+    pub score: f32,
+    /// The number of episodes watched or chapters read.
+    pub progress: u32,
+    /// The number of volumes read.
+    pub progress_volumes: Option<u32>,
+    /// The number of times the media was rewatched or reread.
+    pub repeat: u32,
+    /// The notes of the user.
+    pub notes: Option<String>,
+    /// When the user started the media.
+    pub started_at: Option<Date>,
+    /// When the user completed the media.
+    pub completed_at: Option<Date>,
+}
+
+impl ImportedEntry {
+    /// Returns the variables saving the entry to the list of the
+    /// authenticated user.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_id` - The AniList ID of the media.
+    #[cfg(feature = "client")]
+    pub(crate) fn variables(&self, media_id: i64) -> serde_json::Value {
+        let mut variables = serde_json::json!({
+            "mediaId": media_id,
+            "scoreRaw": self.score.round() as i32,
+            "progress": self.progress,
+            "repeat": self.repeat,
+        });
+        if let Some(status) = self.status {
+            variables["status"] = serde_json::json!(status);
+        }
+        if let Some(progress_volumes) = self.progress_volumes {
+            variables["progressVolumes"] = progress_volumes.into();
+        }
+        if let Some(notes) = &self.notes {
+            variables["notes"] = notes.as_str().into();
+        }
+        if let Some(started_at) = &self.started_at {
+            variables["startedAt"] = serde_json::json!(started_at);
+        }
+        if let Some(completed_at) = &self.completed_at {
+            variables["completedAt"] = serde_json::json!(completed_at);
+        }
+
+        variables
+    }
+}
+
+/// Reads the entries of a list exported by MyAnimeList.
+///
+/// Both anime and manga lists are supported, in the XML format
+/// MyAnimeList exports and imports. Scores are converted to the
+/// 100-point scale and rewatches to the
+/// [`MediaListStatus::Repeating`] status.
+///
+/// # Arguments
+///
+/// * `reader` - The XML document.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidXml`] if the document can't be read, is not
+/// a MyAnimeList export or holds an entry without a valid ID.
+///
+/// # Example
+///
+/// ```
+/// # use rust_anilist::import::parse_mal_xml;
+/// let xml = "<myanimelist><anime>\
+///     <series_animedb_id>1</series_animedb_id>\
+///     <my_status>Completed</my_status>\
+///     <my_score>9</my_score>\
+///     </anime></myanimelist>";
+///
+/// let entries = parse_mal_xml(xml.as_bytes()).unwrap();
+/// assert_eq!(entries[0].mal_id, 1);
+/// assert_eq!(entries[0].score, 90.0);
+/// ```
+pub fn parse_mal_xml<R: Read>(mut reader: R) -> Result<Vec<ImportedEntry>> {
+    let mut xml = String::new();
+    reader
+        .read_to_string(&mut xml)
+        .map_err(|error| Error::InvalidXml(format!("cannot read the document: {error}")))?;
+
+    let (_, root) = elements(&xml)?
+        .into_iter()
+        .find(|(name, _)| *name == "myanimelist")
+        .ok_or_else(|| Error::InvalidXml("no `myanimelist` element".to_string()))?;
+
+    let mut entries = Vec::new();
+    for (name, content) in elements(root)? {
+        let media_type = match name {
+            "anime" => MediaType::Anime,
+            "manga" => MediaType::Manga,
+            _ => continue,
+        };
+        let fields = elements(content)?
+            .into_iter()
+            .map(|(name, content)| Ok((name, text(content)?)))
+            .collect::<Result<HashMap<_, _>>>()?;
+
+        entries.push(parse_entry(media_type, &fields)?);
+    }
+
+    Ok(entries)
+}
+
+/// Builds an entry from the fields of an `anime` or `manga` element.
+///
+/// # Arguments
+///
+/// * `media_type` - The type of the media.
+/// * `fields` - The text of the child elements, by name.
+fn parse_entry(media_type: MediaType, fields: &HashMap<&str, String>) -> Result<ImportedEntry> {
+    let is_anime = media_type == MediaType::Anime;
+    let field = |anime: &str, manga: &str| {
+        fields
+            .get(if is_anime { anime } else { manga })
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    };
+    let number = |anime: &str, manga: &str| {
+        field(anime, manga)
+            .and_then(|value| value.parse::<u32>().ok())
+            .unwrap_or_default()
+    };
+
+    let mal_id = field("series_animedb_id", "manga_mangadb_id")
+        .and_then(|id| id.parse::<i64>().ok())
+        .filter(|id| *id > 0)
+        .ok_or_else(|| Error::InvalidXml("an entry has no valid ID".to_string()))?;
+    let is_repeating = number("my_rewatching", "my_rereading") == 1;
+    let status = field("my_status", "my_status")
+        .and_then(mal_status)
+        .map(|status| match status {
+            MediaListStatus::Current if is_repeating => MediaListStatus::Repeating,
+            status => status,
+        });
+
+    Ok(ImportedEntry {
+        mal_id,
+        media_type,
+        title: field("series_title", "manga_title")
+            .unwrap_or_default()
+            .to_string(),
+        status,
+        score: number("my_score", "my_score").min(10) as f32 * 10.0,
+        progress: number("my_watched_episodes", "my_read_chapters"),
+        progress_volumes: Some(number("", "my_read_volumes")).filter(|_| !is_anime),
+        repeat: number("my_times_watched", "my_times_read"),
+        notes: field("my_comments", "my_comments").map(String::from),
+        started_at: field("my_start_date", "my_start_date").and_then(mal_date),
+        completed_at: field("my_finish_date", "my_finish_date").and_then(mal_date),
+    })
+}
+
+/// Returns the status named by MyAnimeList.
+///
+/// Both the names and the numbers MyAnimeList uses are supported.
+///
+/// # Arguments
+///
+/// * `status` - The name or the number of the status.
+fn mal_status(status: &str) -> Option<MediaListStatus> {
+    match status {
+        "Watching" | "Reading" | "1" => Some(MediaListStatus::Current),
+        "Completed" | "2" => Some(MediaListStatus::Completed),
+        "On-Hold" | "3" => Some(MediaListStatus::Paused),
+        "Dropped" | "4" => Some(MediaListStatus::Dropped),
+        "Plan to Watch" | "Plan to Read" | "6" => Some(MediaListStatus::Planning),
+        _ => None,
+    }
+}
+
+/// Parses a date the way MyAnimeList writes it, unknown components
+/// being zeros, e.g. `2016-04-00`.
+///
+/// Returns `None` if the date is fully unknown.
+///
+/// # Arguments
+///
+/// * `date` - The date.
+fn mal_date(date: &str) -> Option<Date> {
+    let mut components = date
+        .splitn(3, '-')
+        .map(|component| component.parse::<u32>().ok().filter(|c| *c > 0));
+    let year = components.next().flatten().map(|year| year as i32);
+    let month = components.next().flatten();
+    let day = components.next().flatten();
+
+    Some(Date::new(year, month, day)).filter(|date| *date != Date::default())
+}
+
+/// Returns the name and the raw content of the elements of an XML
+/// fragment, in order.
+///
+/// Declarations, comments and text between the elements are skipped.
+/// Nested elements are kept in the content of their parent.
+///
+/// # Arguments
+///
+/// * `xml` - The XML fragment.
+fn elements(xml: &str) -> Result<Vec<(&str, &str)>> {
+    let invalid = |reason: &str| Error::InvalidXml(reason.to_string());
+    let mut elements = Vec::new();
+    let mut rest = xml;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            let end = comment
+                .find("-->")
+                .ok_or_else(|| invalid("unclosed comment"))?;
+            rest = &comment[end + 3..];
+            continue;
+        }
+        if rest.starts_with("<?") || rest.starts_with("<!") {
+            let end = rest
+                .find('>')
+                .ok_or_else(|| invalid("unclosed declaration"))?;
+            rest = &rest[end + 1..];
+            continue;
+        }
+
+        let end = rest.find('>').ok_or_else(|| invalid("unclosed tag"))?;
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if tag.starts_with('/') {
+            return Err(invalid(&format!("unexpected closing tag `<{tag}>`")));
+        }
+        if let Some(tag) = tag.strip_suffix('/') {
+            let name = tag.split_whitespace().next().unwrap_or_default();
+            elements.push((name, ""));
+            continue;
+        }
+
+        let name = tag.split_whitespace().next().unwrap_or_default();
+        let length = content_length(rest, name)
+            .ok_or_else(|| invalid(&format!("unclosed element `{name}`")))?;
+        elements.push((name, &rest[..length]));
+        rest = &rest[length + name.len() + 3..];
+    }
+
+    Ok(elements)
+}
+
+/// Returns the length of the content of an element, up to its closing
+/// tag.
+///
+/// CDATA sections and nested elements of the same name are skipped.
+///
+/// # Arguments
+///
+/// * `xml` - The XML following the opening tag of the element.
+/// * `name` - The name of the element.
+fn content_length(xml: &str, name: &str) -> Option<usize> {
+    let opening = format!("<{name}");
+    let closing = format!("</{name}>");
+    let mut depth = 0;
+    let mut position = 0;
+
+    loop {
+        let rest = &xml[position..];
+        let next = rest.find('<')?;
+        let rest = &rest[next..];
+
+        if rest.starts_with("<![CDATA[") {
+            position += next + rest.find("]]>")? + 3;
+        } else if rest.starts_with(&closing) {
+            if depth == 0 {
+                return Some(position + next);
+            }
+            depth -= 1;
+            position += next + closing.len();
+        } else {
+            let end = rest.find('>')?;
+            let is_opening = rest.starts_with(&opening)
+                && rest[opening.len()..=end].starts_with(|c: char| c.is_whitespace() || c == '>')
+                && !rest[..end].ends_with('/');
+            if is_opening {
+                depth += 1;
+            }
+            position += next + end + 1;
+        }
+    }
+}
+
+/// Returns the text of the content of an element.
+///
+/// CDATA sections are kept as is and the entities around them decoded.
+///
+/// # Arguments
+///
+/// * `content` - The raw content of the element.
+fn text(content: &str) -> Result<String> {
+    let mut text = String::new();
+    let mut rest = content;
+
+    while let Some(start) = rest.find("<![CDATA[") {
+        text.push_str(&unescape(&rest[..start]));
+        let cdata = &rest[start + 9..];
+        let end = cdata
+            .find("]]>")
+            .ok_or_else(|| Error::InvalidXml("unclosed CDATA section".to_string()))?;
+        text.push_str(&cdata[..end]);
+        rest = &cdata[end + 3..];
+    }
+    text.push_str(&unescape(rest));
+
+    Ok(text)
+}
+
+/// Decodes the predefined and numeric entities of an XML text.
+///
+/// Unknown entities are kept as is.
+///
+/// # Arguments
+///
+/// * `text` - The text to decode.
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let decoded = entity.and_then(|(entity, _)| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(|code| char::from_u32(code.ok()?)),
+        });
+
+        match (decoded, entity) {
+            (Some(c), Some((_, end))) => {
+                unescaped.push(c);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+
+    unescaped
+}
+
+/// Represents what to do with an entry already in the list of the user.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ConflictPolicy {
+    /// Keep the entry in the list.
+    #[default]
+    Skip,
+    /// Replace the entry in the list with the imported one.
+    Overwrite,
+    /// Replace the entry in the list with the imported one only if the
+    /// imported progress is higher.
+    MergeHigherProgress,
+}
+
+/// Represents the options of an import.
+///
+/// # Example
+///
+/// ```
+/// # use rust_anilist::import::{ConflictPolicy, ImportOptions};
+/// let options = ImportOptions::new()
+///     .conflict_policy(ConflictPolicy::Overwrite)
+///     .dry_run(true);
+/// ```
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "client"), allow(dead_code))]
+pub struct ImportOptions {
+    /// What to do with the entries already in the list.
+    pub(crate) conflict_policy: ConflictPolicy,
+    /// Whether to only report what would be done.
+    pub(crate) dry_run: bool,
+    /// The callback called after every entry.
+    pub(crate) on_progress: Option<ProgressCallback>,
+}
+
+impl ImportOptions {
+    /// Creates the default options: existing entries are skipped.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets what to do with the entries already in the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The conflict policy.
+    pub fn conflict_policy(mut self, policy: ConflictPolicy) -> Self {
+        self.conflict_policy = policy;
+        self
+    }
+
+    /// Sets whether to only report what would be done.
+    ///
+    /// A dry run looks the medias up but saves nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `dry_run` - Whether to save nothing.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Sets a callback called after every entry, whatever its outcome.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - The callback to call.
+    pub fn on_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&ImportProgress) + Send + Sync + 'static,
+    {
+        self.on_progress = Some(Arc::new(callback));
+        self
+    }
+
+    /// Calls the progress callback, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `progress` - The progress of the import.
+    #[cfg(feature = "client")]
+    pub(crate) fn progress(&self, progress: ImportProgress) {
+        if let Some(callback) = &self.on_progress {
+            callback(&progress);
+        }
+    }
+}
+
+impl std::fmt::Debug for ImportOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ImportOptions")
+            .field("conflict_policy", &self.conflict_policy)
+            .field("dry_run", &self.dry_run)
+            .field("on_progress", &self.on_progress.is_some())
+            .finish()
+    }
+}
+
+/// Represents what an import did with an entry.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ImportOutcome {
+    /// The entry was added to the list.
+    Created,
+    /// The entry replaced the one in the list.
+    Updated,
+    /// The entry was left out because of the conflict policy.
+    Skipped,
+    /// The entry was left out because its media is not on AniList.
+    NotFound,
+}
+
+/// Represents the progress of an import, given to the progress callback.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportProgress {
+    /// The number of entries handled so far.
+    pub done: usize,
+    /// The number of entries to handle.
+    pub total: usize,
+    /// The MyAnimeList ID of the entry just handled.
+    pub mal_id: i64,
+    /// What was done with the entry, or would be in a dry run.
+    pub outcome: ImportOutcome,
+}
+
+/// Represents the outcome of an import, as MyAnimeList IDs.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ImportReport {
+    /// The entries added to the list.
+    pub created: Vec<i64>,
+    /// The entries that replaced the ones in the list.
+    pub updated: Vec<i64>,
+    /// The entries left out because of the conflict policy.
+    pub skipped: Vec<i64>,
+    /// The entries left out because their media is not on AniList.
+    pub not_found: Vec<i64>,
+}
+
+impl ImportReport {
+    /// Records the outcome of an entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `mal_id` - The MyAnimeList ID of the entry.
+    /// * `outcome` - What was done with the entry.
+    #[cfg(feature = "client")]
+    pub(crate) fn record(&mut self, mal_id: i64, outcome: ImportOutcome) {
+        match outcome {
+            ImportOutcome::Created => self.created.push(mal_id),
+            ImportOutcome::Updated => self.updated.push(mal_id),
+            ImportOutcome::Skipped => self.skipped.push(mal_id),
+            ImportOutcome::NotFound => self.not_found.push(mal_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANIME_LIST: &str = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<!-- Exported from MyAnimeList -->
+<myanimelist>
+	<myinfo>
+		<user_export_type>1</user_export_type>
+	</myinfo>
+	<anime>
+		<series_animedb_id>1</series_animedb_id>
+		<series_title><![CDATA[Cowboy Bebop]]></series_title>
+		<my_watched_episodes>26</my_watched_episodes>
+		<my_start_date>2016-04-03</my_start_date>
+		<my_finish_date>2016-05-00</my_finish_date>
+		<my_score>9</my_score>
+		<my_status>Completed</my_status>
+		<my_comments><![CDATA[See you ]]]]><![CDATA[> space cowboy]]></my_comments>
+		<my_times_watched>1</my_times_watched>
+		<my_rewatching>0</my_rewatching>
+	</anime>
+	<anime>
+		<series_animedb_id>5</series_animedb_id>
+		<series_title>Tom &amp; Jerry &#233;</series_title>
+		<my_watched_episodes>3</my_watched_episodes>
+		<my_start_date>0000-00-00</my_start_date>
+		<my_score>0</my_score>
+		<my_status>Watching</my_status>
+		<my_comments></my_comments>
+		<my_rewatching>1</my_rewatching>
+	</anime>
+</myanimelist>
+"#;
+
+    #[test]
+    fn test_parse_mal_xml() {
+        let entries = parse_mal_xml(ANIME_LIST.as_bytes()).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries[0],
+            ImportedEntry {
+                mal_id: 1,
+                media_type: MediaType::Anime,
+                title: "Cowboy Bebop".to_string(),
+                status: Some(MediaListStatus::Completed),
+                score: 90.0,
+                progress: 26,
+                progress_volumes: None,
+                repeat: 1,
+                notes: Some("See you ]]> space cowboy".to_string()),
+                started_at: Some(Date::new(Some(2016), Some(4), Some(3))),
+                completed_at: Some(Date::new(Some(2016), Some(5), None)),
+            }
+        );
+        assert_eq!(entries[1].title, "Tom & Jerry é");
+        assert_eq!(entries[1].status, Some(MediaListStatus::Repeating));
+        assert_eq!(entries[1].score, 0.0);
+        assert_eq!(entries[1].notes, None);
+        assert_eq!(entries[1].started_at, None);
+    }
+
+    #[test]
+    fn test_parse_mal_xml_manga() {
+        let xml = "<myanimelist><manga>\
+            <manga_mangadb_id>2</manga_mangadb_id>\
+            <manga_title>Berserk</manga_title>\
+            <my_read_volumes>41</my_read_volumes>\
+            <my_read_chapters>364</my_read_chapters>\
+            <my_status>Plan to Read</my_status>\
+            </manga></myanimelist>";
+
+        let entries = parse_mal_xml(xml.as_bytes()).unwrap();
+
+        assert_eq!(entries[0].media_type, MediaType::Manga);
+        assert_eq!(entries[0].progress, 364);
+        assert_eq!(entries[0].progress_volumes, Some(41));
+        assert_eq!(entries[0].status, Some(MediaListStatus::Planning));
+    }
+
+    #[test]
+    fn test_parse_mal_xml_errors() {
+        let errors = [
+            "<anime></anime>",
+            "<myanimelist><anime><my_score>9</my_score></anime></myanimelist>",
+            "<myanimelist><anime><series_animedb_id>1</series_animedb_id>",
+            "<myanimelist></anime></myanimelist>",
+        ];
+
+        for xml in errors {
+            assert!(matches!(
+                parse_mal_xml(xml.as_bytes()),
+                Err(Error::InvalidXml(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_unescape() {
+        assert_eq!(unescape("a &lt;b&gt; &amp;amp;"), "a <b> &amp;");
+        assert_eq!(unescape("&#x41;&#66;"), "AB");
+        assert_eq!(unescape("R&D &unknown; &"), "R&D &unknown; &");
+    }
+
+    #[test]
+    fn test_mal_date() {
+        assert_eq!(mal_date("0000-00-00"), None);
+        assert_eq!(
+            mal_date("2016-00-00"),
+            Some(Date::new(Some(2016), None, None))
+        );
+    }
+}
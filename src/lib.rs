@@ -2,12 +2,40 @@
 // Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
 
 //! This crate provides a Rust library for interacting with the AniList API.
+//!
+//! The HTTP client is behind the default `client` feature. Disabling the
+//! default features leaves only the [`models`], for crates that get the
+//! AniList data some other way but want its types.
 
 #![deny(missing_docs)]
 
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
+#[cfg(feature = "client")]
 mod client;
 mod error;
+#[cfg(not(feature = "client"))]
+mod handle;
+pub mod import;
 pub mod models;
+pub mod prelude;
+pub mod queries;
+#[cfg(feature = "client")]
+mod url;
 
-pub use client::Client;
-pub use error::{Error, Result};
+#[cfg(feature = "file-cache")]
+pub use client::FileCache;
+#[cfg(feature = "test-utils")]
+pub use client::MockTransport;
+#[cfg(feature = "client")]
+pub use client::{
+    AnimeFields, CacheStore, Client, ClientBuilder, MemoryCache, Operation, RateLimitStatus,
+    RequestInfo, ResponseInfo, RetryEvent, RetryPolicy, Transport, TransportFuture,
+};
+pub use error::{Error, ErrorKind, Result};
+#[cfg(not(feature = "client"))]
+use handle::Client;
+#[cfg(feature = "client")]
+pub use reqwest::Url;
+#[cfg(feature = "client")]
+pub use url::parse_url;
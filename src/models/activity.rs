@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Activity` struct and the `ActivityType` enum.
+
+use serde::{Deserialize, Serialize};
+
+use super::UserId;
+
+/// Represents an activity, such as a status or a message.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Activity {
+    /// The ID of the activity.
+    pub id: i64,
+    /// The type of the activity.
+    #[serde(rename = "type")]
+    pub activity_type: Option<ActivityType>,
+    /// The text of a status, or the message of a message.
+    pub text: Option<String>,
+    /// The ID of the user who posted a status.
+    pub user_id: Option<UserId>,
+    /// The ID of the user who sent a message.
+    pub messenger_id: Option<UserId>,
+    /// The ID of the user who received a message.
+    pub recipient_id: Option<UserId>,
+    /// Whether the message is only visible to its recipient.
+    pub is_private: Option<bool>,
+    /// The number of replies to the activity.
+    #[serde(default)]
+    pub reply_count: u32,
+    /// The number of likes of the activity.
+    #[serde(default)]
+    pub like_count: u32,
+    /// The site URL of the activity.
+    #[serde(rename = "siteUrl")]
+    pub url: String,
+    /// When the activity was created, as a Unix timestamp.
+    pub created_at: i64,
+}
+
+/// Represents the type of an activity.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ActivityType {
+    /// A status posted by a user.
+    #[default]
+    Text,
+    /// A progress update of an anime list.
+    AnimeList,
+    /// A progress update of a manga list.
+    MangaList,
+    /// A message sent to a user.
+    Message,
+    /// A progress update of an anime or a manga list.
+    MediaList,
+    /// A value unknown to this version of the crate.
+    ///
+    /// Unlike the `Unknown(String)` of the other enums, the value isn't
+    /// kept, so that the enum stays `Copy`.
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let activity: Activity = serde_json::from_value(serde_json::json!({
+            "id": 10,
+            "type": "MESSAGE",
+            "text": "Hello!",
+            "messengerId": 1,
+            "recipientId": 2,
+            "isPrivate": true,
+            "siteUrl": "https://anilist.co/activity/10",
+            "createdAt": 1700000000
+        }))
+        .unwrap();
+
+        assert_eq!(activity.activity_type, Some(ActivityType::Message));
+        assert_eq!(activity.recipient_id, Some(UserId::new(2)));
+        assert_eq!(activity.reply_count, 0);
+        assert_eq!(activity.url, "https://anilist.co/activity/10");
+    }
+}
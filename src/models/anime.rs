@@ -1,15 +1,37 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
 
-use serde::{Deserialize, Deserializer, Serialize};
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::connections::{
+    deserialize_characters, deserialize_staff, deserialize_studios, parse_relations,
+};
+use super::debug::{ClientPlaceholder, ConnectionSummary, Truncated};
+use super::media::{display_title, split_hashtags, write_details, Count};
+use super::title::title_similarity;
+#[cfg(feature = "client")]
+use super::MediaTrend;
 use super::{
-    Character, Cover, Date, Format, Link, Person, Relation, Season, Source, Status, Studio, Tag,
-    Title,
+    AnimeId, Character, CharacterRole, Cover, Date, Format, Genre, Link, MediaStats, Person,
+    Ranking, RankingType, Relation, RelationType, Season, Source, Status, Studio, Tag, Title,
+    Trailer,
 };
+#[cfg(feature = "client")]
+use crate::queries;
 use crate::{Client, Result};
 
+/// The number of characters in a page fetched by `Anime::get_all_characters`.
+#[cfg(feature = "client")]
+const CHARACTERS_PER_PAGE: u16 = 50;
+
+/// The number of people in a page fetched by `Anime::get_all_staff`.
+#[cfg(feature = "client")]
+const STAFF_PER_PAGE: u16 = 50;
+
 /// Represents an anime with various attributes.
 ///
 /// The `Anime` struct contains detailed information about an anime,
@@ -17,11 +39,11 @@ use crate::{Client, Result};
 /// episodes, duration, country of origin, licensing status, source,
 /// hashtags, images, genres, synonyms, scores, popularity, tags,
 /// relations, characters, staff, studios, and other metadata.
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[derive(Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Anime {
     /// The ID of the anime.
-    pub id: i64,
+    pub id: AnimeId,
     /// The ID of the anime on MAL.
     pub id_mal: Option<i64>,
     /// The title of the anime.
@@ -62,8 +84,10 @@ pub struct Anime {
     /// The banner image of the anime.
     #[serde(rename = "bannerImage")]
     pub banner: Option<String>,
+    /// The trailer of the anime.
+    pub trailer: Option<Trailer>,
     /// The genres of the anime.
-    pub genres: Option<Vec<String>>,
+    pub genres: Option<Vec<Genre>>,
     /// The synonyms of the anime.
     pub synonyms: Option<Vec<String>>,
     /// The average score of the anime.
@@ -78,18 +102,27 @@ pub struct Anime {
     pub trending: Option<u32>,
     /// The number of favourites of the anime.
     pub favourites: Option<u32>,
+    /// The rankings of the anime.
+    pub rankings: Option<Vec<Ranking>>,
+    /// The score and list status distributions of the anime.
+    pub stats: Option<MediaStats>,
     /// The tags of the anime.
     pub tags: Option<Vec<Tag>>,
     /// The relations of the anime.
+    #[serde(default)]
     pub(crate) relations: Value,
     /// The characters of the anime.
-    #[serde(rename = "characters", deserialize_with = "deserialize_characters")]
+    #[serde(
+        rename = "characters",
+        default,
+        deserialize_with = "deserialize_characters"
+    )]
     pub characters: Option<Vec<Character>>,
     /// The staff of the anime.
-    #[serde(rename = "staff", deserialize_with = "deserialize_staff")]
+    #[serde(rename = "staff", default, deserialize_with = "deserialize_staff")]
     pub staff: Option<Vec<Person>>,
     /// The studios of the anime.
-    #[serde(rename = "studios", deserialize_with = "deserialize_studios")]
+    #[serde(rename = "studios", default, deserialize_with = "deserialize_studios")]
     pub studios: Option<Vec<Studio>>,
     /// Whether the anime is favourite or not.
     pub is_favourite: Option<bool>,
@@ -104,7 +137,9 @@ pub struct Anime {
     /// The streaming episodes of the anime.
     pub streaming_episodes: Option<Vec<Link>>,
     /// The site URL of the anime.
-    #[serde(rename = "siteUrl")]
+    ///
+    /// The client builds it from the ID when the API doesn't give it.
+    #[serde(rename = "siteUrl", default)]
     pub url: String,
 
     /// The client used to fetch additional data.
@@ -115,7 +150,251 @@ pub struct Anime {
     pub(crate) is_full_loaded: bool,
 }
 
+impl PartialEq for Anime {
+    /// Compares every field of the animes but their client and whether they are
+    /// fully loaded.
+    fn eq(&self, other: &Self) -> bool {
+        let Anime {
+            id,
+            id_mal,
+            title,
+            format,
+            status,
+            description,
+            start_date,
+            end_date,
+            season,
+            season_year,
+            season_int,
+            episodes,
+            duration,
+            country_of_origin,
+            is_licensed,
+            source,
+            hashtag,
+            updated_at,
+            cover,
+            banner,
+            trailer,
+            genres,
+            synonyms,
+            average_score,
+            mean_score,
+            popularity,
+            is_locked,
+            trending,
+            favourites,
+            rankings,
+            stats,
+            tags,
+            relations,
+            characters,
+            staff,
+            studios,
+            is_favourite,
+            is_favourite_blocked,
+            is_adult,
+            next_airing_episode,
+            external_links,
+            streaming_episodes,
+            url,
+            client: _,
+            is_full_loaded: _,
+        } = self;
+
+        *id == other.id
+            && *id_mal == other.id_mal
+            && *title == other.title
+            && *format == other.format
+            && *status == other.status
+            && *description == other.description
+            && *start_date == other.start_date
+            && *end_date == other.end_date
+            && *season == other.season
+            && *season_year == other.season_year
+            && *season_int == other.season_int
+            && *episodes == other.episodes
+            && *duration == other.duration
+            && *country_of_origin == other.country_of_origin
+            && *is_licensed == other.is_licensed
+            && *source == other.source
+            && *hashtag == other.hashtag
+            && *updated_at == other.updated_at
+            && *cover == other.cover
+            && *banner == other.banner
+            && *trailer == other.trailer
+            && *genres == other.genres
+            && *synonyms == other.synonyms
+            && *average_score == other.average_score
+            && *mean_score == other.mean_score
+            && *popularity == other.popularity
+            && *is_locked == other.is_locked
+            && *trending == other.trending
+            && *favourites == other.favourites
+            && *rankings == other.rankings
+            && *stats == other.stats
+            && *tags == other.tags
+            && *relations == other.relations
+            && *characters == other.characters
+            && *staff == other.staff
+            && *studios == other.studios
+            && *is_favourite == other.is_favourite
+            && *is_favourite_blocked == other.is_favourite_blocked
+            && *is_adult == other.is_adult
+            && *next_airing_episode == other.next_airing_episode
+            && *external_links == other.external_links
+            && *streaming_episodes == other.streaming_episodes
+            && *url == other.url
+    }
+}
+
+/// Two equal animes always have the same ID, so animes are hashed by ID.
+impl Eq for Anime {}
+
+impl std::hash::Hash for Anime {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl std::fmt::Debug for Anime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Anime {
+            id,
+            id_mal,
+            title,
+            format,
+            status,
+            description,
+            start_date,
+            end_date,
+            season,
+            season_year,
+            season_int,
+            episodes,
+            duration,
+            country_of_origin,
+            is_licensed,
+            source,
+            hashtag,
+            updated_at,
+            cover,
+            banner,
+            trailer,
+            genres,
+            synonyms,
+            average_score,
+            mean_score,
+            popularity,
+            is_locked,
+            trending,
+            favourites,
+            rankings,
+            stats,
+            tags,
+            relations,
+            characters,
+            staff,
+            studios,
+            is_favourite,
+            is_favourite_blocked,
+            is_adult,
+            next_airing_episode,
+            external_links,
+            streaming_episodes,
+            url,
+            client: _,
+            is_full_loaded,
+        } = self;
+
+        f.debug_struct("Anime")
+            .field("id", id)
+            .field("id_mal", id_mal)
+            .field("title", title)
+            .field("format", format)
+            .field("status", status)
+            .field("description", &Truncated(description))
+            .field("start_date", start_date)
+            .field("end_date", end_date)
+            .field("season", season)
+            .field("season_year", season_year)
+            .field("season_int", season_int)
+            .field("episodes", episodes)
+            .field("duration", duration)
+            .field("country_of_origin", country_of_origin)
+            .field("is_licensed", is_licensed)
+            .field("source", source)
+            .field("hashtag", hashtag)
+            .field("updated_at", updated_at)
+            .field("cover", cover)
+            .field("banner", banner)
+            .field("trailer", trailer)
+            .field("genres", genres)
+            .field("synonyms", synonyms)
+            .field("average_score", average_score)
+            .field("mean_score", mean_score)
+            .field("popularity", popularity)
+            .field("is_locked", is_locked)
+            .field("trending", trending)
+            .field("favourites", favourites)
+            .field("rankings", rankings)
+            .field("stats", stats)
+            .field("tags", tags)
+            .field("relations", &ConnectionSummary(relations, "relation"))
+            .field("characters", characters)
+            .field("staff", staff)
+            .field("studios", studios)
+            .field("is_favourite", is_favourite)
+            .field("is_favourite_blocked", is_favourite_blocked)
+            .field("is_adult", is_adult)
+            .field("next_airing_episode", next_airing_episode)
+            .field("external_links", external_links)
+            .field("streaming_episodes", streaming_episodes)
+            .field("url", url)
+            .field("client", &ClientPlaceholder)
+            .field("is_full_loaded", is_full_loaded)
+            .finish()
+    }
+}
+
 impl Anime {
+    /// Creates a partially loaded anime, to test code consuming animes
+    /// without reaching the API.
+    ///
+    /// The other fields are left to their defaults, the site URL is
+    /// built from the ID, and the anime is detached: the methods sending
+    /// requests fail with [`Error::Detached`](crate::Error::Detached).
+    ///
+    /// This function is only available with the `test-util` feature,
+    /// which `test-utils` enables as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the anime.
+    /// * `title` - The title of the anime, in Romaji and native.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Anime;
+    /// let anime = Anime::for_test(1, "Cowboy Bebop");
+    ///
+    /// assert_eq!(anime.title.romaji(), "Cowboy Bebop");
+    /// assert_eq!(anime.url, "https://anilist.co/anime/1");
+    /// ```
+    #[cfg(feature = "test-util")]
+    pub fn for_test(id: impl Into<AnimeId>, title: &str) -> Self {
+        let mut anime = Self {
+            id: id.into(),
+            title: super::Title::for_test(title),
+            client: Client::detached(),
+            ..Default::default()
+        };
+        anime.fill_url();
+
+        anime
+    }
+
     /// Loads the full details of the anime.
     ///
     /// # Errors
@@ -136,6 +415,7 @@ impl Anime {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "client")]
     pub async fn load_full(self) -> Result<Self> {
         if !self.is_full_loaded {
             self.client.get_anime(self.id).await
@@ -144,22 +424,599 @@ impl Anime {
         }
     }
 
+    /// Detaches the anime from its client.
+    ///
+    /// A detached anime keeps its data, and the methods reading it,
+    /// such as [`Anime::relations`] and [`Anime::characters_by_role`], keep working. The methods sending
+    /// requests, such as [`Anime::load_full`] and [`Anime::get_airing_schedule`], fail with
+    /// [`Error::Detached`](crate::Error::Detached) until a client is
+    /// attached with [`Anime::attach`]. The nested models are detached
+    /// as well.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Anime, Client, Result};
+    /// #
+    /// # async fn f(anime: Anime, client: Client) -> Result<()> {
+    /// let cached = anime.detach();
+    /// // ...
+    /// let anime = cached.attach(&client).load_full().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "client")]
+    pub fn detach(self) -> Self {
+        self.attach(&Client::detached())
+    }
+
+    /// Attaches the anime to a client, which sends its requests from now on.
+    ///
+    /// The nested models are attached as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to attach.
+    #[cfg(feature = "client")]
+    pub fn attach(mut self, client: &Client) -> Self {
+        self.set_client(client);
+        self
+    }
+
+    /// Gives the client to the anime and its nested models.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to give.
+    #[cfg(feature = "client")]
+    pub(crate) fn set_client(&mut self, client: &Client) {
+        self.client = client.clone();
+        for character in self.characters.iter_mut().flatten() {
+            character.set_client(client);
+        }
+        for person in self.staff.iter_mut().flatten() {
+            person.set_client(client);
+        }
+        for studio in self.studios.iter_mut().flatten() {
+            studio.set_client(client);
+        }
+    }
+
+    /// Returns the ID of the anime an AniList URL points to.
+    ///
+    /// This doesn't send any request; see [`parse_url`](crate::parse_url)
+    /// for the URLs supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL of the anime page on anilist.co.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is not the URL of an anime page or
+    /// holds no valid ID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Anime;
+    /// let id = Anime::anilist_id_from_url("https://anilist.co/anime/1/Cowboy-Bebop/").unwrap();
+    ///
+    /// assert_eq!(id, 1);
+    /// ```
+    #[cfg(feature = "client")]
+    pub fn anilist_id_from_url(url: &str) -> Result<AnimeId> {
+        match crate::parse_url(url)? {
+            (super::MediaType::Anime, id) => Ok(id.into()),
+            _ => Err(crate::Error::InvalidUrl(url.trim().to_string())),
+        }
+    }
+
+    /// Builds the site URL of the anime from its ID if it is empty.
+    #[cfg(any(feature = "client", feature = "test-util"))]
+    pub(crate) fn fill_url(&mut self) {
+        if self.url.is_empty() {
+            self.url = format!("https://anilist.co/anime/{}", self.id);
+        }
+    }
+
+    /// Returns whether the anime has an episode yet to air.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Anime, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// if anime.is_airing() {
+    ///     println!("Episode {} is coming", anime.next_episode_number().unwrap());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_airing(&self) -> bool {
+        self.next_airing_episode
+            .as_ref()
+            .is_some_and(|airing| !airing.has_aired())
+    }
+
+    /// Returns the number of the next episode to air, if any.
+    pub fn next_episode_number(&self) -> Option<u32> {
+        self.next_airing_episode
+            .as_ref()
+            .filter(|airing| !airing.has_aired())
+            .map(|airing| airing.episode)
+    }
+
+    /// Returns whether the anime has finished airing.
+    pub fn is_finished(&self) -> bool {
+        self.status == Status::Finished
+    }
+
+    /// Returns the number of episodes aired at the given time.
+    ///
+    /// The count is taken from [`Anime::next_airing_episode`] when the
+    /// API reports one, otherwise from [`Anime::episodes`] for a finished
+    /// anime. An anime not yet released has no episode aired. Returns
+    /// `None` when the count is unknown, e.g. for an airing anime fetched
+    /// without its airing schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The Unix timestamp to count at, or `None` for now.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Anime, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// if let (Some(aired), Some(total)) = (anime.aired_episodes(None), anime.episodes) {
+    ///     println!("{aired}/{total} episodes aired");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn aired_episodes(&self, now: Option<i64>) -> Option<u16> {
+        let now = now.unwrap_or_else(|| Utc::now().timestamp());
+
+        if let Some(airing) = &self.next_airing_episode {
+            let aired = if airing.at <= now {
+                airing.episode
+            } else {
+                airing.episode.saturating_sub(1)
+            };
+
+            return u16::try_from(aired).ok();
+        }
+
+        match self.status {
+            Status::Finished => self.episodes,
+            Status::NotYetReleased => Some(0),
+            _ => None,
+        }
+    }
+
+    /// Retrieves every episode of the anime's airing schedule.
+    ///
+    /// Unlike [`Anime::next_airing_episode`], this includes the episodes
+    /// already aired, in airing order. Every page of the schedule is
+    /// fetched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page of the schedule cannot be loaded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Anime, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// for airing in anime.get_airing_schedule().await? {
+    ///     println!("Episode {} airs at {}", airing.episode, airing.at);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "client")]
+    pub async fn get_airing_schedule(&self) -> Result<Vec<AiringSchedule>> {
+        let query = queries::GET_AIRING_SCHEDULE;
+        let mut schedule = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let data = self
+                .client
+                .query_raw_value(query, serde_json::json!({ "id": self.id, "page": page }))
+                .await?;
+            let airing = &data["Media"]["airingSchedule"];
+
+            let nodes: Option<Vec<AiringSchedule>> =
+                serde_json::from_value(airing["nodes"].clone())?;
+            schedule.extend(nodes.unwrap_or_default());
+
+            if !airing["pageInfo"]["hasNextPage"]
+                .as_bool()
+                .unwrap_or_default()
+            {
+                return Ok(schedule);
+            }
+
+            page += 1;
+        }
+    }
+
+    /// Retrieves the daily trends of the anime, oldest first.
+    ///
+    /// Every page of the trends is fetched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page of the trends cannot be loaded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Anime, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// for trend in anime.get_trends().await? {
+    ///     println!("{}: {:?} users", trend.date, trend.popularity);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "client")]
+    pub async fn get_trends(&self) -> Result<Vec<MediaTrend>> {
+        let query = queries::GET_MEDIA_TRENDS;
+        let mut trends = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let data = self
+                .client
+                .query_raw_value(query, serde_json::json!({ "id": self.id, "page": page }))
+                .await?;
+            let connection = &data["Media"]["trends"];
+
+            let nodes: Option<Vec<MediaTrend>> =
+                serde_json::from_value(connection["nodes"].clone())?;
+            trends.extend(nodes.unwrap_or_default());
+
+            if !connection["pageInfo"]["hasNextPage"]
+                .as_bool()
+                .unwrap_or_default()
+            {
+                return Ok(trends);
+            }
+
+            page += 1;
+        }
+    }
+
+    /// Retrieves every character of the anime, most favourited first,
+    /// with their role and voice actors.
+    ///
+    /// Unlike [`Anime::characters`], which only holds the first page
+    /// AniList returns, every page of the characters is fetched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page of the characters cannot be loaded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Anime, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// for character in anime.get_all_characters().await? {
+    ///     println!("{}: {:?}", character.name, character.role);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "client")]
+    pub async fn get_all_characters(&self) -> Result<Vec<Character>> {
+        let mut characters = Vec::new();
+        let mut page = Some(
+            self.client
+                .get_characters_of_anime(self.id, 1, CHARACTERS_PER_PAGE)
+                .await?,
+        );
+        while let Some(current) = page {
+            page = current.next().await?;
+            characters.extend(current);
+        }
+
+        Ok(characters)
+    }
+
+    /// Retrieves every person of the staff of the anime, by relevance,
+    /// with their role in [`Person::staff_role`].
+    ///
+    /// Unlike [`Anime::staff`], which only holds the first page AniList
+    /// returns, every page of the staff is fetched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page of the staff cannot be loaded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Anime, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// for person in anime.get_all_staff().await? {
+    ///     println!("{}: {:?}", person.name, person.staff_role);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "client")]
+    pub async fn get_all_staff(&self) -> Result<Vec<Person>> {
+        let mut staff = Vec::new();
+        let mut page = Some(
+            self.client
+                .get_staff_of_anime(self.id, 1, STAFF_PER_PAGE)
+                .await?,
+        );
+        while let Some(current) = page {
+            page = current.next().await?;
+            staff.extend(current);
+        }
+
+        Ok(staff)
+    }
+
     /// Returns the relations of the anime.
     pub fn relations(&self) -> Result<Vec<Relation>> {
-        let binding = Vec::new();
-        let edges = self
-            .relations
-            .as_object()
-            .and_then(|obj| obj.get("edges"))
-            .and_then(|edges| edges.as_array())
-            .unwrap_or(&binding);
-
-        let relations = edges
+        Ok(parse_relations(&self.relations, &self.client))
+    }
+
+    /// Returns the sequels of the anime.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Anime, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// for sequel in anime.sequels() {
+    ///     println!("{}", sequel.media().title());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sequels(&self) -> Vec<Relation> {
+        self.relations_of_type(RelationType::Sequel)
+    }
+
+    /// Returns the prequels of the anime.
+    pub fn prequels(&self) -> Vec<Relation> {
+        self.relations_of_type(RelationType::Prequel)
+    }
+
+    /// Returns the side stories of the anime.
+    pub fn side_stories(&self) -> Vec<Relation> {
+        self.relations_of_type(RelationType::SideStory)
+    }
+
+    /// Returns the first adaptation of the anime into another format,
+    /// such as a manga, if any.
+    pub fn adaptation(&self) -> Option<Relation> {
+        self.relations_of_type(RelationType::Adaptation)
+            .into_iter()
+            .next()
+    }
+
+    /// Returns the relations of the anime of the given type.
+    fn relations_of_type(&self, relation_type: RelationType) -> Vec<Relation> {
+        self.relations()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|relation| relation.relation_type == relation_type)
+            .collect()
+    }
+
+    /// Returns the characters of the anime, most favourited first.
+    ///
+    /// Characters without a number of favourites come last. The
+    /// `characters` field keeps the order returned by AniList.
+    pub fn characters_by_favourites(&self) -> Vec<&Character> {
+        let mut characters: Vec<&Character> = self.characters.iter().flatten().collect();
+        characters.sort_by_key(|character| std::cmp::Reverse(character.favourites));
+
+        characters
+    }
+
+    /// Returns the hashtags of the anime, such as `#JujutsuKaisen`.
+    ///
+    /// AniList stores them in [`Anime::hashtag`], separated by
+    /// whitespace, which is ignored however repeated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Anime;
+    /// let mut anime = Anime::default();
+    /// anime.hashtag = Some("#呪術廻戦  #JujutsuKaisen ".to_string());
+    ///
+    /// assert_eq!(anime.hashtags(), ["#呪術廻戦", "#JujutsuKaisen"]);
+    /// ```
+    pub fn hashtags(&self) -> Vec<&str> {
+        split_hashtags(self.hashtag.as_deref())
+    }
+
+    /// Returns whether a title or a synonym of the anime is the given
+    /// one.
+    ///
+    /// The romaji, English and native titles and the synonyms are
+    /// compared case-insensitively, ignoring diacritics such as macrons
+    /// and punctuation such as colons, `&` being read as `and`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The title to compare.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Anime;
+    /// let mut anime = Anime::default();
+    /// anime.title = serde_json::from_value(serde_json::json!({
+    ///     "romaji": "Shingeki no Kyojin",
+    ///     "native": "進撃の巨人",
+    /// }))
+    /// .unwrap();
+    /// anime.synonyms = Some(vec!["AoT".to_string()]);
+    ///
+    /// assert!(anime.matches_title("Shingeki no Kyōjin"));
+    /// assert!(anime.matches_title("AOT"));
+    /// ```
+    pub fn matches_title(&self, query: &str) -> bool {
+        self.title_similarity(query) == 1.0
+    }
+
+    /// Returns how closely a title or a synonym of the anime matches a
+    /// query, from `0.0` to `1.0`, to rank search candidates.
+    ///
+    /// The texts are normalized as in [`Anime::matches_title`].
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query.
+    pub fn title_similarity(&self, query: &str) -> f32 {
+        title_similarity(
+            &self.title,
+            self.synonyms.as_deref().unwrap_or_default(),
+            query,
+        )
+    }
+
+    /// Returns the characters of the anime with the given role.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - The role of the characters.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::{Anime, CharacterRole}, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// let protagonists = anime.characters_by_role(CharacterRole::Main);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn characters_by_role(&self, role: CharacterRole) -> Vec<&Character> {
+        self.characters
             .iter()
-            .map(|r| serde_json::from_value(r.clone()).unwrap_or_default())
-            .collect();
+            .flatten()
+            .filter(|character| character.role.as_ref() == Some(&role))
+            .collect()
+    }
+
+    /// Returns the main studio of the anime, the one that animated it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Anime, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// if let Some(studio) = anime.main_studio() {
+    ///     println!("Animated by {}", studio.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn main_studio(&self) -> Option<&Studio> {
+        self.studios
+            .iter()
+            .flatten()
+            .find(|studio| studio.main_studio())
+    }
 
-        Ok(relations)
+    /// Returns the all-time ranking of the anime of the given type.
+    ///
+    /// # Arguments
+    ///
+    /// * `ranking_type` - The type of the ranking.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::{Anime, RankingType}, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// if let Some(ranking) = anime.all_time_rank(RankingType::Rated) {
+    ///     println!("#{} {}", ranking.rank, ranking.ranking_type.name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn all_time_rank(&self, ranking_type: RankingType) -> Option<&Ranking> {
+        self.rankings
+            .iter()
+            .flatten()
+            .find(|ranking| ranking.ranking_type == ranking_type && ranking.is_all_time())
+    }
+
+    /// Returns the ranking of the anime within its season of the given type.
+    ///
+    /// # Arguments
+    ///
+    /// * `ranking_type` - The type of the ranking.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::{Anime, RankingType}, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// if let Some(ranking) = anime.seasonal_rank(RankingType::Popular) {
+    ///     println!("#{} most popular of the season", ranking.rank);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn seasonal_rank(&self, ranking_type: RankingType) -> Option<&Ranking> {
+        self.rankings
+            .iter()
+            .flatten()
+            .find(|ranking| ranking.ranking_type == ranking_type && ranking.is_seasonal())
+    }
+
+    /// Returns the staff of the anime with the given role.
+    ///
+    /// Roles are compared case-insensitively, ignoring the qualifier in
+    /// parentheses AniList appends to some of them, so `"Key Animation"`
+    /// matches `"Key Animation (ep 1)"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - The role of the staff, e.g. `"Director"`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Anime, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// let directors = anime.staff_by_role("Director");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn staff_by_role(&self, role: &str) -> Vec<&Person> {
+        self.staff
+            .iter()
+            .flatten()
+            .filter(|person| person.has_staff_role(role))
+            .collect()
     }
 }
 
@@ -175,75 +1032,418 @@ pub struct AiringSchedule {
     /// The airing date.
     #[serde(rename = "airingAt")]
     pub at: i64,
-    /// Time until the airing.
+    /// Seconds until the airing, negative once the episode has aired.
     #[serde(rename = "timeUntilAiring")]
-    pub time_until: u64,
+    pub time_until: i64,
     /// The airing episode.
     pub episode: u32,
 }
 
-fn deserialize_studios<'de, D>(deserializer: D) -> std::result::Result<Option<Vec<Studio>>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    #[derive(Deserialize)]
-    struct StudioConnection {
-        nodes: Vec<Studio>,
+impl AiringSchedule {
+    /// Returns whether the episode has aired.
+    pub fn has_aired(&self) -> bool {
+        self.at <= Utc::now().timestamp()
+    }
+
+    /// Returns the time left until the episode airs, or `None` once it
+    /// has aired.
+    ///
+    /// Unlike [`AiringSchedule::time_until`], which is measured when the
+    /// schedule is fetched, this is measured now.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Anime, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// if let Some(countdown) = anime.next_airing_episode.and_then(|e| e.countdown()) {
+    ///     let hours = countdown.as_secs() / 3600;
+    ///     println!("Airs in {}d {}h", hours / 24, hours % 24);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn countdown(&self) -> Option<Duration> {
+        let seconds = self.at - Utc::now().timestamp();
+
+        (seconds > 0).then(|| Duration::from_secs(seconds as u64))
+    }
+
+    /// Returns the day the episode airs on, in the time zone of the
+    /// given offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The offset from UTC of the time zone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{FixedOffset, NaiveDate};
+    /// # use rust_anilist::models::AiringSchedule;
+    /// let schedule = AiringSchedule { id: 1, at: 1_700_000_000, time_until: 0, episode: 1 };
+    /// let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+    ///
+    /// assert_eq!(schedule.local_date(tokyo), NaiveDate::from_ymd_opt(2023, 11, 15));
+    /// ```
+    pub fn local_date(&self, offset: FixedOffset) -> Option<NaiveDate> {
+        DateTime::from_timestamp(self.at, 0).map(|at| at.with_timezone(&offset).date_naive())
+    }
+}
+
+/// Represents an episode airing in a time window, as returned by
+/// `Client::get_airing_calendar`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AiringEntry {
+    /// When the episode airs, and its number.
+    pub schedule: AiringSchedule,
+    /// The anime the episode is part of, partially loaded.
+    pub anime: Anime,
+}
+
+/// Represents an episode whose airing time has passed, as yielded by
+/// `Client::watch_airing`.
+pub type AiringEvent = AiringEntry;
+
+impl std::fmt::Display for Anime {
+    /// Writes the title of the anime followed by its year, format and
+    /// number of episodes, e.g. `Cowboy Bebop (1998, TV, 26 eps)`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let year = self
+            .season_year
+            .map(i64::from)
+            .or_else(|| self.start_date.as_ref()?.year.map(i64::from));
+        let episodes = Count::of(self.episodes, "ep");
+
+        write!(f, "{}", display_title(&self.title))?;
+        write_details(
+            f,
+            &[
+                year.as_ref().map(|year| year as _),
+                Some(&self.format.name()),
+                episodes.as_ref().map(|episodes| episodes as _),
+            ],
+        )
     }
-    let connection: Option<StudioConnection> = Option::deserialize(deserializer)?;
-    Ok(connection.map(|c| c.nodes))
 }
 
-fn deserialize_characters<'de, D>(
-    deserializer: D,
-) -> std::result::Result<Option<Vec<Character>>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    #[derive(Deserialize)]
-    #[serde(rename_all = "camelCase")]
-    struct CharacterEdge {
-        node: Character,
-        role: Option<String>,
-        voice_actors: Option<Vec<Person>>,
-    }
-    #[derive(Deserialize)]
-    struct CharacterConnection {
-        edges: Vec<CharacterEdge>,
-    }
-
-    let connection: Option<CharacterConnection> = Option::deserialize(deserializer)?;
-
-    match connection {
-        Some(conn) => {
-            let characters = conn
-                .edges
-                .into_iter()
-                .map(|edge| {
-                    let mut character = edge.node;
-                    if let Some(role_str) = edge.role {
-                        character.role = Some(role_str.into());
-                    }
-                    if let Some(voice_actors) = edge.voice_actors {
-                        character.voice_actors = Some(voice_actors);
-                    }
-                    character
-                })
-                .collect();
-            Ok(Some(characters))
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn character(id: i64, role: CharacterRole, favourites: Option<i64>) -> Character {
+        Character {
+            id: id.into(),
+            role: Some(role),
+            favourites,
+            ..Default::default()
         }
-        None => Ok(None),
     }
-}
 
-fn deserialize_staff<'de, D>(deserializer: D) -> std::result::Result<Option<Vec<Person>>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    #[derive(Deserialize)]
-    struct StaffConnection {
-        nodes: Vec<Person>,
+    #[test]
+    fn test_characters_by_favourites() {
+        let anime = Anime {
+            characters: Some(vec![
+                character(1, CharacterRole::Supporting, None),
+                character(2, CharacterRole::Main, Some(10)),
+                character(3, CharacterRole::Main, Some(500)),
+            ]),
+            ..Default::default()
+        };
+        let ids: Vec<i64> = anime
+            .characters_by_favourites()
+            .iter()
+            .map(|character| character.id.get())
+            .collect();
+
+        assert_eq!(ids, vec![3, 2, 1]);
+        assert_eq!(anime.characters.unwrap()[0].id, 1);
+    }
+
+    #[test]
+    fn test_characters_by_role() {
+        let anime = Anime {
+            characters: Some(vec![
+                character(1, CharacterRole::Supporting, None),
+                character(2, CharacterRole::Main, Some(10)),
+            ]),
+            ..Default::default()
+        };
+
+        assert_eq!(anime.characters_by_role(CharacterRole::Main).len(), 1);
+        assert!(anime
+            .characters_by_role(CharacterRole::Background)
+            .is_empty());
+        assert!(Anime::default().characters_by_favourites().is_empty());
+    }
+
+    #[test]
+    fn test_studios() {
+        let anime: Anime = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "title": { "romaji": "Cowboy Bebop", "native": "カウボーイビバップ" },
+            "format": "TV",
+            "status": "FINISHED",
+            "description": "",
+            "coverImage": {},
+            "studios": {
+                "nodes": [{ "id": 14, "name": "Sunrise", "isAnimationStudio": true }]
+            },
+            "isAdult": false,
+            "siteUrl": ""
+        }))
+        .unwrap();
+        let studios = anime.studios.as_ref().unwrap();
+
+        assert_eq!(studios[0].name, "Sunrise");
+        assert_eq!(studios[0].is_main, None);
+        assert!(anime.main_studio().is_none());
+    }
+
+    #[test]
+    fn test_main_studio() {
+        let response: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/anime.json")).unwrap();
+        let anime: Anime = serde_json::from_value(response["data"]["Media"].clone()).unwrap();
+        let studios = anime.studios.as_ref().unwrap();
+
+        assert_eq!(studios[0].is_main, Some(true));
+        assert_eq!(studios[1].is_main, Some(false));
+        assert_eq!(anime.main_studio().unwrap().name, "Sunrise");
+    }
+
+    #[test]
+    fn test_genres() {
+        let response: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/anime.json")).unwrap();
+        let anime: Anime = serde_json::from_value(response["data"]["Media"].clone()).unwrap();
+
+        assert_eq!(
+            anime.genres,
+            Some(vec![
+                Genre::Action,
+                Genre::Adventure,
+                Genre::Drama,
+                Genre::SciFi
+            ])
+        );
+    }
+
+    #[test]
+    fn test_relation_filters() {
+        let response: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/anime.json")).unwrap();
+        let anime: Anime = serde_json::from_value(response["data"]["Media"].clone()).unwrap();
+
+        assert_eq!(anime.side_stories().len(), 1);
+        assert_eq!(anime.side_stories()[0].media().id(), 5);
+        assert!(anime.sequels().is_empty());
+        assert!(anime.prequels().is_empty());
+        assert!(anime.adaptation().is_none());
+    }
+
+    #[test]
+    fn test_trailer() {
+        let response: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/anime.json")).unwrap();
+        let anime: Anime = serde_json::from_value(response["data"]["Media"].clone()).unwrap();
+
+        assert_eq!(
+            anime.trailer.unwrap().url(),
+            "https://www.youtube.com/watch?v=gY5nDXOtv_o"
+        );
+    }
+
+    #[test]
+    fn test_staff_by_role() {
+        let response: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/anime.json")).unwrap();
+        let mut anime: Anime = serde_json::from_value(response["data"]["Media"].clone()).unwrap();
+
+        assert_eq!(anime.staff_by_role("director")[0].id, 95061);
+
+        anime.staff.as_mut().unwrap().push(Person {
+            id: 2.into(),
+            staff_role: Some("Key Animation (ep 1)".to_string()),
+            ..Default::default()
+        });
+
+        assert_eq!(anime.staff_by_role("Key Animation")[0].id, 2);
+        assert!(anime.staff_by_role("Music").is_empty());
+    }
+
+    #[test]
+    fn test_rankings() {
+        let response: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/anime.json")).unwrap();
+        let anime: Anime = serde_json::from_value(response["data"]["Media"].clone()).unwrap();
+
+        assert_eq!(anime.all_time_rank(RankingType::Rated).unwrap().rank, 39);
+        assert!(anime.all_time_rank(RankingType::Popular).is_none());
+        assert_eq!(anime.seasonal_rank(RankingType::Rated).unwrap().rank, 1);
+        assert_eq!(anime.seasonal_rank(RankingType::Popular).unwrap().rank, 2);
+    }
+
+    #[test]
+    fn test_stats() {
+        let response: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/anime.json")).unwrap();
+        let anime: Anime = serde_json::from_value(response["data"]["Media"].clone()).unwrap();
+        let stats = anime.stats.unwrap();
+
+        assert_eq!(stats.scores()[0], (10, 120));
+        assert_eq!(stats.scores()[3], (100, 42000));
+        assert_eq!(stats.status_distribution.unwrap().len(), 5);
+    }
+
+    fn airing(at: i64, episode: u32) -> AiringSchedule {
+        AiringSchedule {
+            id: 1,
+            at,
+            time_until: 0,
+            episode,
+        }
+    }
+
+    #[test]
+    fn test_is_airing() {
+        let now = Utc::now().timestamp();
+        let mut anime = Anime::default();
+
+        assert!(!anime.is_airing());
+        assert_eq!(anime.next_episode_number(), None);
+
+        anime.next_airing_episode = Some(airing(now + 3600, 5));
+
+        assert!(anime.is_airing());
+        assert_eq!(anime.next_episode_number(), Some(5));
+
+        anime.next_airing_episode = Some(airing(now - 3600, 4));
+
+        assert!(!anime.is_airing());
+        assert_eq!(anime.next_episode_number(), None);
+    }
+
+    #[test]
+    fn test_aired_episodes() {
+        let now = Utc::now().timestamp();
+        let mut anime = Anime {
+            status: Status::Releasing,
+            episodes: Some(12),
+            ..Default::default()
+        };
+
+        assert_eq!(anime.aired_episodes(Some(now)), None);
+
+        anime.next_airing_episode = Some(airing(now + 3600, 5));
+
+        assert_eq!(anime.aired_episodes(Some(now)), Some(4));
+        assert_eq!(anime.aired_episodes(Some(now + 7200)), Some(5));
+
+        anime.next_airing_episode = Some(airing(now + 3600, 1));
+
+        assert_eq!(anime.aired_episodes(Some(now)), Some(0));
+    }
+
+    #[test]
+    fn test_hashtags() {
+        let mut anime = Anime::default();
+        assert!(anime.hashtags().is_empty());
+
+        anime.hashtag = Some("\t#ひとりぼっち\u{3000}#BocchiTheRock \n".to_string());
+        assert_eq!(anime.hashtags(), ["#ひとりぼっち", "#BocchiTheRock"]);
+
+        anime.hashtag = Some("   ".to_string());
+        assert!(anime.hashtags().is_empty());
+    }
+
+    #[test]
+    fn test_aired_episodes_without_schedule() {
+        let finished = Anime {
+            status: Status::Finished,
+            episodes: Some(26),
+            ..Default::default()
+        };
+        let upcoming = Anime {
+            status: Status::NotYetReleased,
+            episodes: Some(12),
+            ..Default::default()
+        };
+
+        assert!(finished.is_finished());
+        assert_eq!(finished.aired_episodes(None), Some(26));
+        assert!(!upcoming.is_finished());
+        assert_eq!(upcoming.aired_episodes(None), Some(0));
+        assert_eq!(
+            Anime {
+                status: Status::Finished,
+                ..Default::default()
+            }
+            .aired_episodes(None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_display_without_details() {
+        let mut anime = Anime {
+            title: serde_json::from_value(serde_json::json!({
+                "romaji": "Untitled",
+                "native": "無題"
+            }))
+            .unwrap(),
+            episodes: Some(0),
+            ..Default::default()
+        };
+
+        assert_eq!(anime.to_string(), "Untitled (TV)");
+
+        anime.episodes = Some(1);
+        anime.start_date = Some(Date {
+            year: Some(2024),
+            ..Default::default()
+        });
+
+        assert_eq!(anime.to_string(), "Untitled (2024, TV, 1 ep)");
+    }
+
+    #[test]
+    fn test_countdown() {
+        let now = Utc::now().timestamp();
+        let countdown = airing(now + 2 * 86400 + 3 * 3600, 5).countdown().unwrap();
+
+        assert!(countdown <= Duration::from_secs(2 * 86400 + 3 * 3600));
+        assert!(countdown > Duration::from_secs(2 * 86400 + 3 * 3600 - 60));
+        assert_eq!(airing(now - 1, 4).countdown(), None);
+        assert!(airing(now - 1, 4).has_aired());
+    }
+
+    #[test]
+    #[cfg(feature = "client")]
+    fn test_anilist_id_from_url() {
+        assert_eq!(
+            Anime::anilist_id_from_url("anilist.co/anime/21/ONE-PIECE").unwrap(),
+            21
+        );
+        assert!(matches!(
+            Anime::anilist_id_from_url("https://anilist.co/manga/30013"),
+            Err(crate::Error::InvalidUrl(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "client")]
+    fn test_fill_url() {
+        let mut anime = Anime {
+            id: 1.into(),
+            ..Default::default()
+        };
+        anime.fill_url();
+        assert_eq!(anime.url, "https://anilist.co/anime/1");
+
+        anime.url = "https://anilist.co/anime/1/Cowboy-Bebop".to_string();
+        anime.fill_url();
+        assert_eq!(anime.url, "https://anilist.co/anime/1/Cowboy-Bebop");
     }
-    let connection: Option<StaffConnection> = Option::deserialize(deserializer)?;
-    Ok(connection.map(|c| c.nodes))
 }
@@ -5,18 +5,24 @@
 
 use std::fmt::Display;
 
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use chrono::NaiveDate;
+use serde::{Deserialize, Deserializer, Serialize};
 
-use super::{Date, Gender, Image, Name, Person};
-use crate::{Client, Result};
+use super::connections::Connection;
+use super::debug::{ClientPlaceholder, Truncated};
+#[cfg(feature = "client")]
+use super::Page;
+use super::{CharacterId, Date, Format, Gender, Image, MediaSort, MediaType, Name, Person, Title};
+use crate::Client;
+#[cfg(feature = "client")]
+use crate::Result;
 
 /// Represents a character.
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[derive(Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Character {
     /// The ID of the character.
-    pub id: i64,
+    pub id: CharacterId,
     /// The name of the character.
     pub name: Name,
     /// The role of the character in the story.
@@ -24,18 +30,25 @@ pub struct Character {
     /// The image of the character.
     pub image: Image,
     /// The description of the character.
+    #[serde(default, deserialize_with = "deserialize_description")]
     pub description: String,
     /// The gender of the character.
     pub gender: Option<Gender>,
     /// The date of birth of the character.
     pub date_of_birth: Option<Date>,
-    /// The age of the character.
+    /// The age of the character, as free text such as `"17"`, `"17-18"`
+    /// or `"25 (first season)"`.
+    ///
+    /// See [`Character::age_range`] for a parsed representation.
     pub age: Option<String>,
     /// The blood type of the character.
     pub blood_type: Option<String>,
-    /// The medias that the character participates.
-    #[serde(rename = "media")]
-    pub(crate) medias: Option<Value>,
+    /// The first page of the medias the character appears in, most
+    /// popular first.
+    ///
+    /// Use [`Character::get_medias`] to get all of them.
+    #[serde(rename = "media", default, deserialize_with = "deserialize_medias")]
+    pub medias: Option<Vec<CharacterMedia>>,
     /// Whether the character is a favorite.
     pub is_favourite: Option<bool>,
     /// Whether the character is blocked from being a favorite.
@@ -52,13 +65,159 @@ pub struct Character {
 
     /// The client used to fetch additional data.
     #[serde(skip)]
+    #[cfg_attr(not(feature = "client"), allow(dead_code))]
     pub(crate) client: Client,
     /// Whether the person's data is fully loaded.
     #[serde(default)]
     pub(crate) is_full_loaded: bool,
+    /// Whether more medias follow the ones in `medias`.
+    #[serde(skip)]
+    pub(crate) has_more_medias: bool,
+}
+
+impl PartialEq for Character {
+    /// Compares every field of the characters but their client, whether they
+    /// are fully loaded and whether they have more medias.
+    fn eq(&self, other: &Self) -> bool {
+        let Character {
+            id,
+            name,
+            role,
+            image,
+            description,
+            gender,
+            date_of_birth,
+            age,
+            blood_type,
+            medias,
+            is_favourite,
+            is_favourite_blocked,
+            url,
+            favourites,
+            voice_actors,
+            mod_notes,
+            client: _,
+            is_full_loaded: _,
+            has_more_medias: _,
+        } = self;
+
+        *id == other.id
+            && *name == other.name
+            && *role == other.role
+            && *image == other.image
+            && *description == other.description
+            && *gender == other.gender
+            && *date_of_birth == other.date_of_birth
+            && *age == other.age
+            && *blood_type == other.blood_type
+            && *medias == other.medias
+            && *is_favourite == other.is_favourite
+            && *is_favourite_blocked == other.is_favourite_blocked
+            && *url == other.url
+            && *favourites == other.favourites
+            && *voice_actors == other.voice_actors
+            && *mod_notes == other.mod_notes
+    }
+}
+
+/// Two equal characters always have the same ID, so characters are hashed by
+/// ID.
+impl Eq for Character {}
+
+impl std::hash::Hash for Character {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl std::fmt::Debug for Character {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Character {
+            id,
+            name,
+            role,
+            image,
+            description,
+            gender,
+            date_of_birth,
+            age,
+            blood_type,
+            medias,
+            is_favourite,
+            is_favourite_blocked,
+            url,
+            favourites,
+            voice_actors,
+            mod_notes,
+            client: _,
+            is_full_loaded,
+            has_more_medias,
+        } = self;
+
+        f.debug_struct("Character")
+            .field("id", id)
+            .field("name", name)
+            .field("role", role)
+            .field("image", image)
+            .field("description", &Truncated(description))
+            .field("gender", gender)
+            .field("date_of_birth", date_of_birth)
+            .field("age", age)
+            .field("blood_type", blood_type)
+            .field("medias", medias)
+            .field("is_favourite", is_favourite)
+            .field("is_favourite_blocked", is_favourite_blocked)
+            .field("url", url)
+            .field("favourites", favourites)
+            .field("voice_actors", voice_actors)
+            .field("mod_notes", &mod_notes.as_deref().map(Truncated))
+            .field("client", &ClientPlaceholder)
+            .field("is_full_loaded", is_full_loaded)
+            .field("has_more_medias", has_more_medias)
+            .finish()
+    }
 }
 
 impl Character {
+    /// Creates a partially loaded character, to test code consuming
+    /// characters without reaching the API.
+    ///
+    /// The other fields are left to their defaults, the site URL is
+    /// built from the ID, and the character is detached: the methods
+    /// sending requests fail with [`Error::Detached`](crate::Error::Detached).
+    ///
+    /// This function is only available with the `test-util` feature,
+    /// which `test-utils` enables as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the character.
+    /// * `name` - The full name of the character.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Character;
+    /// let character = Character::for_test(1, "Spike Spiegel");
+    ///
+    /// assert_eq!(character.name.full(), Some("Spike Spiegel".to_string()));
+    /// ```
+    #[cfg(feature = "test-util")]
+    pub fn for_test(id: impl Into<CharacterId>, name: &str) -> Self {
+        let id = id.into();
+
+        Self {
+            id,
+            name: Name {
+                full: Some(name.to_string()),
+                ..Default::default()
+            },
+            url: format!("https://anilist.co/character/{id}"),
+            client: Client::detached(),
+            ..Default::default()
+        }
+    }
+
     /// Loads the full details of the character.
     ///
     /// # Errors
@@ -78,6 +237,7 @@ impl Character {
     /// let character = character.load_full().await?;
     /// # Ok(())
     /// # }
+    #[cfg(feature = "client")]
     pub async fn load_full(self) -> Result<Self> {
         if !self.is_full_loaded {
             self.client.get_character(self.id).await
@@ -86,41 +246,457 @@ impl Character {
         }
     }
 
-    /// Retrieves the media associated with the chcharacterr.
+    /// Detaches the character from its client.
+    ///
+    /// A detached character keeps its data, and the methods reading it,
+    /// such as [`Character::age_range`] and [`Character::medias_by_role`], keep working. The methods sending
+    /// requests, such as [`Character::load_full`] and [`Character::get_medias`], fail with
+    /// [`Error::Detached`](crate::Error::Detached) until a client is
+    /// attached with [`Character::attach`]. The nested models are detached
+    /// as well.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Character, Client, Result};
+    /// #
+    /// # async fn f(character: Character, client: Client) -> Result<()> {
+    /// let cached = character.detach();
+    /// // ...
+    /// let character = cached.attach(&client).load_full().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "client")]
+    pub fn detach(self) -> Self {
+        self.attach(&Client::detached())
+    }
+
+    /// Attaches the character to a client, which sends its requests from now on.
+    ///
+    /// The nested models are attached as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to attach.
+    #[cfg(feature = "client")]
+    pub fn attach(mut self, client: &Client) -> Self {
+        self.set_client(client);
+        self
+    }
+
+    /// Gives the client to the character and its nested models.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to give.
+    #[cfg(feature = "client")]
+    pub(crate) fn set_client(&mut self, client: &Client) {
+        self.client = client.clone();
+        for media in self.medias.iter_mut().flatten() {
+            for voice_actor in &mut media.voice_actors {
+                voice_actor.set_client(client);
+            }
+        }
+        for voice_actor in self.voice_actors.iter_mut().flatten() {
+            voice_actor.set_client(client);
+        }
+    }
+
+    /// Returns the age of the character as a range of years.
+    ///
+    /// The first value is the youngest age and the second one the oldest,
+    /// if the age spans several years. Parentheticals such as
+    /// `"(first season)"` are ignored. Returns `None` if the character has
+    /// no age or if it isn't numeric, such as `"Unknown"`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::models::Character;
+    /// #
+    /// # fn f(character: Character) {
+    /// // "17-18 (second season)" gives `Some((17, Some(18)))`.
+    /// if let Some((youngest, oldest)) = character.age_range() {
+    ///     println!("{youngest} to {} years old", oldest.unwrap_or(youngest));
+    /// }
+    /// # }
+    /// ```
+    pub fn age_range(&self) -> Option<(u32, Option<u32>)> {
+        let age = strip_parentheticals(self.age.as_deref()?);
+        if !age
+            .chars()
+            .all(|c| c.is_ascii_digit() || c.is_whitespace() || AGE_SEPARATORS.contains(&c))
+        {
+            return None;
+        }
+
+        let ages: Vec<u32> = age
+            .split(|c: char| !c.is_ascii_digit())
+            .filter_map(|age| age.parse().ok())
+            .collect();
+        let youngest = *ages.iter().min()?;
+        let oldest = ages
+            .iter()
+            .max()
+            .copied()
+            .filter(|&oldest| oldest != youngest);
+
+        Some((youngest, oldest))
+    }
+
+    /// Returns whether today, in the local time zone, is the birthday of
+    /// the character.
+    ///
+    /// See [`Character::is_birthday_on`].
+    pub fn is_birthday_today(&self) -> bool {
+        self.is_birthday_on(&Date::now())
+    }
+
+    /// Returns whether the given date is the birthday of the character.
+    ///
+    /// Only the month and the day are compared, as the year of birth of
+    /// characters is rarely known. A birthday on February 29 falls on
+    /// February 28 in common years. Returns `false` if the month or the
+    /// day of either date is unknown.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date to compare with the date of birth.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::models::{Character, Date};
+    /// #
+    /// # fn f(characters: Vec<Character>) {
+    /// let christmas = Date::new(Some(2025), Some(12), Some(25));
+    /// let born_on_christmas: Vec<_> = characters
+    ///     .iter()
+    ///     .filter(|character| character.is_birthday_on(&christmas))
+    ///     .collect();
+    /// # }
+    /// ```
+    pub fn is_birthday_on(&self, date: &Date) -> bool {
+        let Some(birth) = &self.date_of_birth else {
+            return false;
+        };
+        let (Some(month), Some(day), Some(birth_month), Some(birth_day)) =
+            (date.month, date.day, birth.month, birth.day)
+        else {
+            return false;
+        };
+
+        if (birth_month, birth_day) == (2, 29) && (month, day) == (2, 28) {
+            return date
+                .year
+                .is_some_and(|year| NaiveDate::from_ymd_opt(year, 2, 29).is_none());
+        }
+
+        (month, day) == (birth_month, birth_day)
+    }
+
+    /// Returns the loaded medias in which the character has the given role.
+    ///
+    /// Only the medias in [`Character::medias`] are considered; use
+    /// [`Character::get_medias`] to consider all of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - The role of the character.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::{Character, CharacterRole}, Result};
+    /// #
+    /// # async fn f(character: Character) -> Result<()> {
+    /// for media in character.medias_by_role(CharacterRole::Main) {
+    ///     println!("{}", media.title.romaji());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn medias_by_role(&self, role: CharacterRole) -> Vec<&CharacterMedia> {
+        self.medias
+            .iter()
+            .flatten()
+            .filter(|media| media.character_role.as_ref() == Some(&role))
+            .collect()
+    }
+
+    /// Retrieves all the medias the character appears in, most popular
+    /// first.
+    ///
+    /// No request is sent if [`Character::medias`] already holds all of
+    /// them; otherwise the missing pages are fetched.
     ///
     /// # Errors
     ///
-    /// Returns an error if the media cannot be retrieved.
+    /// Returns an error if a page of medias cannot be retrieved.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Character, Result};
+    /// #
+    /// # async fn f(character: Character) -> Result<()> {
+    /// let medias = character.get_medias().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "client")]
+    pub async fn get_medias(&self) -> Result<Vec<CharacterMedia>> {
+        let (mut medias, first_page) = match &self.medias {
+            Some(medias) if !self.has_more_medias => return Ok(medias.clone()),
+            Some(medias) => (medias.clone(), 2),
+            None => (Vec::new(), 1),
+        };
+
+        let mut page = Some(
+            self.client
+                .get_character_medias(self.id, first_page, MEDIAS_PER_PAGE)
+                .await?,
+        );
+        while let Some(current) = page {
+            page = current.next().await?;
+            medias.extend(current);
+        }
+
+        Ok(medias)
+    }
+
+    /// Retrieves a page of the medias the character appears in, in the
+    /// order and with the options of the filter.
+    ///
+    /// The role of the character in each media is in
+    /// [`CharacterMedia::character_role`]. AniList can't filter by role,
+    /// so filter the items of the pages to keep a role only.
+    ///
+    /// # Arguments
     ///
-    /// # Type Parameters
+    /// * `filter` - The options of the request.
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of medias to get per page.
     ///
-    /// * `T` - The type of the media to be returned.
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// # use rust_anilist::{models::{Manga, Character}, Result};
+    /// # use rust_anilist::{models::{Character, CharacterMediaFilter, CharacterRole, MediaSort}, Result};
     /// #
     /// # async fn f(character: Character) -> Result<()> {
-    /// let char_mangas = character.get_medias::<Manga>().await?;
+    /// let filter = CharacterMediaFilter::new().sort(MediaSort::StartDate);
+    /// let mut page = Some(character.get_medias_filtered(filter, 1, 25).await?);
+    ///
+    /// while let Some(current) = page {
+    ///     for media in current.iter() {
+    ///         if media.character_role == Some(CharacterRole::Main) {
+    ///             println!("{}", media.title.romaji());
+    ///         }
+    ///     }
+    ///
+    ///     page = current.next().await?;
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_medias<T>(&self) -> Result<Vec<T>> {
-        unimplemented!()
+    #[cfg(feature = "client")]
+    pub async fn get_medias_filtered(
+        &self,
+        filter: CharacterMediaFilter,
+        page: u16,
+        per_page: u16,
+    ) -> Result<Page<CharacterMedia>> {
+        self.client
+            .get_character_medias_filtered(self.id, filter, page, per_page)
+            .await
+    }
+}
+
+/// Represents the options of a request for the medias of a character.
+///
+/// # Example
+///
+/// ```
+/// # use rust_anilist::models::{CharacterMediaFilter, MediaSort};
+/// let filter = CharacterMediaFilter::new()
+///     .sort(MediaSort::StartDate)
+///     .on_list(true);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(not(feature = "client"), allow(dead_code))]
+pub struct CharacterMediaFilter {
+    /// The order of the medias.
+    pub(crate) sort: MediaSort,
+    /// Whether the medias must be in the list of the user, if set.
+    pub(crate) on_list: Option<bool>,
+}
+
+impl CharacterMediaFilter {
+    /// Creates a filter keeping every media, most popular first.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the order of the medias.
+    ///
+    /// # Arguments
+    ///
+    /// * `sort` - The order of the medias.
+    pub fn sort(mut self, sort: MediaSort) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Only keeps the medias in the list of the authenticated user, or
+    /// only the ones out of it.
+    ///
+    /// Without a token, AniList ignores this option.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_list` - Whether the medias must be in the list.
+    pub fn on_list(mut self, on_list: bool) -> Self {
+        self.on_list = Some(on_list);
+        self
+    }
+
+    /// Returns the variables of the filter.
+    #[cfg(feature = "client")]
+    pub(crate) fn variables(&self) -> serde_json::Value {
+        let mut variables = serde_json::json!({ "sort": [self.sort] });
+        if let Some(on_list) = self.on_list {
+            variables["onList"] = on_list.into();
+        }
+
+        variables
+    }
+}
+
+/// The number of medias in the first page loaded with a character.
+#[cfg(feature = "client")]
+const MEDIAS_PER_PAGE: u16 = 25;
+
+/// The characters allowed between the ages of a character.
+const AGE_SEPARATORS: [char; 6] = ['-', '~', '\u{2013}', ',', '/', '+'];
+
+/// Removes the parenthesized and bracketed parts of a text.
+fn strip_parentheticals(text: &str) -> String {
+    let mut depth = 0usize;
+
+    text.chars()
+        .filter(|&c| match c {
+            '(' | '[' => {
+                depth += 1;
+                false
+            }
+            ')' | ']' => {
+                depth = depth.saturating_sub(1);
+                false
+            }
+            _ => depth == 0,
+        })
+        .collect()
+}
+
+/// Represents a media a character appears in.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CharacterMedia {
+    /// The ID of the media.
+    pub media_id: i64,
+    /// The title of the media.
+    pub title: Title,
+    /// The type of the media, either anime or manga.
+    pub media_type: MediaType,
+    /// The format of the media.
+    pub format: Option<Format>,
+    /// The role of the character in the media.
+    pub character_role: Option<CharacterRole>,
+    /// The voice actors of the character in the media, if it is an anime.
+    #[serde(default)]
+    pub voice_actors: Vec<Person>,
+}
+
+/// Represents an edge of the media connection of a character.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CharacterMediaEdge {
+    node: CharacterMediaNode,
+    character_role: Option<CharacterRole>,
+    voice_actors: Option<Vec<Person>>,
+}
+
+/// Represents the media of an edge of the media connection of a character.
+#[derive(Deserialize)]
+struct CharacterMediaNode {
+    id: i64,
+    title: Title,
+    #[serde(rename = "type")]
+    media_type: Option<MediaType>,
+    format: Option<Format>,
+}
+
+impl From<CharacterMediaEdge> for CharacterMedia {
+    fn from(edge: CharacterMediaEdge) -> Self {
+        Self {
+            media_id: edge.node.id,
+            title: edge.node.title,
+            media_type: edge.node.media_type.unwrap_or_default(),
+            format: edge.node.format,
+            character_role: edge.character_role,
+            voice_actors: edge.voice_actors.unwrap_or_default(),
+        }
     }
 }
 
+fn deserialize_description<'de, D>(deserializer: D) -> std::result::Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::deserialize(deserializer)?.unwrap_or_default())
+}
+
+fn deserialize_medias<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Vec<CharacterMedia>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct CharacterMediaConnection {
+        edges: Vec<CharacterMediaEdge>,
+    }
+
+    let medias: Option<Connection<CharacterMediaConnection, CharacterMedia>> =
+        Option::deserialize(deserializer)?;
+
+    Ok(medias.map(|medias| match medias {
+        Connection::Connection(conn) => conn.edges.into_iter().map(Into::into).collect(),
+        Connection::List(medias) => medias,
+    }))
+}
+
 /// Represents the role of a character in a story.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
 pub enum CharacterRole {
     /// A background character.
     #[default]
+    #[serde(alias = "BACKGROUND")]
     Background,
     /// A main character.
+    #[serde(alias = "MAIN")]
     Main,
     /// A supporting character.
+    #[serde(alias = "SUPPORTING")]
     Supporting,
+    /// A value unknown to this version of the crate, as sent by AniList.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl From<&str> for CharacterRole {
@@ -128,7 +704,8 @@ impl From<&str> for CharacterRole {
         match value {
             "MAIN" => CharacterRole::Main,
             "SUPPORTING" => CharacterRole::Supporting,
-            _ => CharacterRole::Background,
+            "BACKGROUND" => CharacterRole::Background,
+            _ => CharacterRole::Unknown(value.to_string()),
         }
     }
 }
@@ -145,6 +722,178 @@ impl Display for CharacterRole {
             CharacterRole::Background => write!(f, "Background"),
             CharacterRole::Main => write!(f, "Main"),
             CharacterRole::Supporting => write!(f, "Supporting"),
+            CharacterRole::Unknown(role) => write!(f, "{role}"),
+        }
+    }
+}
+
+impl Display for Character {
+    /// Writes the name of the character followed by its role, e.g.
+    /// `Spike Spiegel [Main]`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(role) = &self.role {
+            write!(f, " [{role}]")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn character() -> Character {
+        serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "name": { "full": "Spike Spiegel", "alternative": [] },
+            "image": { "large": "", "medium": "" },
+            "description": "",
+            "siteUrl": "https://anilist.co/character/1",
+            "media": {
+                "edges": [
+                    {
+                        "characterRole": "MAIN",
+                        "voiceActors": [],
+                        "node": {
+                            "id": 1,
+                            "title": { "romaji": "Cowboy Bebop", "native": "カウボーイビバップ" },
+                            "type": "ANIME",
+                            "format": "TV"
+                        }
+                    },
+                    {
+                        "characterRole": "SUPPORTING",
+                        "voiceActors": null,
+                        "node": {
+                            "id": 173,
+                            "title": { "romaji": "Cowboy Bebop: Shooting Star", "native": "カウボーイビバップ シューティングスター" },
+                            "type": "MANGA",
+                            "format": "MANGA"
+                        }
+                    }
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_medias() {
+        let medias = character().medias.unwrap();
+
+        assert_eq!(medias.len(), 2);
+        assert_eq!(medias[0].media_id, 1);
+        assert_eq!(medias[0].media_type, MediaType::Anime);
+        assert_eq!(medias[0].format, Some(Format::Tv));
+        assert_eq!(medias[1].media_type, MediaType::Manga);
+        assert_eq!(medias[1].character_role, Some(CharacterRole::Supporting));
+        assert!(medias[1].voice_actors.is_empty());
+    }
+
+    #[test]
+    fn test_medias_by_role() {
+        let character = character();
+        let main = character.medias_by_role(CharacterRole::Main);
+
+        assert_eq!(main.len(), 1);
+        assert_eq!(main[0].title.romaji(), "Cowboy Bebop");
+    }
+
+    fn age_range(age: &str) -> Option<(u32, Option<u32>)> {
+        Character {
+            age: Some(age.to_string()),
+            ..Default::default()
         }
+        .age_range()
+    }
+
+    #[test]
+    fn test_age_range_single() {
+        assert_eq!(age_range("17"), Some((17, None)));
+        assert_eq!(age_range(" 17 "), Some((17, None)));
+        assert_eq!(age_range("17+"), Some((17, None)));
+    }
+
+    #[test]
+    fn test_age_range_range() {
+        assert_eq!(age_range("17-18"), Some((17, Some(18))));
+        assert_eq!(age_range("17 ~ 19"), Some((17, Some(19))));
+        assert_eq!(age_range("16, 19"), Some((16, Some(19))));
+        assert_eq!(age_range("18-17"), Some((17, Some(18))));
+    }
+
+    #[test]
+    fn test_age_range_with_notes() {
+        assert_eq!(age_range("25 (first season)"), Some((25, None)));
+        assert_eq!(age_range("16 (Part 1), 19 (Part 2)"), Some((16, Some(19))));
+        assert_eq!(age_range("14-15 [manga]"), Some((14, Some(15))));
+    }
+
+    #[test]
+    fn test_age_range_garbage() {
+        assert_eq!(age_range("Unknown"), None);
+        assert_eq!(age_range("Teens"), None);
+        assert_eq!(age_range("Over 1000"), None);
+        assert_eq!(age_range("(10)"), None);
+        assert_eq!(age_range(""), None);
+        assert_eq!(Character::default().age_range(), None);
+    }
+
+    fn born_on(month: u32, day: u32) -> Character {
+        Character {
+            date_of_birth: Some(Date::new(None, Some(month), Some(day))),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_birthday_on() {
+        let character = born_on(6, 26);
+
+        assert!(character.is_birthday_on(&Date::new(Some(2024), Some(6), Some(26))));
+        assert!(character.is_birthday_on(&Date::new(None, Some(6), Some(26))));
+        assert!(!character.is_birthday_on(&Date::new(Some(2024), Some(6), Some(27))));
+        assert!(!character.is_birthday_on(&Date::new(Some(2024), Some(6), None)));
+    }
+
+    #[test]
+    fn test_is_birthday_on_leap_day() {
+        let character = born_on(2, 29);
+
+        assert!(character.is_birthday_on(&Date::new(Some(2024), Some(2), Some(29))));
+        assert!(!character.is_birthday_on(&Date::new(Some(2024), Some(2), Some(28))));
+        assert!(character.is_birthday_on(&Date::new(Some(2025), Some(2), Some(28))));
+    }
+
+    #[test]
+    fn test_is_birthday_unknown() {
+        let character = Character {
+            date_of_birth: Some(Date::new(None, Some(6), None)),
+            ..Default::default()
+        };
+
+        assert!(!character.is_birthday_on(&Date::new(Some(2024), Some(6), Some(1))));
+        assert!(!Character::default().is_birthday_today());
+    }
+
+    #[test]
+    fn test_serialize_round_trip() {
+        let character = character();
+        let value = serde_json::to_value(&character).unwrap();
+        let round_trip: Character = serde_json::from_value(value).unwrap();
+
+        assert_eq!(round_trip.medias, character.medias);
+    }
+
+    #[test]
+    fn test_role_from_str() {
+        assert_eq!(CharacterRole::from("MAIN"), CharacterRole::Main);
+        assert_eq!(CharacterRole::from("BACKGROUND"), CharacterRole::Background);
+        assert_eq!(
+            CharacterRole::from("CAMEO"),
+            CharacterRole::Unknown("CAMEO".to_string())
+        );
     }
 }
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 /// associated variant. Additionally, it supports custom colors defined
 /// by a hex string.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "UPPERCASE"))]
+#[serde(rename_all = "UPPERCASE")]
 pub enum Color {
     /// The blue color.
     Blue,
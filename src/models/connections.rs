@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the deserializers of the connections shared by
+//! the media models, such as their characters, staff, studios and
+//! relations.
+
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+use super::{Character, Person, Relation, Studio};
+use crate::Client;
+
+/// Represents a list as returned by the API, or as serialized by this crate.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Connection<C, T> {
+    /// The plain list serialized by this crate.
+    List(Vec<T>),
+    /// The connection object returned by the API.
+    Connection(C),
+}
+
+pub(super) fn deserialize_studios<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Vec<Studio>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct StudioEdge {
+        node: Studio,
+        is_main: Option<bool>,
+    }
+    #[derive(Deserialize)]
+    struct StudioConnection {
+        edges: Option<Vec<StudioEdge>>,
+        nodes: Option<Vec<Studio>>,
+    }
+
+    let studios: Option<Connection<StudioConnection, Studio>> = Option::deserialize(deserializer)?;
+
+    Ok(studios.map(|studios| match studios {
+        Connection::Connection(StudioConnection {
+            edges: Some(edges), ..
+        }) => edges
+            .into_iter()
+            .map(|edge| {
+                let mut studio = edge.node;
+                studio.is_main = edge.is_main;
+                studio
+            })
+            .collect(),
+        // Queries selecting the nodes don't tell the main studio.
+        Connection::Connection(conn) => conn.nodes.unwrap_or_default(),
+        Connection::List(studios) => studios,
+    }))
+}
+
+pub(super) fn deserialize_characters<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Vec<Character>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct CharacterEdge {
+        node: Character,
+        role: Option<String>,
+        voice_actors: Option<Vec<Person>>,
+    }
+    #[derive(Deserialize)]
+    struct CharacterConnection {
+        edges: Vec<CharacterEdge>,
+    }
+
+    let characters: Option<Connection<CharacterConnection, Character>> =
+        Option::deserialize(deserializer)?;
+
+    Ok(characters.map(|characters| match characters {
+        Connection::Connection(conn) => conn
+            .edges
+            .into_iter()
+            .map(|edge| {
+                let mut character = edge.node;
+                if let Some(role_str) = edge.role {
+                    character.role = Some(role_str.into());
+                }
+                if let Some(voice_actors) = edge.voice_actors {
+                    character.voice_actors = Some(voice_actors);
+                }
+                character
+            })
+            .collect(),
+        Connection::List(characters) => characters,
+    }))
+}
+
+pub(super) fn deserialize_staff<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Vec<Person>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct StaffEdge {
+        node: Person,
+        role: Option<String>,
+    }
+    #[derive(Deserialize)]
+    struct StaffConnection {
+        edges: Vec<StaffEdge>,
+    }
+
+    let staff: Option<Connection<StaffConnection, Person>> = Option::deserialize(deserializer)?;
+
+    Ok(staff.map(|staff| match staff {
+        Connection::Connection(conn) => conn
+            .edges
+            .into_iter()
+            .map(|edge| {
+                let mut person = edge.node;
+                person.staff_role = edge.role;
+                person
+            })
+            .collect(),
+        Connection::List(staff) => staff,
+    }))
+}
+
+/// Parses the characters of a character connection, with their role and
+/// voice actors.
+///
+/// # Arguments
+///
+/// * `connection` - The character connection, holding the edges.
+#[cfg(feature = "client")]
+pub(crate) fn character_edges(connection: &Value) -> Vec<Character> {
+    deserialize_characters(connection)
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// Parses the people of a staff connection, with their role.
+///
+/// # Arguments
+///
+/// * `connection` - The staff connection, holding the edges.
+#[cfg(feature = "client")]
+pub(crate) fn staff_edges(connection: &Value) -> Vec<Person> {
+    deserialize_staff(connection)
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// Parses the relations of a media from its relation connection.
+///
+/// # Arguments
+///
+/// * `relations` - The relation connection of the media.
+/// * `client` - The client given to the relations.
+pub(super) fn parse_relations(relations: &Value, client: &Client) -> Vec<Relation> {
+    relations["edges"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .map(|edge| {
+            let mut relation = Relation::deserialize(edge).unwrap_or_default();
+            relation.client = client.clone();
+            relation
+        })
+        .collect()
+}
@@ -5,6 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::image::is_default_url;
 use crate::models::Color;
 
 /// Represents the cover images of various sizes and the color of the cover.
@@ -12,7 +13,7 @@ use crate::models::Color;
 /// The `Cover` struct contains URLs for the cover images in different sizes
 /// (extra large, large, and medium) and an optional color.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Cover {
     /// The URL of the cover image in extra large size.
     pub extra_large: Option<String>,
@@ -37,6 +38,20 @@ impl Cover {
             None
         }
     }
+
+    /// Returns whether the cover is AniList's default placeholder.
+    ///
+    /// The cover is considered a placeholder if it has at least one URL
+    /// and all of its URLs point to the placeholder image.
+    pub fn is_default(&self) -> bool {
+        let urls = [
+            self.extra_large.as_deref(),
+            self.large.as_deref(),
+            self.medium.as_deref(),
+        ];
+
+        urls.iter().any(Option::is_some) && urls.iter().flatten().all(|url| is_default_url(url))
+    }
 }
 
 #[cfg(test)]
@@ -90,4 +105,39 @@ mod tests {
 
         assert_eq!(cover.largest(), None);
     }
+
+    #[test]
+    fn test_is_default() {
+        let cover = Cover {
+            extra_large: Some(
+                "https://s4.anilist.co/file/anilistcdn/media/anime/cover/large/default.jpg"
+                    .to_string(),
+            ),
+            large: Some(
+                "https://s4.anilist.co/file/anilistcdn/media/anime/cover/medium/default.jpg"
+                    .to_string(),
+            ),
+            medium: None,
+            color: None,
+        };
+
+        assert!(cover.is_default());
+    }
+
+    #[test]
+    fn test_is_default_with_real_cover() {
+        let cover = Cover {
+            extra_large: Some("https://example.com/bx1-CXtrrkMpJ8Zq.png".to_string()),
+            large: None,
+            medium: None,
+            color: None,
+        };
+
+        assert!(!cover.is_default());
+    }
+
+    #[test]
+    fn test_is_default_with_none() {
+        assert!(!Cover::default().is_default());
+    }
 }
@@ -6,8 +6,32 @@
 use chrono::{Datelike, Local, NaiveDate};
 use serde::{Deserialize, Serialize};
 
+/// The English names of the months.
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
 /// Represents a date.
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+///
+/// Any component may be missing: AniList only knows the year some
+/// animes will air, and the birthdays of characters often lack a year.
+///
+/// Dates are ordered by year, then month, then day, a missing component
+/// sorting before any known one. So `2016` sorts before `January 2016`,
+/// which sorts before `January 1, 2016`, and a date without a year sorts
+/// before every date with one.
+#[derive(Debug, Default, Clone, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Date {
     /// The year of the date.
@@ -129,6 +153,44 @@ impl Date {
     pub fn is_valid(&self) -> bool {
         self.year.is_some() && self.month.is_some() && self.day.is_some()
     }
+
+    /// Returns whether the year, the month and the day are all known.
+    pub fn is_complete(&self) -> bool {
+        self.year.is_some() && self.month.is_some() && self.day.is_some()
+    }
+
+    /// Formats the known components of the date in the ISO 8601 format.
+    ///
+    /// The date is truncated at the first missing component, e.g.
+    /// `2016-04` when the day is unknown. A date without a year, like a
+    /// birthday, is formatted as `--04-03`; an empty string is returned
+    /// if its month or day is unknown too.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Date;
+    /// assert_eq!(Date::new(Some(2016), Some(4), Some(3)).format_iso(), "2016-04-03");
+    /// assert_eq!(Date::new(Some(2016), Some(4), None).format_iso(), "2016-04");
+    /// assert_eq!(Date::new(Some(2016), None, Some(3)).format_iso(), "2016");
+    /// assert_eq!(Date::new(None, Some(4), Some(3)).format_iso(), "--04-03");
+    /// ```
+    pub fn format_iso(&self) -> String {
+        match (self.year, self.month, self.day) {
+            (Some(year), Some(month), Some(day)) => format!("{year:04}-{month:02}-{day:02}"),
+            (Some(year), Some(month), None) => format!("{year:04}-{month:02}"),
+            (Some(year), None, _) => format!("{year:04}"),
+            (None, Some(month), Some(day)) => format!("--{month:02}-{day:02}"),
+            (None, _, _) => String::new(),
+        }
+    }
+
+    /// Returns the English name of the month, if known and valid.
+    fn month_name(&self) -> Option<&'static str> {
+        let month = self.month?.checked_sub(1)?;
+
+        MONTHS.get(month as usize).copied()
+    }
 }
 
 impl From<NaiveDate> for Date {
@@ -147,9 +209,18 @@ impl From<Date> for NaiveDate {
     }
 }
 
+/// Writes the known components of the date, e.g. `April 3, 2016`,
+/// `April 2016`, `2016` or `April 3`.
 impl std::fmt::Display for Date {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.as_string())
+        match (self.month_name(), self.day, self.year) {
+            (Some(month), Some(day), Some(year)) => write!(f, "{month} {day}, {year}"),
+            (Some(month), Some(day), None) => write!(f, "{month} {day}"),
+            (Some(month), None, Some(year)) => write!(f, "{month} {year}"),
+            (Some(month), None, None) => write!(f, "{month}"),
+            (None, _, Some(year)) => write!(f, "{year}"),
+            (None, _, None) => Ok(()),
+        }
     }
 }
 
@@ -232,4 +303,72 @@ mod tests {
         assert!(valid_date.is_valid());
         assert!(!invalid_date.is_valid());
     }
+
+    #[test]
+    fn test_is_complete() {
+        assert!(Date::new(Some(2016), Some(4), Some(3)).is_complete());
+        assert!(!Date::new(Some(2016), Some(4), None).is_complete());
+        assert!(!Date::new(None, Some(4), Some(3)).is_complete());
+    }
+
+    #[test]
+    fn test_format_iso() {
+        assert_eq!(
+            Date::new(Some(2016), Some(4), Some(3)).format_iso(),
+            "2016-04-03"
+        );
+        assert_eq!(Date::new(Some(2016), Some(4), None).format_iso(), "2016-04");
+        assert_eq!(Date::new(Some(2016), None, None).format_iso(), "2016");
+        assert_eq!(Date::new(None, Some(12), Some(25)).format_iso(), "--12-25");
+        assert_eq!(Date::new(None, Some(12), None).format_iso(), "");
+        assert_eq!(Date::default().format_iso(), "");
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Date::new(Some(2016), Some(4), Some(3)).to_string(),
+            "April 3, 2016"
+        );
+        assert_eq!(
+            Date::new(Some(2016), Some(4), None).to_string(),
+            "April 2016"
+        );
+        assert_eq!(Date::new(Some(2016), None, Some(3)).to_string(), "2016");
+        assert_eq!(
+            Date::new(None, Some(12), Some(25)).to_string(),
+            "December 25"
+        );
+        assert_eq!(Date::new(Some(2016), Some(13), Some(3)).to_string(), "2016");
+        assert_eq!(Date::default().to_string(), "");
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut dates = [
+            Date::new(Some(2016), Some(4), Some(3)),
+            Date::new(Some(2015), Some(12), Some(31)),
+            Date::new(Some(2016), Some(4), None),
+            Date::new(Some(2016), None, None),
+            Date::new(None, Some(1), Some(1)),
+            Date::new(Some(2016), Some(1), Some(20)),
+            Date::default(),
+        ];
+        dates.sort();
+
+        assert_eq!(
+            dates.iter().map(Date::format_iso).collect::<Vec<_>>(),
+            [
+                "",
+                "--01-01",
+                "2015-12-31",
+                "2016",
+                "2016-01-20",
+                "2016-04",
+                "2016-04-03"
+            ]
+        );
+        assert!(Date::new(Some(2016), None, None) < Date::new(Some(2016), Some(1), Some(1)));
+        assert!(Date::new(Some(2017), None, None) > Date::new(Some(2016), Some(12), Some(31)));
+    }
 }
@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the helpers of the `Debug` output of the models.
+//!
+//! The models carrying a client implement `Debug` by hand so their
+//! output leaves out the client and stays short enough for logs. The
+//! implementations destructure the model, so a field added later can't
+//! be left out of the output unnoticed.
+
+use std::fmt::{Debug, Formatter, Result};
+
+use serde_json::Value;
+
+/// The number of characters of a text kept in the `Debug` output.
+const TEXT_LIMIT: usize = 80;
+
+/// Stands in for the client of a model, which is never printed.
+pub(super) struct ClientPlaceholder;
+
+impl Debug for ClientPlaceholder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "Client {{ .. }}")
+    }
+}
+
+/// Prints the beginning of a long text, such as a description.
+pub(super) struct Truncated<'a>(pub(super) &'a str);
+
+impl Debug for Truncated<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.0.char_indices().nth(TEXT_LIMIT) {
+            Some((end, _)) => write!(f, "{:?}…", &self.0[..end]),
+            None => write!(f, "{:?}", self.0),
+        }
+    }
+}
+
+/// Prints the number of edges or nodes of a raw connection.
+pub(super) struct ConnectionSummary<'a>(pub(super) &'a Value, pub(super) &'static str);
+
+impl Debug for ConnectionSummary<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let (count, kind) = match (self.0["edges"].as_array(), self.0["nodes"].as_array()) {
+            (Some(edges), _) => (edges.len(), "edges"),
+            (None, Some(nodes)) => (nodes.len(), "nodes"),
+            (None, None) => (0, "edges"),
+        };
+
+        write!(f, "<{count} {} {kind}>", self.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncated() {
+        let long = "a".repeat(100);
+
+        assert_eq!(format!("{:?}", Truncated("short")), "\"short\"");
+        assert_eq!(
+            format!("{:?}", Truncated(&long)),
+            format!("\"{}\"…", "a".repeat(TEXT_LIMIT))
+        );
+    }
+
+    #[test]
+    fn test_connection_summary() {
+        let edges = serde_json::json!({ "edges": [{}, {}] });
+        let nodes = serde_json::json!({ "nodes": [{}] });
+
+        assert_eq!(
+            format!("{:?}", ConnectionSummary(&edges, "relation")),
+            "<2 relation edges>"
+        );
+        assert_eq!(
+            format!("{:?}", ConnectionSummary(&nodes, "media")),
+            "<1 media nodes>"
+        );
+        assert_eq!(
+            format!("{:?}", ConnectionSummary(&Value::Null, "relation")),
+            "<0 relation edges>"
+        );
+    }
+}
@@ -0,0 +1,500 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the exporters of the `MediaListCollection` struct
+//! and the `MalExport` struct.
+
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+use super::{Date, MediaListCollection, MediaListEntry, MediaListStatus, MediaType};
+use crate::{Error, Result};
+
+/// The columns of the exported lists, in order.
+const COLUMNS: [&str; 11] = [
+    "media_id",
+    "mal_id",
+    "title_romaji",
+    "title_english",
+    "status",
+    "score",
+    "progress",
+    "started_at",
+    "completed_at",
+    "repeat",
+    "notes",
+];
+
+/// An exported list entry, holding one field per column.
+#[derive(Serialize)]
+struct Row<'a> {
+    media_id: i64,
+    mal_id: Option<i64>,
+    title_romaji: Option<&'a str>,
+    title_english: Option<&'a str>,
+    status: Option<String>,
+    score: Option<f32>,
+    progress: u32,
+    started_at: Option<String>,
+    completed_at: Option<String>,
+    repeat: u32,
+    notes: Option<&'a str>,
+}
+
+impl<'a> From<&'a MediaListEntry> for Row<'a> {
+    fn from(entry: &'a MediaListEntry) -> Self {
+        let iso =
+            |date: &Option<Date>| Some(date.as_ref()?.format_iso()).filter(|date| !date.is_empty());
+
+        Self {
+            media_id: entry.media_id,
+            mal_id: entry.media.as_ref().and_then(|media| media.id_mal),
+            title_romaji: entry.media.as_ref().map(|media| media.title.romaji()),
+            title_english: entry.media.as_ref().map(|media| media.title.english()),
+            status: entry.status.map(|status| status.to_string().to_uppercase()),
+            score: Some(entry.score).filter(|score| *score > 0.0),
+            progress: entry.progress,
+            started_at: iso(&entry.started_at),
+            completed_at: iso(&entry.completed_at),
+            repeat: entry.repeat,
+            notes: entry.notes.as_deref().filter(|notes| !notes.is_empty()),
+        }
+    }
+}
+
+impl Row<'_> {
+    /// Returns the fields of the row, in the order of the columns.
+    ///
+    /// Missing values are empty.
+    fn fields(&self) -> [String; 11] {
+        fn optional<T: ToString>(value: Option<T>) -> String {
+            value.map(|value| value.to_string()).unwrap_or_default()
+        }
+
+        [
+            self.media_id.to_string(),
+            optional(self.mal_id),
+            optional(self.title_romaji),
+            optional(self.title_english),
+            optional(self.status.as_ref()),
+            optional(self.score),
+            self.progress.to_string(),
+            optional(self.started_at.as_ref()),
+            optional(self.completed_at.as_ref()),
+            self.repeat.to_string(),
+            optional(self.notes),
+        ]
+    }
+}
+
+impl MediaListCollection {
+    /// Writes the entries of the list as CSV, one line per entry after a
+    /// header line.
+    ///
+    /// The columns are, in order:
+    ///
+    /// - `media_id`: the AniList ID of the media.
+    /// - `mal_id`: the MyAnimeList ID of the media.
+    /// - `title_romaji`, `title_english`: the titles of the media, falling
+    ///   back to the native title.
+    /// - `status`: the status of the entry, e.g. `CURRENT`.
+    /// - `score`: the score on the 100-point scale.
+    /// - `progress`: the number of episodes watched or chapters read.
+    /// - `started_at`, `completed_at`: the dates in the ISO 8601 format,
+    ///   truncated when partial, e.g. `2016-04`.
+    /// - `repeat`: the number of rewatches or rereads.
+    /// - `notes`: the notes of the user.
+    ///
+    /// Missing values and unscored entries are left empty. Fields holding
+    /// commas, quotes or line breaks are quoted as described by RFC 4180.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Where to write the CSV.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::models::MediaType;
+    /// # async fn f(client: rust_anilist::Client) -> Result<(), Box<dyn std::error::Error>> {
+    /// let list = client
+    ///     .get_media_list_collection("andrielfr", MediaType::Anime)
+    ///     .await?;
+    /// list.to_csv(std::fs::File::create("anime.csv")?)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "{}", COLUMNS.join(","))?;
+
+        for entry in &self.entries {
+            let fields = Row::from(entry).fields().map(|field| escape_csv(&field));
+            writeln!(writer, "{}", fields.join(","))?;
+        }
+
+        writer.flush()
+    }
+
+    /// Writes the entries of the list as a JSON array.
+    ///
+    /// Each entry is an object holding the columns described in
+    /// [`MediaListCollection::to_csv`], missing values being `null`.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Where to write the JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    pub fn to_json<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let rows = self.entries.iter().map(Row::from).collect::<Vec<_>>();
+        serde_json::to_writer_pretty(&mut writer, &rows)?;
+
+        writer.flush()
+    }
+}
+
+/// Represents a list exported in the XML format of MyAnimeList.
+///
+/// See [`MediaListCollection::to_mal_xml`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MalExport {
+    /// The XML document, to import on MyAnimeList.
+    pub xml: String,
+    /// The AniList IDs of the medias left out because they are not on
+    /// MyAnimeList.
+    pub skipped: Vec<i64>,
+}
+
+impl MediaListCollection {
+    /// Exports the list in the XML format imported by MyAnimeList.
+    ///
+    /// Each entry is written with the fields of the MyAnimeList export:
+    /// the MyAnimeList ID of the media, the status, the score rounded to
+    /// 1–10, the progress, the dates, the number of rewatches or
+    /// rereads and the notes. Entries are updated on import.
+    ///
+    /// Entries whose media has no MyAnimeList ID can't be imported, so
+    /// they are left out and reported in [`MalExport::skipped`].
+    ///
+    /// # Arguments
+    ///
+    /// * `media_type` - The type of the list, either `Anime` or `Manga`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidMediaType`] if `media_type` is neither
+    /// `Anime` nor `Manga`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::models::MediaType;
+    /// # async fn f(client: rust_anilist::Client) -> Result<(), Box<dyn std::error::Error>> {
+    /// let list = client
+    ///     .get_media_list_collection("andrielfr", MediaType::Anime)
+    ///     .await?;
+    /// let export = list.to_mal_xml(MediaType::Anime)?;
+    /// std::fs::write("animelist.xml", export.xml)?;
+    /// for id in export.skipped {
+    ///     eprintln!("https://anilist.co/anime/{id} is not on MyAnimeList");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_mal_xml(&self, media_type: MediaType) -> Result<MalExport> {
+        let is_anime = match media_type {
+            MediaType::Anime => true,
+            MediaType::Manga => false,
+            _ => return Err(Error::InvalidMediaType),
+        };
+
+        let mut export = MalExport::default();
+        let xml = &mut export.xml;
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\" ?>\n<myanimelist>\n");
+        xml.push_str("\t<myinfo>\n");
+        push_element(xml, 2, "user_export_type", if is_anime { 1 } else { 2 });
+        xml.push_str("\t</myinfo>\n");
+
+        for entry in &self.entries {
+            let Some(mal_id) = entry.media.as_ref().and_then(|media| media.id_mal) else {
+                export.skipped.push(entry.media_id);
+                continue;
+            };
+            let title = entry
+                .media
+                .as_ref()
+                .map(|media| media.title.romaji())
+                .unwrap_or_default();
+            let status = mal_status(entry.status.unwrap_or_default(), is_anime);
+            let score = (entry.score / 10.0).round().clamp(0.0, 10.0);
+            let is_repeating = u8::from(entry.status == Some(MediaListStatus::Repeating));
+
+            if is_anime {
+                xml.push_str("\t<anime>\n");
+                push_element(xml, 2, "series_animedb_id", mal_id);
+                push_element(xml, 2, "series_title", cdata(title));
+                push_element(xml, 2, "my_watched_episodes", entry.progress);
+            } else {
+                xml.push_str("\t<manga>\n");
+                push_element(xml, 2, "manga_mangadb_id", mal_id);
+                push_element(xml, 2, "manga_title", cdata(title));
+                push_element(
+                    xml,
+                    2,
+                    "my_read_volumes",
+                    entry.progress_volumes.unwrap_or(0),
+                );
+                push_element(xml, 2, "my_read_chapters", entry.progress);
+            }
+            push_element(xml, 2, "my_start_date", mal_date(&entry.started_at));
+            push_element(xml, 2, "my_finish_date", mal_date(&entry.completed_at));
+            push_element(xml, 2, "my_score", score);
+            push_element(xml, 2, "my_status", status);
+            push_element(
+                xml,
+                2,
+                "my_comments",
+                cdata(entry.notes.as_deref().unwrap_or_default()),
+            );
+            if is_anime {
+                push_element(xml, 2, "my_times_watched", entry.repeat);
+                push_element(xml, 2, "my_rewatching", is_repeating);
+            } else {
+                push_element(xml, 2, "my_times_read", entry.repeat);
+                push_element(xml, 2, "my_rereading", is_repeating);
+            }
+            push_element(xml, 2, "update_on_import", 1);
+            xml.push_str(if is_anime {
+                "\t</anime>\n"
+            } else {
+                "\t</manga>\n"
+            });
+        }
+
+        export.xml.push_str("</myanimelist>\n");
+
+        Ok(export)
+    }
+}
+
+/// Writes an element holding a value on its own line.
+///
+/// # Arguments
+///
+/// * `xml` - The document to write to.
+/// * `depth` - The number of tabs before the element.
+/// * `name` - The name of the element.
+/// * `value` - The value of the element, already escaped.
+fn push_element(xml: &mut String, depth: usize, name: &str, value: impl std::fmt::Display) {
+    let indent = "\t".repeat(depth);
+    // Writing to a string can't fail.
+    let _ = writeln!(xml, "{indent}<{name}>{value}</{name}>");
+}
+
+/// Wraps a text in a CDATA section, splitting the `]]>` it holds.
+///
+/// # Arguments
+///
+/// * `text` - The text to wrap.
+fn cdata(text: &str) -> String {
+    format!("<![CDATA[{}]]>", text.replace("]]>", "]]]]><![CDATA[>"))
+}
+
+/// Returns the name MyAnimeList gives a status.
+///
+/// MyAnimeList has no status for rewatches, they are listed as being
+/// watched with the `my_rewatching` flag set.
+///
+/// # Arguments
+///
+/// * `status` - The status of the entry.
+/// * `is_anime` - Whether the entry is in an anime list.
+fn mal_status(status: MediaListStatus, is_anime: bool) -> &'static str {
+    match (status, is_anime) {
+        (MediaListStatus::Current | MediaListStatus::Repeating, true) => "Watching",
+        (MediaListStatus::Current | MediaListStatus::Repeating, false) => "Reading",
+        (MediaListStatus::Completed, _) => "Completed",
+        (MediaListStatus::Paused, _) => "On-Hold",
+        (MediaListStatus::Dropped, _) => "Dropped",
+        (MediaListStatus::Planning | MediaListStatus::Unknown, true) => "Plan to Watch",
+        (MediaListStatus::Planning | MediaListStatus::Unknown, false) => "Plan to Read",
+    }
+}
+
+/// Formats a date the way MyAnimeList does, unknown components being
+/// zeros, e.g. `2016-04-00`.
+///
+/// # Arguments
+///
+/// * `date` - The date, if any.
+fn mal_date(date: &Option<Date>) -> String {
+    let date = date.clone().unwrap_or_default();
+
+    format!(
+        "{:04}-{:02}-{:02}",
+        date.year.unwrap_or(0),
+        date.month.unwrap_or(0),
+        date.day.unwrap_or(0)
+    )
+}
+
+/// Quotes a CSV field if it holds a comma, a quote or a line break.
+///
+/// # Arguments
+///
+/// * `field` - The field to escape.
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{MediaListMedia, MediaListStatus, ScoreFormat};
+
+    fn collection() -> MediaListCollection {
+        let title = serde_json::from_value(serde_json::json!({
+            "romaji": "Cowboy Bebop",
+            "english": "Cowboy Bebop",
+            "native": "カウボーイビバップ"
+        }))
+        .unwrap();
+
+        MediaListCollection {
+            score_format: ScoreFormat::Point10,
+            entries: vec![
+                MediaListEntry {
+                    media_id: 1,
+                    status: Some(MediaListStatus::Completed),
+                    score: 90.0,
+                    progress: 26,
+                    repeat: 1,
+                    notes: Some("Bang.\nSee you, \"space cowboy\", later".to_string()),
+                    started_at: Some(Date::new(Some(2016), Some(4), Some(3))),
+                    completed_at: Some(Date::new(Some(2016), Some(5), None)),
+                    media: Some(MediaListMedia {
+                        id: 1,
+                        id_mal: Some(1),
+                        title,
+                    }),
+                    ..Default::default()
+                },
+                MediaListEntry {
+                    media_id: 20,
+                    status: Some(MediaListStatus::Planning),
+                    ..Default::default()
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let mut csv = Vec::new();
+        collection().to_csv(&mut csv).unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "media_id,mal_id,title_romaji,title_english,status,score,progress,started_at,completed_at,repeat,notes\n\
+             1,1,Cowboy Bebop,Cowboy Bebop,COMPLETED,90,26,2016-04-03,2016-05,1,\"Bang.\nSee you, \"\"space cowboy\"\", later\"\n\
+             20,,,,PLANNING,,0,,,0,\n"
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let mut json = Vec::new();
+        collection().to_json(&mut json).unwrap();
+        let rows: serde_json::Value = serde_json::from_slice(&json).unwrap();
+
+        assert_eq!(rows[0].as_object().unwrap().keys().collect::<Vec<_>>(), {
+            let mut columns = COLUMNS.to_vec();
+            columns.sort();
+            columns
+        });
+        assert_eq!(rows[0]["started_at"], "2016-04-03");
+        assert_eq!(rows[0]["score"], 90.0);
+        assert_eq!(rows[1]["score"], serde_json::Value::Null);
+        assert_eq!(rows[1]["status"], "PLANNING");
+    }
+
+    #[test]
+    fn test_to_mal_xml() {
+        let export = collection().to_mal_xml(MediaType::Anime).unwrap();
+
+        assert_eq!(export.skipped, [20]);
+        assert_eq!(
+            export.xml,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" ?>\n\
+             <myanimelist>\n\
+             \t<myinfo>\n\
+             \t\t<user_export_type>1</user_export_type>\n\
+             \t</myinfo>\n\
+             \t<anime>\n\
+             \t\t<series_animedb_id>1</series_animedb_id>\n\
+             \t\t<series_title><![CDATA[Cowboy Bebop]]></series_title>\n\
+             \t\t<my_watched_episodes>26</my_watched_episodes>\n\
+             \t\t<my_start_date>2016-04-03</my_start_date>\n\
+             \t\t<my_finish_date>2016-05-00</my_finish_date>\n\
+             \t\t<my_score>9</my_score>\n\
+             \t\t<my_status>Completed</my_status>\n\
+             \t\t<my_comments><![CDATA[Bang.\nSee you, \"space cowboy\", later]]></my_comments>\n\
+             \t\t<my_times_watched>1</my_times_watched>\n\
+             \t\t<my_rewatching>0</my_rewatching>\n\
+             \t\t<update_on_import>1</update_on_import>\n\
+             \t</anime>\n\
+             </myanimelist>\n"
+        );
+    }
+
+    #[test]
+    fn test_to_mal_xml_manga() {
+        let export = collection().to_mal_xml(MediaType::Manga).unwrap();
+
+        assert!(export
+            .xml
+            .contains("<user_export_type>2</user_export_type>"));
+        assert!(export
+            .xml
+            .contains("<manga_mangadb_id>1</manga_mangadb_id>"));
+        assert!(export
+            .xml
+            .contains("<my_read_chapters>26</my_read_chapters>"));
+        assert!(matches!(
+            collection().to_mal_xml(MediaType::User),
+            Err(Error::InvalidMediaType)
+        ));
+    }
+
+    #[test]
+    fn test_mal_status() {
+        assert_eq!(mal_status(MediaListStatus::Repeating, true), "Watching");
+        assert_eq!(mal_status(MediaListStatus::Planning, false), "Plan to Read");
+        assert_eq!(mal_status(MediaListStatus::Paused, true), "On-Hold");
+    }
+
+    #[test]
+    fn test_cdata() {
+        assert_eq!(cdata("a ]]> b"), "<![CDATA[a ]]]]><![CDATA[> b]]>");
+    }
+
+    #[test]
+    fn test_escape_csv() {
+        assert_eq!(escape_csv("plain"), "plain");
+        assert_eq!(escape_csv("a,b"), "\"a,b\"");
+        assert_eq!(escape_csv("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_csv("line\r\nbreak"), "\"line\r\nbreak\"");
+    }
+}
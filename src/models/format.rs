@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 /// such as TV shows, movies, specials, OVAs, ONAs, music, manga, novels,
 /// and one-shots.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "SCREAMING_SNAKE_CASE"))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Format {
     /// Represents a TV show.
     #[default]
@@ -34,6 +34,9 @@ pub enum Format {
     Novel,
     /// Represents a one-shot.
     OneShot,
+    /// A value unknown to this version of the crate, as sent by AniList.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl Format {
@@ -50,6 +53,7 @@ impl Format {
             Format::Manga => "Manga",
             Format::Novel => "Novel",
             Format::OneShot => "One-Shot",
+            Format::Unknown(format) => format,
         }
     }
 
@@ -66,8 +70,72 @@ impl Format {
             Format::Manga => "Professionally published manga with more than one chapter",
             Format::Novel => "Written books released as a series of light novels",
             Format::OneShot => "Manga with just one chapter",
+            Format::Unknown(_) => "Unknown",
         }
     }
+
+    /// Returns the display name of the format, as written by `Display`.
+    fn display_name(&self) -> &str {
+        match self {
+            Format::Tv => "TV Show",
+            Format::TvShort => "TV Short",
+            Format::Movie => "Movie",
+            Format::Special => "Special",
+            Format::Ova => "OVA",
+            Format::Ona => "ONA",
+            Format::Music => "Music Video",
+            Format::Manga => "Manga",
+            Format::Novel => "Light Novel",
+            Format::OneShot => "One-Shot",
+            Format::Unknown(format) => format,
+        }
+    }
+
+    /// Returns a short abbreviation of the format, for compact badges.
+    ///
+    /// An unknown format is returned as sent by AniList.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Format;
+    /// assert_eq!(Format::Movie.abbreviation(), "MOV");
+    /// assert_eq!(Format::Ova.abbreviation(), "OVA");
+    /// ```
+    pub fn abbreviation(&self) -> &str {
+        match self {
+            Format::Tv => "TV",
+            Format::TvShort => "TVS",
+            Format::Movie => "MOV",
+            Format::Special => "SP",
+            Format::Ova => "OVA",
+            Format::Ona => "ONA",
+            Format::Music => "MV",
+            Format::Manga => "MGA",
+            Format::Novel => "LN",
+            Format::OneShot => "OS",
+            Format::Unknown(format) => format,
+        }
+    }
+
+    /// Returns whether the format is the one of an anime.
+    pub fn is_anime(&self) -> bool {
+        matches!(
+            self,
+            Format::Tv
+                | Format::TvShort
+                | Format::Movie
+                | Format::Special
+                | Format::Ova
+                | Format::Ona
+                | Format::Music
+        )
+    }
+
+    /// Returns whether the format is the one of a manga.
+    pub fn is_manga(&self) -> bool {
+        matches!(self, Format::Manga | Format::Novel | Format::OneShot)
+    }
 }
 
 impl From<&str> for Format {
@@ -83,7 +151,7 @@ impl From<&str> for Format {
             "MANGA" => Format::Manga,
             "NOVEL" => Format::Novel,
             "ONE_SHOT" => Format::OneShot,
-            _ => Format::default(),
+            _ => Format::Unknown(value.to_string()),
         }
     }
 }
@@ -94,9 +162,34 @@ impl From<String> for Format {
     }
 }
 
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    /// Parses a format from its GraphQL name, such as `TV_SHORT`, or its
+    /// display name, such as `TV Short`, ignoring case.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let format = match s.trim().to_uppercase().as_str() {
+            "TV" | "TV SHOW" => Format::Tv,
+            "TV_SHORT" | "TV SHORT" => Format::TvShort,
+            "MOVIE" => Format::Movie,
+            "SPECIAL" => Format::Special,
+            "OVA" => Format::Ova,
+            "ONA" => Format::Ona,
+            "MUSIC" | "MUSIC VIDEO" => Format::Music,
+            "MANGA" => Format::Manga,
+            "NOVEL" | "LIGHT NOVEL" => Format::Novel,
+            "ONE_SHOT" | "ONE-SHOT" => Format::OneShot,
+            _ => return Err(format!("unknown format: `{s}`")),
+        };
+
+        Ok(format)
+    }
+}
+
 impl std::fmt::Display for Format {
+    /// Writes the display name of the format, e.g. `TV Show`.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.name())
+        write!(f, "{}", self.display_name())
     }
 }
 
@@ -104,6 +197,102 @@ impl std::fmt::Display for Format {
 mod tests {
     use super::*;
 
+    static ALL: [Format; 10] = [
+        Format::Tv,
+        Format::TvShort,
+        Format::Movie,
+        Format::Special,
+        Format::Ova,
+        Format::Ona,
+        Format::Music,
+        Format::Manga,
+        Format::Novel,
+        Format::OneShot,
+    ];
+
+    /// Fails to compile when a variant is added, so it is added to `ALL`.
+    fn position(format: &Format) -> usize {
+        match format {
+            Format::Tv => 0,
+            Format::TvShort => 1,
+            Format::Movie => 2,
+            Format::Special => 3,
+            Format::Ova => 4,
+            Format::Ona => 5,
+            Format::Music => 6,
+            Format::Manga => 7,
+            Format::Novel => 8,
+            Format::OneShot => 9,
+            Format::Unknown(_) => ALL.len(),
+        }
+    }
+
+    #[test]
+    fn test_all_variants() {
+        for (i, format) in ALL.iter().enumerate() {
+            assert_eq!(position(format), i);
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let names: Vec<String> = ALL.iter().map(Format::to_string).collect();
+
+        assert_eq!(
+            names,
+            [
+                "TV Show",
+                "TV Short",
+                "Movie",
+                "Special",
+                "OVA",
+                "ONA",
+                "Music Video",
+                "Manga",
+                "Light Novel",
+                "One-Shot",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_abbreviation() {
+        let abbreviations: Vec<&str> = ALL.iter().map(Format::abbreviation).collect();
+
+        assert_eq!(
+            abbreviations,
+            ["TV", "TVS", "MOV", "SP", "OVA", "ONA", "MV", "MGA", "LN", "OS"]
+        );
+    }
+
+    #[test]
+    fn test_is_anime_and_is_manga() {
+        let anime: Vec<&Format> = ALL.iter().filter(|format| format.is_anime()).collect();
+        let manga: Vec<&Format> = ALL.iter().filter(|format| format.is_manga()).collect();
+
+        assert_eq!(anime.len(), 7);
+        assert_eq!(manga, [&Format::Manga, &Format::Novel, &Format::OneShot]);
+    }
+
+    #[test]
+    fn test_parse() {
+        for format in &ALL {
+            let graphql = serde_json::to_value(format).unwrap();
+
+            assert_eq!(
+                graphql.as_str().unwrap().parse::<Format>(),
+                Ok(format.clone())
+            );
+            assert_eq!(format.to_string().parse::<Format>(), Ok(format.clone()));
+            assert_eq!(
+                format.to_string().to_lowercase().parse(),
+                Ok(format.clone())
+            );
+        }
+
+        assert!("Anime".parse::<Format>().is_err());
+    }
+
     #[test]
     fn test_format_name() {
         assert_eq!(Format::Tv.name(), "TV");
@@ -130,7 +319,10 @@ mod tests {
         assert_eq!(Format::from("MANGA"), Format::Manga);
         assert_eq!(Format::from("novel"), Format::Novel);
         assert_eq!(Format::from("ONE_SHOT"), Format::OneShot);
-        assert_eq!(Format::from("unknown"), Format::Tv); // Default case
+        assert_eq!(
+            Format::from("unknown"),
+            Format::Unknown("unknown".to_string())
+        );
     }
 
     #[test]
@@ -145,6 +337,9 @@ mod tests {
         assert_eq!(Format::from("MANGA".to_string()), Format::Manga);
         assert_eq!(Format::from("novel".to_string()), Format::Novel);
         assert_eq!(Format::from("ONE_SHOT".to_string()), Format::OneShot);
-        assert_eq!(Format::from("unknown".to_string()), Format::Tv); // Default case
+        assert_eq!(
+            Format::from("unknown".to_string()),
+            Format::Unknown("unknown".to_string())
+        );
     }
 }
@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 /// The `Gender` enum defines various gender identities, including male,
 /// female, non-binary, and other custom genders.
 #[derive(Debug, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "PascalCase"))]
+#[serde(rename_all = "PascalCase")]
 pub enum Gender {
     /// Represents the male gender.
     Male,
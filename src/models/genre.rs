@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Genre` enum.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// Represents a genre of a media.
+///
+/// AniList has a fixed list of genres; a genre missing from it is kept
+/// as [`Genre::Other`] so new genres don't break deserialization.
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum Genre {
+    /// The action genre.
+    Action,
+    /// The adventure genre.
+    Adventure,
+    /// The comedy genre.
+    Comedy,
+    /// The drama genre.
+    Drama,
+    /// The ecchi genre.
+    Ecchi,
+    /// The fantasy genre.
+    Fantasy,
+    /// The hentai genre.
+    Hentai,
+    /// The horror genre.
+    Horror,
+    /// The mahou shoujo genre.
+    MahouShoujo,
+    /// The mecha genre.
+    Mecha,
+    /// The music genre.
+    Music,
+    /// The mystery genre.
+    Mystery,
+    /// The psychological genre.
+    Psychological,
+    /// The romance genre.
+    Romance,
+    /// The science fiction genre.
+    SciFi,
+    /// The slice of life genre.
+    SliceOfLife,
+    /// The sports genre.
+    Sports,
+    /// The supernatural genre.
+    Supernatural,
+    /// The thriller genre.
+    Thriller,
+    /// A genre missing from the list above.
+    Other(String),
+}
+
+/// The genres of AniList, in alphabetical order.
+static ALL: [Genre; 19] = [
+    Genre::Action,
+    Genre::Adventure,
+    Genre::Comedy,
+    Genre::Drama,
+    Genre::Ecchi,
+    Genre::Fantasy,
+    Genre::Hentai,
+    Genre::Horror,
+    Genre::MahouShoujo,
+    Genre::Mecha,
+    Genre::Music,
+    Genre::Mystery,
+    Genre::Psychological,
+    Genre::Romance,
+    Genre::SciFi,
+    Genre::SliceOfLife,
+    Genre::Sports,
+    Genre::Supernatural,
+    Genre::Thriller,
+];
+
+impl Genre {
+    /// Returns the genres of AniList, in alphabetical order.
+    ///
+    /// [`Genre::Other`] is not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Genre;
+    /// for genre in Genre::all() {
+    ///     println!("{genre}");
+    /// }
+    /// ```
+    pub fn all() -> &'static [Genre] {
+        &ALL
+    }
+
+    /// Returns the name of the genre, as written by AniList.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Genre;
+    /// assert_eq!(Genre::SciFi.name(), "Sci-Fi");
+    /// assert_eq!(Genre::Other(String::from("Kids")).name(), "Kids");
+    /// ```
+    pub fn name(&self) -> &str {
+        match self {
+            Genre::Action => "Action",
+            Genre::Adventure => "Adventure",
+            Genre::Comedy => "Comedy",
+            Genre::Drama => "Drama",
+            Genre::Ecchi => "Ecchi",
+            Genre::Fantasy => "Fantasy",
+            Genre::Hentai => "Hentai",
+            Genre::Horror => "Horror",
+            Genre::MahouShoujo => "Mahou Shoujo",
+            Genre::Mecha => "Mecha",
+            Genre::Music => "Music",
+            Genre::Mystery => "Mystery",
+            Genre::Psychological => "Psychological",
+            Genre::Romance => "Romance",
+            Genre::SciFi => "Sci-Fi",
+            Genre::SliceOfLife => "Slice of Life",
+            Genre::Sports => "Sports",
+            Genre::Supernatural => "Supernatural",
+            Genre::Thriller => "Thriller",
+            Genre::Other(genre) => genre,
+        }
+    }
+}
+
+impl From<&str> for Genre {
+    fn from(value: &str) -> Self {
+        ALL.iter()
+            .find(|genre| genre.name() == value)
+            .cloned()
+            .unwrap_or_else(|| Genre::Other(value.to_string()))
+    }
+}
+
+impl From<String> for Genre {
+    fn from(value: String) -> Self {
+        Genre::from(value.as_str())
+    }
+}
+
+impl From<Genre> for String {
+    fn from(value: Genre) -> Self {
+        match value {
+            Genre::Other(genre) => genre,
+            genre => genre.name().to_string(),
+        }
+    }
+}
+
+impl Display for Genre {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Genre::from("Action"), Genre::Action);
+        assert_eq!(Genre::from("Sci-Fi"), Genre::SciFi);
+        assert_eq!(Genre::from("Slice of Life"), Genre::SliceOfLife);
+        assert_eq!(Genre::from("Sci-fi"), Genre::Other(String::from("Sci-fi")));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for genre in Genre::all() {
+            let json = serde_json::to_value(genre).unwrap();
+
+            assert_eq!(json, genre.name());
+            assert_eq!(serde_json::from_value::<Genre>(json).unwrap(), *genre);
+        }
+
+        let other = Genre::Other(String::from("Kids"));
+        let json = serde_json::to_value(&other).unwrap();
+
+        assert_eq!(json, "Kids");
+        assert_eq!(serde_json::from_value::<Genre>(json).unwrap(), other);
+    }
+}
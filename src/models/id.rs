@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the ID types of the AniList entities.
+
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Defines an ID type wrapping an integer.
+macro_rules! id {
+    ($(#[$meta:meta])* $name:ident($int:ty)) => {
+        $(#[$meta])*
+        #[derive(
+            Debug, Default, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name($int);
+
+        impl $name {
+            /// Creates an ID from its integer value.
+            ///
+            /// # Arguments
+            ///
+            /// * `id` - The integer value of the ID.
+            pub const fn new(id: $int) -> Self {
+                Self(id)
+            }
+
+            /// Returns the integer value of the ID.
+            pub const fn get(self) -> $int {
+                self.0
+            }
+        }
+
+        impl From<$int> for $name {
+            fn from(id: $int) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<$name> for $int {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl PartialEq<$int> for $name {
+            fn eq(&self, other: &$int) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIntError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse().map(Self)
+            }
+        }
+    };
+}
+
+id! {
+    /// The ID of an [`Anime`](super::Anime).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::AnimeId;
+    /// let id: AnimeId = "1".parse().unwrap();
+    ///
+    /// assert_eq!(id, AnimeId::from(1));
+    /// assert_eq!(id.to_string(), "1");
+    /// ```
+    AnimeId(i64)
+}
+
+id! {
+    /// The ID of a [`Manga`](super::Manga).
+    MangaId(i64)
+}
+
+id! {
+    /// The ID of a [`Character`](super::Character).
+    CharacterId(i64)
+}
+
+id! {
+    /// The ID of a [`Person`](super::Person).
+    PersonId(i64)
+}
+
+id! {
+    /// The ID of a [`Studio`](super::Studio).
+    StudioId(i64)
+}
+
+id! {
+    /// The ID of a [`User`](super::User).
+    UserId(i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_transparent() {
+        let id: AnimeId = serde_json::from_value(serde_json::json!(20)).unwrap();
+
+        assert_eq!(id, 20);
+        assert_eq!(serde_json::to_value(id).unwrap(), serde_json::json!(20));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("42".parse::<UserId>(), Ok(UserId::new(42)));
+        assert!("forty-two".parse::<CharacterId>().is_err());
+    }
+
+    #[test]
+    fn test_conversions() {
+        let id = StudioId::from(7);
+
+        assert_eq!(id.get(), 7);
+        assert_eq!(i64::from(id), 7);
+        assert_eq!(id.to_string(), "7");
+    }
+}
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 
 /// Represents an image with different sizes.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "lowercase"))]
+#[serde(rename_all = "lowercase")]
 pub struct Image {
     /// URL of the large version of the image.
     pub large: String,
@@ -24,6 +24,58 @@ impl Image {
             &self.large
         }
     }
+
+    /// Returns the URL of the largest available version of the image.
+    ///
+    /// Unlike [`Image::largest`], this returns `None` when both URLs
+    /// are empty, mirroring [`Cover::largest`](super::Cover::largest).
+    pub fn best(&self) -> Option<&str> {
+        self.large().or_else(|| self.medium())
+    }
+
+    /// Returns the URL of the large version of the image, if any.
+    pub fn large(&self) -> Option<&str> {
+        Some(self.large.as_str()).filter(|url| !url.is_empty())
+    }
+
+    /// Returns the URL of the medium version of the image, if any.
+    pub fn medium(&self) -> Option<&str> {
+        Some(self.medium.as_str()).filter(|url| !url.is_empty())
+    }
+
+    /// Returns whether the image is AniList's default placeholder.
+    ///
+    /// AniList serves a gray silhouette for entries without art. The
+    /// image is considered a placeholder if it has at least one URL and
+    /// all of its URLs point to the placeholder.
+    pub fn is_default(&self) -> bool {
+        let urls = [self.large(), self.medium()];
+
+        urls.iter().any(Option::is_some) && urls.iter().flatten().all(|url| is_default_url(url))
+    }
+}
+
+/// Returns whether the URL points to one of AniList's placeholder images.
+///
+/// Only the file name is checked (`default.jpg`, `default_*.png`, ...),
+/// so the detection does not depend on the CDN host.
+///
+/// # Arguments
+///
+/// * `url` - The URL of the image.
+pub(crate) fn is_default_url(url: &str) -> bool {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    let file_name = path.rsplit('/').next().unwrap_or_default();
+
+    match file_name.rsplit_once('.') {
+        Some((stem, extension)) => {
+            matches!(
+                extension.to_lowercase().as_str(),
+                "jpg" | "jpeg" | "png" | "gif" | "webp"
+            ) && (stem == "default" || stem.starts_with("default_"))
+        }
+        None => false,
+    }
 }
 
 #[cfg(test)]
@@ -59,4 +111,83 @@ mod tests {
 
         assert_eq!(image.largest(), "");
     }
+
+    #[test]
+    fn test_best_with_large() {
+        let image = Image {
+            large: "https://example.com/large.jpg".to_string(),
+            medium: "https://example.com/medium.jpg".to_string(),
+        };
+
+        assert_eq!(image.best(), Some("https://example.com/large.jpg"));
+    }
+
+    #[test]
+    fn test_best_with_medium() {
+        let image = Image {
+            large: "".to_string(),
+            medium: "https://example.com/medium.jpg".to_string(),
+        };
+
+        assert_eq!(image.best(), Some("https://example.com/medium.jpg"));
+    }
+
+    #[test]
+    fn test_best_with_none() {
+        let image = Image {
+            large: "".to_string(),
+            medium: "".to_string(),
+        };
+
+        assert_eq!(image.best(), None);
+        assert_eq!(image.large(), None);
+        assert_eq!(image.medium(), None);
+    }
+
+    #[test]
+    fn test_is_default() {
+        let image = Image {
+            large: "https://s4.anilist.co/file/anilistcdn/character/large/default.jpg".to_string(),
+            medium: "https://s4.anilist.co/file/anilistcdn/character/medium/default.jpg"
+                .to_string(),
+        };
+
+        assert!(image.is_default());
+    }
+
+    #[test]
+    fn test_is_default_with_other_host() {
+        let image = Image {
+            large: "https://img.anili.st/user/avatar/large/default_3.png?v=1".to_string(),
+            medium: "".to_string(),
+        };
+
+        assert!(image.is_default());
+    }
+
+    #[test]
+    fn test_is_default_with_real_image() {
+        let image = Image {
+            large: "https://s4.anilist.co/file/anilistcdn/character/large/b1-ChxaldmieFlQ.png"
+                .to_string(),
+            medium: "https://s4.anilist.co/file/anilistcdn/character/medium/default.jpg"
+                .to_string(),
+        };
+
+        assert!(!image.is_default());
+    }
+
+    #[test]
+    fn test_is_default_with_empty() {
+        assert!(!Image::default().is_default());
+    }
+
+    #[test]
+    fn test_is_default_url() {
+        assert!(is_default_url("https://example.com/default.jpg"));
+        assert!(is_default_url("https://example.com/a/default_2.PNG"));
+        assert!(!is_default_url("https://example.com/defaults.jpg"));
+        assert!(!is_default_url("https://example.com/default/cover.jpg"));
+        assert!(!is_default_url("https://example.com/default"));
+    }
 }
@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 /// The `Language` enum defines a list of supported languages, each with
 /// an associated variant. The default language is Japanese.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "PascalCase"))]
+#[serde(rename_all = "PascalCase")]
 pub enum Language {
     /// The Japanese language.
     #[default]
@@ -67,10 +67,14 @@ pub enum Language {
     Urdu,
     /// The Polish language.
     Polish,
+    /// A value unknown to this version of the crate, as sent by AniList.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl Language {
-    /// Returns the ISO 639-1 code of the language.
+    /// Returns the ISO 639-1 code of the language, or `und` if the
+    /// language is unknown.
     pub fn code(&self) -> &str {
         match self {
             Language::Japanese => "ja",
@@ -100,6 +104,7 @@ impl Language {
             Language::Hindi => "hi",
             Language::Urdu => "ur",
             Language::Polish => "pl",
+            Language::Unknown(_) => "und",
         }
     }
 
@@ -140,6 +145,7 @@ impl Language {
             Language::Hindi => "हिंदी",
             Language::Urdu => "اردو",
             Language::Polish => "Polski",
+            Language::Unknown(language) => language,
         }
     }
 }
@@ -174,7 +180,7 @@ impl From<&str> for Language {
             "HI" | "HINDI" => Language::Hindi,
             "UR" | "URDU" => Language::Urdu,
             "PL" | "POLISH" => Language::Polish,
-            _ => Language::default(),
+            _ => Language::Unknown(value.to_string()),
         }
     }
 }
@@ -215,6 +221,7 @@ impl std::fmt::Display for Language {
             Language::Hindi => write!(f, "Hindi"),
             Language::Urdu => write!(f, "Urdu"),
             Language::Polish => write!(f, "Polish"),
+            Language::Unknown(language) => write!(f, "{language}"),
         }
     }
 }
@@ -345,7 +352,10 @@ mod tests {
         assert_eq!(Language::from("hi"), Language::Hindi);
         assert_eq!(Language::from("ur"), Language::Urdu);
         assert_eq!(Language::from("pl"), Language::Polish);
-        assert_eq!(Language::from("unknown"), Language::Japanese); // Default case
+        assert_eq!(
+            Language::from("unknown"),
+            Language::Unknown("unknown".to_string())
+        );
     }
 
     #[test]
@@ -377,6 +387,9 @@ mod tests {
         assert_eq!(Language::from("hi".to_string()), Language::Hindi);
         assert_eq!(Language::from("ur".to_string()), Language::Urdu);
         assert_eq!(Language::from("pl".to_string()), Language::Polish);
-        assert_eq!(Language::from("unknown".to_string()), Language::Japanese); // Default case
+        assert_eq!(
+            Language::from("unknown".to_string()),
+            Language::Unknown("unknown".to_string())
+        );
     }
 }
@@ -9,7 +9,7 @@ use super::{Color, Language};
 
 /// Represents a link.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Link {
     /// The ID of the link.
     pub id: Option<i64>,
@@ -39,7 +39,7 @@ pub struct Link {
 
 /// Represents the type of link.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "UPPERCASE"))]
+#[serde(rename_all = "UPPERCASE")]
 pub enum LinkType {
     /// The info link type.
     #[default]
@@ -48,6 +48,9 @@ pub enum LinkType {
     Streaming,
     /// The social link type.
     Social,
+    /// A value unknown to this version of the crate, as sent by AniList.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LinkType {
@@ -56,6 +59,7 @@ impl std::fmt::Display for LinkType {
             LinkType::Info => write!(f, "Info"),
             LinkType::Streaming => write!(f, "Streaming"),
             LinkType::Social => write!(f, "Social"),
+            LinkType::Unknown(link_type) => write!(f, "{link_type}"),
         }
     }
 }
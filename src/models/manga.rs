@@ -6,8 +6,15 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::connections::{
+    deserialize_characters, deserialize_staff, deserialize_studios, parse_relations,
+};
+use super::debug::{ClientPlaceholder, ConnectionSummary, Truncated};
+use super::media::{display_title, split_hashtags, write_details, Count};
+use super::title::title_similarity;
 use super::{
-    Character, Cover, Date, Format, Link, Person, Relation, Source, Status, Studio, Tag, Title,
+    Character, CharacterRole, Cover, Date, Format, Genre, Link, MangaId, MediaStats, Person,
+    Ranking, RankingType, Relation, Source, Status, Studio, Tag, Title,
 };
 use crate::{Client, Result};
 
@@ -18,11 +25,11 @@ use crate::{Client, Result};
 /// chapters, volumes, country of origin, licensing status, source,
 /// hashtags, images, genres, synonyms, scores, popularity, tags,
 /// relations, characters, staff, studios, and other metadata.
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[derive(Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Manga {
     /// The ID of the manga.
-    pub id: i64,
+    pub id: MangaId,
     /// The ID of the manga on MAL.
     pub id_mal: Option<i64>,
     /// The title of the manga.
@@ -58,7 +65,7 @@ pub struct Manga {
     #[serde(rename = "bannerImage")]
     pub banner: Option<String>,
     /// The genres of the manga.
-    pub genres: Option<Vec<String>>,
+    pub genres: Option<Vec<Genre>>,
     /// The synonyms of the manga.
     pub synonyms: Option<Vec<String>>,
     /// The average score of the manga.
@@ -73,17 +80,27 @@ pub struct Manga {
     pub trending: Option<u32>,
     /// The number of favourites of the manga.
     pub favourites: Option<u32>,
+    /// The rankings of the manga.
+    pub rankings: Option<Vec<Ranking>>,
+    /// The score and list status distributions of the manga.
+    pub stats: Option<MediaStats>,
     /// The tags of the manga.
     pub tags: Option<Vec<Tag>>,
     /// The relations of the manga.
+    #[serde(default)]
     pub(crate) relations: Value,
     /// The characters of the manga.
-    pub(crate) characters: Value,
+    #[serde(
+        rename = "characters",
+        default,
+        deserialize_with = "deserialize_characters"
+    )]
+    pub characters: Option<Vec<Character>>,
     /// The staff of the manga.
-    #[serde(skip)]
+    #[serde(rename = "staff", default, deserialize_with = "deserialize_staff")]
     pub staff: Option<Vec<Person>>,
     /// The studios of the manga.
-    #[serde(skip)]
+    #[serde(rename = "studios", default, deserialize_with = "deserialize_studios")]
     pub studios: Option<Vec<Studio>>,
     /// Whether the manga is favourite or not.
     pub is_favourite: Option<bool>,
@@ -105,6 +122,189 @@ pub struct Manga {
     pub(crate) is_full_loaded: bool,
 }
 
+impl PartialEq for Manga {
+    /// Compares every field of the mangas but their client and whether they are
+    /// fully loaded.
+    fn eq(&self, other: &Self) -> bool {
+        let Manga {
+            id,
+            id_mal,
+            title,
+            format,
+            status,
+            description,
+            start_date,
+            end_date,
+            chapters,
+            volumes,
+            country_of_origin,
+            is_licensed,
+            source,
+            hashtag,
+            updated_at,
+            cover,
+            banner,
+            genres,
+            synonyms,
+            average_score,
+            mean_score,
+            popularity,
+            is_locked,
+            trending,
+            favourites,
+            rankings,
+            stats,
+            tags,
+            relations,
+            characters,
+            staff,
+            studios,
+            is_favourite,
+            is_favourite_blocked,
+            is_adult,
+            external_links,
+            url,
+            client: _,
+            is_full_loaded: _,
+        } = self;
+
+        *id == other.id
+            && *id_mal == other.id_mal
+            && *title == other.title
+            && *format == other.format
+            && *status == other.status
+            && *description == other.description
+            && *start_date == other.start_date
+            && *end_date == other.end_date
+            && *chapters == other.chapters
+            && *volumes == other.volumes
+            && *country_of_origin == other.country_of_origin
+            && *is_licensed == other.is_licensed
+            && *source == other.source
+            && *hashtag == other.hashtag
+            && *updated_at == other.updated_at
+            && *cover == other.cover
+            && *banner == other.banner
+            && *genres == other.genres
+            && *synonyms == other.synonyms
+            && *average_score == other.average_score
+            && *mean_score == other.mean_score
+            && *popularity == other.popularity
+            && *is_locked == other.is_locked
+            && *trending == other.trending
+            && *favourites == other.favourites
+            && *rankings == other.rankings
+            && *stats == other.stats
+            && *tags == other.tags
+            && *relations == other.relations
+            && *characters == other.characters
+            && *staff == other.staff
+            && *studios == other.studios
+            && *is_favourite == other.is_favourite
+            && *is_favourite_blocked == other.is_favourite_blocked
+            && *is_adult == other.is_adult
+            && *external_links == other.external_links
+            && *url == other.url
+    }
+}
+
+/// Two equal mangas always have the same ID, so mangas are hashed by ID.
+impl Eq for Manga {}
+
+impl std::hash::Hash for Manga {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl std::fmt::Debug for Manga {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Manga {
+            id,
+            id_mal,
+            title,
+            format,
+            status,
+            description,
+            start_date,
+            end_date,
+            chapters,
+            volumes,
+            country_of_origin,
+            is_licensed,
+            source,
+            hashtag,
+            updated_at,
+            cover,
+            banner,
+            genres,
+            synonyms,
+            average_score,
+            mean_score,
+            popularity,
+            is_locked,
+            trending,
+            favourites,
+            rankings,
+            stats,
+            tags,
+            relations,
+            characters,
+            staff,
+            studios,
+            is_favourite,
+            is_favourite_blocked,
+            is_adult,
+            external_links,
+            url,
+            client: _,
+            is_full_loaded,
+        } = self;
+
+        f.debug_struct("Manga")
+            .field("id", id)
+            .field("id_mal", id_mal)
+            .field("title", title)
+            .field("format", format)
+            .field("status", status)
+            .field("description", &Truncated(description))
+            .field("start_date", start_date)
+            .field("end_date", end_date)
+            .field("chapters", chapters)
+            .field("volumes", volumes)
+            .field("country_of_origin", country_of_origin)
+            .field("is_licensed", is_licensed)
+            .field("source", source)
+            .field("hashtag", hashtag)
+            .field("updated_at", updated_at)
+            .field("cover", cover)
+            .field("banner", banner)
+            .field("genres", genres)
+            .field("synonyms", synonyms)
+            .field("average_score", average_score)
+            .field("mean_score", mean_score)
+            .field("popularity", popularity)
+            .field("is_locked", is_locked)
+            .field("trending", trending)
+            .field("favourites", favourites)
+            .field("rankings", rankings)
+            .field("stats", stats)
+            .field("tags", tags)
+            .field("relations", &ConnectionSummary(relations, "relation"))
+            .field("characters", characters)
+            .field("staff", staff)
+            .field("studios", studios)
+            .field("is_favourite", is_favourite)
+            .field("is_favourite_blocked", is_favourite_blocked)
+            .field("is_adult", is_adult)
+            .field("external_links", external_links)
+            .field("url", url)
+            .field("client", &ClientPlaceholder)
+            .field("is_full_loaded", is_full_loaded)
+            .finish()
+    }
+}
+
 impl Manga {
     /// Loads the full details of the manga.
     ///
@@ -126,6 +326,7 @@ impl Manga {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "client")]
     pub async fn load_full(self) -> Result<Self> {
         if !self.is_full_loaded {
             self.client.get_manga(self.id).await
@@ -134,47 +335,287 @@ impl Manga {
         }
     }
 
-    /// Returns the characters of the manga.
-    pub fn characters(&self) -> Result<Vec<Character>> {
-        let binding = Vec::new();
-        let edges = self
-            .characters
-            .as_object()
-            .and_then(|obj| obj.get("edges"))
-            .and_then(|edges| edges.as_array())
-            .unwrap_or(&binding);
-
-        let mut characters = Vec::with_capacity(edges.len());
-
-        for edge in edges {
-            let binding = serde_json::Map::new();
-            let obj = edge.as_object().unwrap_or(&binding);
-            let node = obj.get("node").unwrap_or(&Value::Null);
-            let role = obj.get("role").and_then(|role| role.as_str()).unwrap_or("");
-
-            let mut character: Character = serde_json::from_value(node.clone()).unwrap_or_default();
-            character.role = Some(role.into());
-            characters.push(character);
+    /// Detaches the manga from its client.
+    ///
+    /// A detached manga keeps its data, and the methods reading it,
+    /// such as [`Manga::relations`] and [`Manga::characters_by_role`], keep working. The methods sending
+    /// requests, such as [`Manga::load_full`], fail with
+    /// [`Error::Detached`](crate::Error::Detached) until a client is
+    /// attached with [`Manga::attach`]. The nested models are detached
+    /// as well.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Manga, Client, Result};
+    /// #
+    /// # async fn f(manga: Manga, client: Client) -> Result<()> {
+    /// let cached = manga.detach();
+    /// // ...
+    /// let manga = cached.attach(&client).load_full().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "client")]
+    pub fn detach(self) -> Self {
+        self.attach(&Client::detached())
+    }
+
+    /// Attaches the manga to a client, which sends its requests from now on.
+    ///
+    /// The nested models are attached as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to attach.
+    #[cfg(feature = "client")]
+    pub fn attach(mut self, client: &Client) -> Self {
+        self.set_client(client);
+        self
+    }
+
+    /// Gives the client to the manga and its nested models.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to give.
+    #[cfg(feature = "client")]
+    pub(crate) fn set_client(&mut self, client: &Client) {
+        self.client = client.clone();
+        for character in self.characters.iter_mut().flatten() {
+            character.set_client(client);
+        }
+        for person in self.staff.iter_mut().flatten() {
+            person.set_client(client);
         }
+        for studio in self.studios.iter_mut().flatten() {
+            studio.set_client(client);
+        }
+    }
 
-        Ok(characters)
+    /// Returns the characters of the manga.
+    #[deprecated(note = "use the `characters` field instead")]
+    pub fn characters(&self) -> Result<Vec<Character>> {
+        Ok(self.characters.clone().unwrap_or_default())
     }
 
     /// Returns the relations of the manga.
     pub fn relations(&self) -> Result<Vec<Relation>> {
-        let binding = Vec::new();
-        let edges = self
-            .relations
-            .as_object()
-            .and_then(|obj| obj.get("edges"))
-            .and_then(|edges| edges.as_array())
-            .unwrap_or(&binding);
-
-        let relations = edges
+        Ok(parse_relations(&self.relations, &self.client))
+    }
+
+    /// Returns the characters of the manga, most favourited first.
+    ///
+    /// See [`Anime::characters_by_favourites`](super::Anime::characters_by_favourites).
+    pub fn characters_by_favourites(&self) -> Vec<&Character> {
+        let mut characters: Vec<&Character> = self.characters.iter().flatten().collect();
+        characters.sort_by_key(|character| std::cmp::Reverse(character.favourites));
+
+        characters
+    }
+
+    /// Returns the hashtags of the manga, such as `#JujutsuKaisen`.
+    ///
+    /// AniList stores them in [`Manga::hashtag`], separated by
+    /// whitespace, which is ignored however repeated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Manga;
+    /// let mut manga = Manga::default();
+    /// manga.hashtag = Some("#呪術廻戦  #JujutsuKaisen ".to_string());
+    ///
+    /// assert_eq!(manga.hashtags(), ["#呪術廻戦", "#JujutsuKaisen"]);
+    /// ```
+    pub fn hashtags(&self) -> Vec<&str> {
+        split_hashtags(self.hashtag.as_deref())
+    }
+
+    /// Returns whether a title or a synonym of the manga is the given
+    /// one.
+    ///
+    /// The romaji, English and native titles and the synonyms are
+    /// compared case-insensitively, ignoring diacritics such as macrons
+    /// and punctuation such as colons, `&` being read as `and`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The title to compare.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Manga;
+    /// let mut manga = Manga::default();
+    /// manga.title = serde_json::from_value(serde_json::json!({
+    ///     "romaji": "Shingeki no Kyojin",
+    ///     "native": "進撃の巨人",
+    /// }))
+    /// .unwrap();
+    /// manga.synonyms = Some(vec!["AoT".to_string()]);
+    ///
+    /// assert!(manga.matches_title("Shingeki no Kyōjin"));
+    /// assert!(manga.matches_title("AOT"));
+    /// ```
+    pub fn matches_title(&self, query: &str) -> bool {
+        self.title_similarity(query) == 1.0
+    }
+
+    /// Returns how closely a title or a synonym of the manga matches a
+    /// query, from `0.0` to `1.0`, to rank search candidates.
+    ///
+    /// The texts are normalized as in [`Manga::matches_title`].
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query.
+    pub fn title_similarity(&self, query: &str) -> f32 {
+        title_similarity(
+            &self.title,
+            self.synonyms.as_deref().unwrap_or_default(),
+            query,
+        )
+    }
+
+    /// Returns the characters of the manga with the given role.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - The role of the characters.
+    pub fn characters_by_role(&self, role: CharacterRole) -> Vec<&Character> {
+        self.characters
+            .iter()
+            .flatten()
+            .filter(|character| character.role.as_ref() == Some(&role))
+            .collect()
+    }
+
+    /// Returns the all-time ranking of the manga of the given type.
+    ///
+    /// # Arguments
+    ///
+    /// * `ranking_type` - The type of the ranking.
+    pub fn all_time_rank(&self, ranking_type: RankingType) -> Option<&Ranking> {
+        self.rankings
             .iter()
-            .map(|r| serde_json::from_value(r.clone()).unwrap_or_default())
-            .collect();
+            .flatten()
+            .find(|ranking| ranking.ranking_type == ranking_type && ranking.is_all_time())
+    }
+
+    /// Returns the staff of the manga with the given role.
+    ///
+    /// See [`Anime::staff_by_role`](super::Anime::staff_by_role).
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - The role of the staff, e.g. `"Story & Art"`.
+    pub fn staff_by_role(&self, role: &str) -> Vec<&Person> {
+        self.staff
+            .iter()
+            .flatten()
+            .filter(|person| person.has_staff_role(role))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Manga {
+    /// Writes the title of the manga followed by its year, format and
+    /// numbers of volumes and chapters, e.g.
+    /// `Yotsuba&! (2003, Manga, 15 vols, 113 chs)`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let year = self.start_date.as_ref().and_then(|date| date.year);
+        let volumes = Count::of(self.volumes, "vol");
+        let chapters = Count::of(self.chapters, "ch");
+
+        write!(f, "{}", display_title(&self.title))?;
+        write_details(
+            f,
+            &[
+                year.as_ref().map(|year| year as _),
+                Some(&self.format.name()),
+                volumes.as_ref().map(|volumes| volumes as _),
+                chapters.as_ref().map(|chapters| chapters as _),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Media, RelationType};
+
+    fn manga() -> Manga {
+        let response: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/manga.json")).unwrap();
+
+        serde_json::from_value(response["data"]["Media"].clone()).unwrap()
+    }
+
+    #[test]
+    fn test_characters() {
+        let manga = manga();
+        let characters = manga.characters.as_ref().unwrap();
+
+        assert_eq!(characters.len(), 5);
+        assert_eq!(characters[0].role, Some(CharacterRole::Main));
+        assert_eq!(manga.characters_by_role(CharacterRole::Main).len(), 3);
+        assert_eq!(manga.characters_by_favourites()[0].id, 422);
+        assert_eq!(manga.characters_by_favourites()[4].id, 2861);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_characters_method() {
+        let manga = manga();
+
+        assert_eq!(manga.characters().unwrap(), manga.characters.unwrap());
+    }
+
+    #[test]
+    fn test_staff_by_role() {
+        let manga = manga();
+
+        assert_eq!(manga.staff_by_role("story & art")[0].id, 96972);
+        assert_eq!(manga.staff_by_role("Art")[0].id, 135209);
+        assert!(manga.staff_by_role("Director").is_empty());
+    }
+
+    #[test]
+    fn test_relations() {
+        let manga = manga();
+        let relations = manga.relations().unwrap();
+
+        assert_eq!(relations.len(), 8);
+        assert_eq!(
+            relations
+                .iter()
+                .filter(|relation| relation.relation_type == RelationType::Adaptation)
+                .count(),
+            6
+        );
+        assert!(matches!(relations[0].media(), Media::Anime(_)));
+        assert!(matches!(relations[7].media(), Media::Manga(_)));
+    }
+
+    #[test]
+    fn test_rankings_and_stats() {
+        let manga = manga();
+
+        assert_eq!(manga.all_time_rank(RankingType::Rated).unwrap().rank, 1);
+        assert_eq!(manga.all_time_rank(RankingType::Popular).unwrap().rank, 3);
+        assert_eq!(manga.stats.unwrap().scores()[2], (100, 52000));
+    }
+
+    #[test]
+    fn test_ongoing() {
+        let manga = manga();
 
-        Ok(relations)
+        assert_eq!(manga.status, Status::Releasing);
+        assert_eq!(manga.chapters, None);
+        assert!(manga.studios.unwrap().is_empty());
+        assert_eq!(manga.tags.unwrap().len(), 2);
+        assert_eq!(manga.external_links.unwrap().len(), 2);
     }
 }
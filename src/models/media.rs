@@ -3,11 +3,16 @@
 
 //! This module contains the `Media` enum.
 
+use std::fmt::Display;
+
 use serde::{Deserialize, Serialize};
 
-use super::{Anime, Format, Manga};
+use super::{Anime, Format, Manga, Title, TitleLanguage};
 
 /// Represents different types of media.
+// Anime and Manga are both large and passed by value everywhere else,
+// boxing one of them would only make matching on `Media` awkward.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
 pub enum Media {
     /// Represents an anime media type.
@@ -25,19 +30,24 @@ impl Media {
     /// Returns the id of the media.
     pub fn id(&self) -> i64 {
         match self {
-            Media::Anime(anime) => anime.id,
-            Media::Manga(manga) => manga.id,
+            Media::Anime(anime) => anime.id.get(),
+            Media::Manga(manga) => manga.id.get(),
             Media::Unknown => 0,
         }
     }
 
     /// Returns the title of the media.
+    ///
+    /// The title is in the language set on the client that fetched the
+    /// media, or in Romaji if none is set.
     pub fn title(&self) -> &str {
-        match self {
-            Media::Anime(anime) => anime.title.romaji(),
-            Media::Manga(manga) => manga.title.romaji(),
-            Media::Unknown => "Unknown",
-        }
+        let title = match self {
+            Media::Anime(anime) => &anime.title,
+            Media::Manga(manga) => &manga.title,
+            Media::Unknown => return "Unknown",
+        };
+
+        display_title(title)
     }
 
     /// Returns the format of the media.
@@ -61,3 +71,85 @@ impl From<Manga> for Media {
         Media::Manga(manga)
     }
 }
+
+impl Display for Media {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Media::Anime(anime) => anime.fmt(f),
+            Media::Manga(manga) => manga.fmt(f),
+            Media::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// Returns the title of a media in the language set on the client that
+/// fetched it, or in Romaji if none is set.
+///
+/// # Arguments
+///
+/// * `title` - The title of the media.
+pub(super) fn display_title(title: &Title) -> &str {
+    title.get(title.language.unwrap_or(TitleLanguage::Romaji))
+}
+
+/// Returns the hashtags of a media, which AniList stores in a single
+/// string separated by whitespace.
+///
+/// # Arguments
+///
+/// * `hashtag` - The hashtag field of the media.
+pub(super) fn split_hashtags(hashtag: Option<&str>) -> Vec<&str> {
+    hashtag.unwrap_or_default().split_whitespace().collect()
+}
+
+/// Writes the details of a media after its title, such as
+/// ` (1998, TV, 26 eps)`.
+///
+/// The missing details are skipped, and nothing is written if all of
+/// them are missing.
+///
+/// # Arguments
+///
+/// * `f` - The formatter to write to.
+/// * `details` - The details of the media.
+pub(super) fn write_details(
+    f: &mut std::fmt::Formatter<'_>,
+    details: &[Option<&dyn Display>],
+) -> std::fmt::Result {
+    let mut details = details.iter().flatten();
+
+    if let Some(first) = details.next() {
+        write!(f, " ({first}")?;
+        for detail in details {
+            write!(f, ", {detail}")?;
+        }
+        write!(f, ")")?;
+    }
+
+    Ok(())
+}
+
+/// A number of units of a media, such as `26 eps` or `1 vol`.
+pub(super) struct Count(pub(super) u16, pub(super) &'static str);
+
+impl Count {
+    /// Returns the count of a media, or `None` if it is unknown or zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of units, if known.
+    /// * `unit` - The abbreviated unit, in the singular.
+    pub(super) fn of(count: Option<u16>, unit: &'static str) -> Option<Self> {
+        count
+            .filter(|&count| count > 0)
+            .map(|count| Count(count, unit))
+    }
+}
+
+impl Display for Count {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let plural = if self.0 == 1 { "" } else { "s" };
+
+        write!(f, "{} {}{plural}", self.0, self.1)
+    }
+}
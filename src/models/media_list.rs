@@ -0,0 +1,612 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `MediaListEntry`, `MediaListCollection` and
+//! `ListComparison` structs and the `MediaListStatus` and `ScoreFormat`
+//! enums.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Date, Title, UserId};
+
+/// Represents a media in a user's list.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaListEntry {
+    /// The ID of the entry.
+    pub id: i64,
+    /// The ID of the user owning the list.
+    pub user_id: UserId,
+    /// The ID of the anime or manga.
+    pub media_id: i64,
+    /// The status of the media in the list.
+    pub status: Option<MediaListStatus>,
+    /// The score given by the user, from 0 to 100.
+    ///
+    /// A score of `0` means the media was not scored. Use
+    /// [`ScoreFormat::format`] to display it the way the user does.
+    #[serde(default)]
+    pub score: f32,
+    /// The number of episodes watched or chapters read.
+    #[serde(default)]
+    pub progress: u32,
+    /// The number of volumes read.
+    pub progress_volumes: Option<u32>,
+    /// The number of times the media was rewatched or reread.
+    #[serde(default)]
+    pub repeat: u32,
+    /// Whether the entry is hidden from other users.
+    #[serde(default)]
+    pub private: bool,
+    /// The notes of the user.
+    pub notes: Option<String>,
+    /// When the user started the media.
+    pub started_at: Option<Date>,
+    /// When the user completed the media.
+    pub completed_at: Option<Date>,
+    /// When the entry was last updated, as a Unix timestamp.
+    pub updated_at: Option<i64>,
+    /// The anime or manga, if selected by the query.
+    ///
+    /// It is only returned by [`Client::get_media_list_collection`].
+    ///
+    /// [`Client::get_media_list_collection`]: crate::Client::get_media_list_collection
+    #[serde(default)]
+    pub media: Option<MediaListMedia>,
+}
+
+/// Represents the anime or manga of a list entry.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaListMedia {
+    /// The ID of the media.
+    pub id: i64,
+    /// The ID of the media on MyAnimeList, if any.
+    pub id_mal: Option<i64>,
+    /// The title of the media.
+    pub title: Title,
+}
+
+/// Represents the whole anime or manga list of a user.
+///
+/// See [`Client::get_media_list_collection`]. It can be exported with
+/// [`MediaListCollection::to_csv`] and [`MediaListCollection::to_json`].
+///
+/// [`Client::get_media_list_collection`]: crate::Client::get_media_list_collection
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaListCollection {
+    /// The format the user scores media in.
+    ///
+    /// The scores of the entries are on the 100-point scale; use
+    /// [`ScoreFormat::format`] to display them the way the user does.
+    pub score_format: ScoreFormat,
+    /// The entries of the list, each one once even if it is in several
+    /// custom lists.
+    pub entries: Vec<MediaListEntry>,
+}
+
+impl MediaListEntry {
+    /// Returns the score of the entry on the 100-point scale, given it
+    /// was fetched in `format`.
+    ///
+    /// The entries returned by the client are already scored on the
+    /// 100-point scale; this is for entries deserialized from a query
+    /// selecting the `score` in the format of the user, e.g. sent with
+    /// [`Client::query_raw`](crate::Client::query_raw).
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The format the score was fetched in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::{MediaListEntry, ScoreFormat};
+    /// let entry = MediaListEntry { score: 4.0, ..Default::default() };
+    /// assert_eq!(entry.normalized_score(ScoreFormat::Point5), 80.0);
+    /// ```
+    pub fn normalized_score(&self, format: ScoreFormat) -> f32 {
+        format.normalize(self.score)
+    }
+}
+
+/// Represents the status of a media in a user's list.
+///
+/// It serializes to the names used by AniList, e.g. `CURRENT`, so it
+/// can be sent back as a variable.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MediaListStatus {
+    /// Currently watching or reading.
+    Current,
+    /// Planning to watch or read.
+    #[default]
+    Planning,
+    /// Finished watching or reading.
+    Completed,
+    /// Stopped watching or reading before finishing.
+    Dropped,
+    /// Paused watching or reading.
+    Paused,
+    /// Re-watching or re-reading.
+    Repeating,
+    /// A value unknown to this version of the crate.
+    ///
+    /// Unlike the `Unknown(String)` of the other enums, the value isn't
+    /// kept, so that the enum stays `Copy`.
+    #[serde(other)]
+    Unknown,
+}
+
+impl MediaListStatus {
+    /// Returns whether the media is being watched or read.
+    ///
+    /// This is the case for [`MediaListStatus::Current`] and
+    /// [`MediaListStatus::Repeating`].
+    pub fn is_active(&self) -> bool {
+        matches!(self, MediaListStatus::Current | MediaListStatus::Repeating)
+    }
+}
+
+impl From<&str> for MediaListStatus {
+    fn from(value: &str) -> Self {
+        match value.trim().to_uppercase().as_str() {
+            "CURRENT" => MediaListStatus::Current,
+            "PLANNING" => MediaListStatus::Planning,
+            "COMPLETED" => MediaListStatus::Completed,
+            "DROPPED" => MediaListStatus::Dropped,
+            "PAUSED" => MediaListStatus::Paused,
+            "REPEATING" => MediaListStatus::Repeating,
+            _ => MediaListStatus::Unknown,
+        }
+    }
+}
+
+impl From<String> for MediaListStatus {
+    fn from(value: String) -> Self {
+        MediaListStatus::from(value.as_str())
+    }
+}
+
+impl std::fmt::Display for MediaListStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MediaListStatus::Current => write!(f, "Current"),
+            MediaListStatus::Planning => write!(f, "Planning"),
+            MediaListStatus::Completed => write!(f, "Completed"),
+            MediaListStatus::Dropped => write!(f, "Dropped"),
+            MediaListStatus::Paused => write!(f, "Paused"),
+            MediaListStatus::Repeating => write!(f, "Repeating"),
+            MediaListStatus::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// Represents the format in which a user scores media.
+///
+/// Scores are stored by AniList on a 100-point scale and displayed in
+/// the format chosen by the user.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum ScoreFormat {
+    /// An integer from 0 to 100.
+    #[default]
+    #[serde(rename = "POINT_100")]
+    Point100,
+    /// A float from 0 to 10 with 1 decimal place.
+    #[serde(rename = "POINT_10_DECIMAL")]
+    Point10Decimal,
+    /// An integer from 0 to 10.
+    #[serde(rename = "POINT_10")]
+    Point10,
+    /// An integer from 0 to 5, shown as stars.
+    #[serde(rename = "POINT_5")]
+    Point5,
+    /// An integer from 0 to 3, shown as smileys.
+    #[serde(rename = "POINT_3")]
+    Point3,
+    /// A value unknown to this version of the crate.
+    ///
+    /// Unlike the `Unknown(String)` of the other enums, the value isn't
+    /// kept, so that the enum stays `Copy`.
+    #[serde(other)]
+    Unknown,
+}
+
+impl ScoreFormat {
+    /// Formats a score stored on the 100-point scale in this format.
+    ///
+    /// A score of `0` means the media was not scored and gives an empty
+    /// string.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw_score` - The score, from 0 to 100.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::ScoreFormat;
+    /// assert_eq!(ScoreFormat::Point10Decimal.format(85.0), "8.5");
+    /// assert_eq!(ScoreFormat::Point5.format(85.0), "★★★★");
+    /// assert_eq!(ScoreFormat::Point3.format(85.0), "🙂");
+    /// ```
+    pub fn format(&self, raw_score: f32) -> String {
+        let score = raw_score.clamp(0.0, 100.0);
+
+        if score == 0.0 {
+            return String::new();
+        }
+
+        match self {
+            ScoreFormat::Point100 | ScoreFormat::Unknown => format!("{}", score.round()),
+            ScoreFormat::Point10Decimal => format!("{:.1}", score / 10.0),
+            ScoreFormat::Point10 => format!("{}", (score / 10.0).round()),
+            ScoreFormat::Point5 => "★".repeat((score / 20.0).round().max(1.0) as usize),
+            ScoreFormat::Point3 if score >= 61.0 => "🙂".to_string(),
+            ScoreFormat::Point3 if score >= 36.0 => "😐".to_string(),
+            ScoreFormat::Point3 => "🙁".to_string(),
+        }
+    }
+
+    /// Converts a score given in this format to the 100-point scale.
+    ///
+    /// Smileys are converted the way AniList does: 35, 60 and 85 points.
+    /// A score of `0` means the media was not scored and stays `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `score` - The score, in this format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::ScoreFormat;
+    /// assert_eq!(ScoreFormat::Point10Decimal.normalize(8.5), 85.0);
+    /// assert_eq!(ScoreFormat::Point5.normalize(4.0), 80.0);
+    /// assert_eq!(ScoreFormat::Point3.normalize(3.0), 85.0);
+    /// ```
+    pub fn normalize(&self, score: f32) -> f32 {
+        if score <= 0.0 {
+            return 0.0;
+        }
+
+        let score = match self {
+            ScoreFormat::Point100 | ScoreFormat::Unknown => score,
+            ScoreFormat::Point10Decimal | ScoreFormat::Point10 => score * 10.0,
+            ScoreFormat::Point5 => score * 20.0,
+            ScoreFormat::Point3 => 10.0 + score.round().min(3.0) * 25.0,
+        };
+
+        score.min(100.0)
+    }
+}
+
+impl From<&str> for ScoreFormat {
+    fn from(value: &str) -> Self {
+        match value.trim().to_uppercase().as_str() {
+            "POINT_100" => ScoreFormat::Point100,
+            "POINT_10_DECIMAL" => ScoreFormat::Point10Decimal,
+            "POINT_10" => ScoreFormat::Point10,
+            "POINT_5" => ScoreFormat::Point5,
+            "POINT_3" => ScoreFormat::Point3,
+            _ => ScoreFormat::Unknown,
+        }
+    }
+}
+
+impl From<String> for ScoreFormat {
+    fn from(value: String) -> Self {
+        ScoreFormat::from(value.as_str())
+    }
+}
+
+impl std::fmt::Display for ScoreFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScoreFormat::Point100 => write!(f, "100 Point"),
+            ScoreFormat::Point10Decimal => write!(f, "10 Point Decimal"),
+            ScoreFormat::Point10 => write!(f, "10 Point"),
+            ScoreFormat::Point5 => write!(f, "5 Star"),
+            ScoreFormat::Point3 => write!(f, "3 Point Smiley"),
+            ScoreFormat::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// Represents the comparison of the lists of two users.
+///
+/// Scores are compared on the 100-point scale, whatever the score
+/// formats of the users. See [`Client::compare_lists`].
+///
+/// [`Client::compare_lists`]: crate::Client::compare_lists
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListComparison {
+    /// The medias in both lists, as the entries of the first and the
+    /// second user.
+    pub shared: Vec<(MediaListEntry, MediaListEntry)>,
+    /// The entries of the medias only in the list of the first user.
+    pub only_in_first: Vec<MediaListEntry>,
+    /// The entries of the medias only in the list of the second user.
+    pub only_in_second: Vec<MediaListEntry>,
+    /// The scores of the medias scored by both users.
+    pub score_differences: Vec<ScoreDifference>,
+}
+
+impl ListComparison {
+    /// Compares the lists of two users.
+    ///
+    /// The scores of the entries must be on the 100-point scale. The
+    /// entries are sorted by media ID, and a media listed twice in the
+    /// same list is only counted once.
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - The entries of the first user.
+    /// * `second` - The entries of the second user.
+    pub fn new(first: Vec<MediaListEntry>, second: Vec<MediaListEntry>) -> Self {
+        let mut second = second
+            .into_iter()
+            .map(|entry| (entry.media_id, entry))
+            .collect::<HashMap<_, _>>();
+        let first = first
+            .into_iter()
+            .map(|entry| (entry.media_id, entry))
+            .collect::<HashMap<_, _>>();
+
+        let mut comparison = Self::default();
+        for (media_id, entry) in first {
+            match second.remove(&media_id) {
+                Some(other) => comparison.shared.push((entry, other)),
+                None => comparison.only_in_first.push(entry),
+            }
+        }
+        comparison.only_in_second = second.into_values().collect();
+
+        comparison.shared.sort_by_key(|(entry, _)| entry.media_id);
+        comparison.only_in_first.sort_by_key(|entry| entry.media_id);
+        comparison
+            .only_in_second
+            .sort_by_key(|entry| entry.media_id);
+        comparison.score_differences = comparison
+            .shared
+            .iter()
+            .filter(|(first, second)| first.score > 0.0 && second.score > 0.0)
+            .map(|(first, second)| ScoreDifference {
+                media_id: first.media_id,
+                first_score: first.score,
+                second_score: second.score,
+            })
+            .collect();
+
+        comparison
+    }
+
+    /// Returns the mean absolute difference between the scores of the
+    /// medias scored by both users, on the 100-point scale.
+    ///
+    /// The lower it is, the closer the tastes of the users. Returns
+    /// `None` if no media was scored by both users.
+    pub fn mean_score_difference(&self) -> Option<f32> {
+        if self.score_differences.is_empty() {
+            return None;
+        }
+
+        let total = self
+            .score_differences
+            .iter()
+            .map(|score| score.difference().abs())
+            .sum::<f32>();
+
+        Some(total / self.score_differences.len() as f32)
+    }
+}
+
+/// Represents the scores given to a media by two users.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreDifference {
+    /// The ID of the anime or manga.
+    pub media_id: i64,
+    /// The score given by the first user, from 1 to 100.
+    pub first_score: f32,
+    /// The score given by the second user, from 1 to 100.
+    pub second_score: f32,
+}
+
+impl ScoreDifference {
+    /// Returns how many points the first user scored the media above
+    /// the second one.
+    pub fn difference(&self) -> f32 {
+        self.first_score - self.second_score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [MediaListStatus; 6] = [
+        MediaListStatus::Current,
+        MediaListStatus::Planning,
+        MediaListStatus::Completed,
+        MediaListStatus::Dropped,
+        MediaListStatus::Paused,
+        MediaListStatus::Repeating,
+    ];
+
+    #[test]
+    fn test_media_list_entry_deserialize() {
+        let entry: MediaListEntry = serde_json::from_value(serde_json::json!({
+            "id": 100,
+            "userId": 1,
+            "mediaId": 20,
+            "status": "CURRENT",
+            "score": 85.0,
+            "progress": 12,
+            "progressVolumes": null,
+            "repeat": 0,
+            "private": false,
+            "notes": null,
+            "startedAt": { "year": 2024, "month": 1, "day": 5 },
+            "completedAt": { "year": null, "month": null, "day": null },
+            "updatedAt": 1704412800
+        }))
+        .unwrap();
+
+        assert_eq!(entry.user_id, 1);
+        assert_eq!(entry.media_id, 20);
+        assert_eq!(entry.status, Some(MediaListStatus::Current));
+        assert_eq!(entry.progress, 12);
+        assert_eq!(ScoreFormat::Point10Decimal.format(entry.score), "8.5");
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        for status in ALL {
+            let json = serde_json::to_string(&status).unwrap();
+
+            assert_eq!(json, format!("\"{}\"", status.to_string().to_uppercase()));
+            assert_eq!(
+                serde_json::from_str::<MediaListStatus>(&json).unwrap(),
+                status
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        for status in ALL {
+            assert_eq!(MediaListStatus::from(status.to_string()), status);
+        }
+
+        assert_eq!(
+            MediaListStatus::from("REPEATING"),
+            MediaListStatus::Repeating
+        );
+        assert_eq!(MediaListStatus::from("unknown"), MediaListStatus::Unknown);
+    }
+
+    #[test]
+    fn test_score_format() {
+        assert_eq!(ScoreFormat::Point100.format(85.0), "85");
+        assert_eq!(ScoreFormat::Point10Decimal.format(85.0), "8.5");
+        assert_eq!(ScoreFormat::Point10Decimal.format(100.0), "10.0");
+        assert_eq!(ScoreFormat::Point10.format(85.0), "9");
+        assert_eq!(ScoreFormat::Point5.format(85.0), "★★★★");
+        assert_eq!(ScoreFormat::Point5.format(5.0), "★");
+        assert_eq!(ScoreFormat::Point3.format(85.0), "🙂");
+        assert_eq!(ScoreFormat::Point3.format(50.0), "😐");
+        assert_eq!(ScoreFormat::Point3.format(20.0), "🙁");
+        assert_eq!(ScoreFormat::Point10.format(0.0), "");
+        assert_eq!(ScoreFormat::from("POINT_10"), ScoreFormat::Point10);
+        assert_eq!(ScoreFormat::from("unknown"), ScoreFormat::Unknown);
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(ScoreFormat::Point100.normalize(73.0), 73.0);
+        assert_eq!(ScoreFormat::Point10Decimal.normalize(7.3), 73.0);
+        assert_eq!(ScoreFormat::Point10.normalize(7.0), 70.0);
+        assert_eq!(ScoreFormat::Point5.normalize(5.0), 100.0);
+        assert_eq!(ScoreFormat::Point3.normalize(1.0), 35.0);
+        assert_eq!(ScoreFormat::Point3.normalize(2.0), 60.0);
+        assert_eq!(ScoreFormat::Point10.normalize(0.0), 0.0);
+
+        for stars in 1..=5 {
+            let score = ScoreFormat::Point5.normalize(stars as f32);
+
+            assert_eq!(ScoreFormat::Point5.format(score), "★".repeat(stars));
+        }
+        let smileys = (1..=3)
+            .map(|score| ScoreFormat::Point3.format(ScoreFormat::Point3.normalize(score as f32)))
+            .collect::<Vec<_>>();
+        assert_eq!(smileys, ["🙁", "😐", "🙂"]);
+    }
+
+    #[test]
+    fn test_normalized_score() {
+        let entry = MediaListEntry {
+            score: 7.5,
+            ..Default::default()
+        };
+
+        assert_eq!(entry.normalized_score(ScoreFormat::Point10Decimal), 75.0);
+        assert_eq!(entry.normalized_score(ScoreFormat::Point100), 7.5);
+        assert_eq!(
+            MediaListEntry::default().normalized_score(ScoreFormat::Point3),
+            0.0
+        );
+    }
+
+    fn entry(media_id: i64, score: f32) -> MediaListEntry {
+        MediaListEntry {
+            media_id,
+            score,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_list_comparison() {
+        let comparison = ListComparison::new(
+            vec![
+                entry(3, 80.0),
+                entry(1, 90.0),
+                entry(2, 0.0),
+                entry(4, 50.0),
+            ],
+            vec![
+                entry(1, 70.0),
+                entry(5, 60.0),
+                entry(2, 40.0),
+                entry(1, 70.0),
+            ],
+        );
+
+        let shared = comparison
+            .shared
+            .iter()
+            .map(|(first, second)| (first.media_id, second.media_id))
+            .collect::<Vec<_>>();
+        assert_eq!(shared, [(1, 1), (2, 2)]);
+        assert_eq!(comparison.only_in_first, [entry(3, 80.0), entry(4, 50.0)]);
+        assert_eq!(comparison.only_in_second, [entry(5, 60.0)]);
+        assert_eq!(
+            comparison.score_differences,
+            [ScoreDifference {
+                media_id: 1,
+                first_score: 90.0,
+                second_score: 70.0,
+            }]
+        );
+        assert_eq!(comparison.score_differences[0].difference(), 20.0);
+        assert_eq!(comparison.mean_score_difference(), Some(20.0));
+        assert_eq!(ListComparison::default().mean_score_difference(), None);
+    }
+
+    #[test]
+    fn test_score_format_serde() {
+        let json = serde_json::to_string(&ScoreFormat::Point10Decimal).unwrap();
+
+        assert_eq!(json, "\"POINT_10_DECIMAL\"");
+        assert_eq!(
+            serde_json::from_str::<ScoreFormat>(&json).unwrap(),
+            ScoreFormat::Point10Decimal
+        );
+        assert_eq!(ScoreFormat::from("POINT_3"), ScoreFormat::Point3);
+    }
+
+    #[test]
+    fn test_is_active() {
+        let active: Vec<MediaListStatus> = ALL.into_iter().filter(|s| s.is_active()).collect();
+
+        assert_eq!(
+            active,
+            vec![MediaListStatus::Current, MediaListStatus::Repeating]
+        );
+    }
+}
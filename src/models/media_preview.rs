@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `MediaPreview` struct.
+
+use serde::{Deserialize, Serialize};
+
+use super::{Format, Title};
+
+/// Represents the few fields of an anime or a manga needed to preview it,
+/// such as in a link card.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaPreview {
+    /// The ID of the media.
+    pub id: i64,
+    /// The title of the media.
+    pub title: Title,
+    /// The URL of the large cover image of the media, if any.
+    pub cover: Option<String>,
+    /// The format of the media, if known.
+    pub format: Option<Format>,
+    /// The year of the season the media was released in, if known.
+    pub season_year: Option<u32>,
+}
@@ -3,53 +3,96 @@
 
 //! This module contains various models and structures used in the library.
 
+mod activity;
 mod anime;
 mod character;
 mod color;
+mod connections;
 mod cover;
 mod date;
+mod debug;
+mod export;
 mod format;
 mod gender;
+mod genre;
+mod id;
 mod image;
 mod language;
 mod link;
 mod manga;
 mod media;
+mod media_list;
+mod media_preview;
 mod name;
 mod notification;
+mod occupation;
+mod page;
 mod person;
+mod ranking;
 mod relation;
+mod search;
 mod season;
+mod sort;
 mod source;
+mod stats;
 mod status;
 mod studio;
 mod tag;
 mod title;
+mod trailer;
 mod user;
 
-pub use anime::Anime;
-pub use character::{Character, CharacterRole};
+pub use activity::{Activity, ActivityType};
+pub use anime::{AiringEntry, AiringEvent, AiringSchedule, Anime};
+#[cfg(feature = "client")]
+pub(crate) use character::CharacterMediaEdge;
+pub use character::{Character, CharacterMedia, CharacterMediaFilter, CharacterRole};
 pub use color::Color;
+#[cfg(feature = "client")]
+pub(crate) use connections::{character_edges, staff_edges};
 pub use cover::Cover;
 pub use date::Date;
+pub use export::MalExport;
 pub use format::Format;
 pub use gender::Gender;
+pub use genre::Genre;
+pub use id::{AnimeId, CharacterId, MangaId, PersonId, StudioId, UserId};
 pub use image::Image;
 pub use language::Language;
 pub use link::{Link, LinkType};
 pub use manga::Manga;
 pub use media::Media;
-pub use name::Name;
-pub use notification::{Notification, NotificationOption, NotificationType};
+pub use media_list::{
+    ListComparison, MediaListCollection, MediaListEntry, MediaListMedia, MediaListStatus,
+    ScoreDifference, ScoreFormat,
+};
+pub use media_preview::MediaPreview;
+pub use name::{Name, NameOrder};
+pub use notification::{
+    ActivityNotification, AiringNotification, FollowingNotification, Notification,
+    NotificationOption, NotificationType,
+};
+pub use occupation::Occupation;
+pub use page::{Page, PageInfo};
+#[cfg(feature = "client")]
+pub(crate) use page::{PageRequest, ParseItems};
 pub use person::Person;
+pub use ranking::{Ranking, RankingType};
 pub use relation::{Relation, RelationType};
+pub use search::{MediaSearchOptions, SearchResults};
 pub use season::Season;
+pub use sort::MediaSort;
 pub use source::Source;
+pub use stats::{MediaStats, MediaTrend, ScoreDistribution, StatusDistribution};
 pub use status::Status;
-pub use studio::Studio;
+pub use studio::{Studio, StudioRole};
 pub use tag::Tag;
-pub use title::Title;
-pub use user::User;
+pub use title::{Title, TitleLanguage};
+pub use trailer::Trailer;
+pub use user::{
+    Favourites, User, UserFormatStatistic, UserGenreStatistic, UserReleaseYearStatistic,
+    UserStatisticTypes, UserStatistics, UserStatusStatistic, UserTagStatistic,
+};
 
 use serde::{Deserialize, Serialize};
 
@@ -60,8 +103,10 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
 pub enum MediaType {
     /// An anime.
+    #[serde(alias = "ANIME")]
     Anime,
     /// A manga.
+    #[serde(alias = "MANGA")]
     Manga,
     /// A character.
     Character,
@@ -72,6 +117,107 @@ pub enum MediaType {
     /// A studio.
     Studio,
     /// Unknown type.
+    ///
+    /// The value isn't kept: besides animes and mangas, the types are the
+    /// entities of the crate rather than values sent by AniList.
     #[default]
+    #[serde(other)]
     Unknown,
 }
+
+impl MediaType {
+    /// Returns the name AniList gives the type, `ANIME` or `MANGA`.
+    ///
+    /// Returns `None` for the other types, which are not media types
+    /// for AniList.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::MediaType;
+    /// assert_eq!(MediaType::Manga.as_graphql(), Some("MANGA"));
+    /// assert_eq!(MediaType::Studio.as_graphql(), None);
+    /// ```
+    pub fn as_graphql(&self) -> Option<&'static str> {
+        match self {
+            MediaType::Anime => Some("ANIME"),
+            MediaType::Manga => Some("MANGA"),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for MediaType {
+    fn from(value: &str) -> Self {
+        match value.trim().to_uppercase().as_str() {
+            "ANIME" => MediaType::Anime,
+            "MANGA" => MediaType::Manga,
+            "CHARACTER" => MediaType::Character,
+            "USER" => MediaType::User,
+            "PERSON" | "STAFF" => MediaType::Person,
+            "STUDIO" => MediaType::Studio,
+            _ => MediaType::Unknown,
+        }
+    }
+}
+
+impl From<String> for MediaType {
+    fn from(value: String) -> Self {
+        MediaType::from(value.as_str())
+    }
+}
+
+impl std::fmt::Display for MediaType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MediaType::Anime => write!(f, "Anime"),
+            MediaType::Manga => write!(f, "Manga"),
+            MediaType::Character => write!(f, "Character"),
+            MediaType::User => write!(f, "User"),
+            MediaType::Person => write!(f, "Person"),
+            MediaType::Studio => write!(f, "Studio"),
+            MediaType::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [MediaType; 7] = [
+        MediaType::Anime,
+        MediaType::Manga,
+        MediaType::Character,
+        MediaType::User,
+        MediaType::Person,
+        MediaType::Studio,
+        MediaType::Unknown,
+    ];
+
+    #[test]
+    fn test_media_type_from_str() {
+        for media_type in ALL {
+            assert_eq!(MediaType::from(media_type.to_string()), media_type);
+        }
+
+        assert_eq!(MediaType::from("ANIME"), MediaType::Anime);
+        assert_eq!(MediaType::from(" manga "), MediaType::Manga);
+        assert_eq!(MediaType::from("STAFF"), MediaType::Person);
+        assert_eq!(MediaType::from("novel"), MediaType::Unknown);
+    }
+
+    #[test]
+    fn test_media_type_as_graphql() {
+        let types = ALL
+            .iter()
+            .filter_map(MediaType::as_graphql)
+            .collect::<Vec<_>>();
+
+        assert_eq!(types, ["ANIME", "MANGA"]);
+        assert_eq!(
+            MediaType::from(MediaType::Anime.as_graphql().unwrap()),
+            MediaType::Anime
+        );
+    }
+}
@@ -1,13 +1,13 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
 
-//! This module contains the `Name` struct.
+//! This module contains the `Name` struct and the `NameOrder` enum.
 
 use serde::{Deserialize, Serialize};
 
 /// Represents a name.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Name {
     /// The first name.
     pub first: Option<String>,
@@ -52,6 +52,299 @@ impl Name {
     pub fn user_preferred(&self) -> Option<String> {
         self.user_preferred.clone()
     }
+
+    /// Returns the name with its parts in the given order.
+    ///
+    /// If neither the first nor the last name is known, the full, native
+    /// or preferred name is returned instead. The result is only empty
+    /// if the name has none of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The order of the parts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::{Name, NameOrder};
+    /// let name = Name {
+    ///     first: Some("Shinichirou".to_string()),
+    ///     last: Some("Watanabe".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(name.formatted(NameOrder::Western), "Shinichirou Watanabe");
+    /// assert_eq!(name.formatted(NameOrder::Native), "Watanabe Shinichirou");
+    /// assert_eq!(
+    ///     name.formatted(NameOrder::SortableLastFirst),
+    ///     "Watanabe, Shinichirou"
+    /// );
+    /// ```
+    pub fn formatted(&self, order: NameOrder) -> String {
+        let given = [part(&self.first), part(&self.middle)]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let given = Some(given).filter(|given| !given.is_empty());
+
+        match (given, part(&self.last)) {
+            (Some(given), Some(last)) => match order {
+                NameOrder::Western => format!("{given} {last}"),
+                NameOrder::Native => format!("{last} {given}"),
+                NameOrder::SortableLastFirst => format!("{last}, {given}"),
+            },
+            (Some(given), None) => given,
+            (None, Some(last)) => last.to_string(),
+            (None, None) => part(&self.full)
+                .or(part(&self.native))
+                .or(part(&self.user_preferred))
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+
+    /// Returns whether the name matches a search query.
+    ///
+    /// The comparison ignores case, diacritics, punctuation and the order
+    /// of the words, tolerates small typos, and checks the full, native,
+    /// preferred and alternative names. Alternative names that may contain
+    /// spoilers are skipped; see [`Name::matches_including_spoilers`].
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Name;
+    /// let name = Name {
+    ///     full: Some("Hayao Miyazaki".to_string()),
+    ///     native: Some("宮崎駿".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert!(name.matches("miyazaki hayao"));
+    /// assert!(name.matches("宮崎駿"));
+    /// assert!(!name.matches("Isao Takahata"));
+    /// ```
+    pub fn matches(&self, query: &str) -> bool {
+        self.score(query) >= MATCH_THRESHOLD
+    }
+
+    /// Returns whether the name, including the alternative names that may
+    /// contain spoilers, matches a search query.
+    ///
+    /// See [`Name::matches`].
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query.
+    pub fn matches_including_spoilers(&self, query: &str) -> bool {
+        self.score_including_spoilers(query) >= MATCH_THRESHOLD
+    }
+
+    /// Returns how closely the name matches a search query, from `0.0`
+    /// to `1.0`.
+    ///
+    /// The best score among the forms of the name is returned, compared
+    /// as in [`Name::matches`]. `1.0` means a form is equal to the query
+    /// once normalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query.
+    pub fn score(&self, query: &str) -> f32 {
+        self.best_score(query, false)
+    }
+
+    /// Returns how closely the name, including the alternative names that
+    /// may contain spoilers, matches a search query.
+    ///
+    /// See [`Name::score`].
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query.
+    pub fn score_including_spoilers(&self, query: &str) -> f32 {
+        self.best_score(query, true)
+    }
+
+    /// Returns the best score among the forms of the name.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query.
+    /// * `include_spoilers` - Whether to check the spoiler alternatives.
+    fn best_score(&self, query: &str, include_spoilers: bool) -> f32 {
+        let query = normalize(query);
+        if query.is_empty() {
+            return 0.0;
+        }
+
+        let parts = Some(self.formatted(NameOrder::Western));
+        let spoilers = self
+            .alternative_spoiler
+            .iter()
+            .flatten()
+            .filter(|_| include_spoilers);
+
+        [&self.full, &self.native, &self.user_preferred, &parts]
+            .into_iter()
+            .flatten()
+            .chain(&self.alternative)
+            .chain(spoilers)
+            .map(|form| similarity(&normalize(form), &query))
+            .fold(0.0, f32::max)
+    }
+}
+
+impl std::fmt::Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match part(&self.user_preferred)
+            .or(part(&self.full))
+            .or(part(&self.native))
+        {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "{}", self.formatted(NameOrder::Western)),
+        }
+    }
+}
+
+/// Represents the order of the parts of a name.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum NameOrder {
+    /// The given name first, e.g. "Shinichirou Watanabe".
+    #[default]
+    Western,
+    /// The family name first, e.g. "Watanabe Shinichirou".
+    Native,
+    /// The family name first, separated by a comma, e.g.
+    /// "Watanabe, Shinichirou". Suited to sorting by family name.
+    SortableLastFirst,
+}
+
+/// Returns a part of a name, unless it is missing or blank.
+///
+/// # Arguments
+///
+/// * `part` - The part of the name.
+fn part(part: &Option<String>) -> Option<&str> {
+    part.as_deref()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+}
+
+/// The score from which a name matches a query.
+const MATCH_THRESHOLD: f32 = 0.8;
+
+/// Returns the words of a name or a query, lowercased and without
+/// diacritics or punctuation.
+///
+/// # Arguments
+///
+/// * `text` - The name or the query.
+pub(super) fn normalize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .chars()
+        .map(fold_diacritic)
+        .collect::<String>()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Returns a Latin letter without its diacritic.
+///
+/// # Arguments
+///
+/// * `c` - The lowercase letter.
+fn fold_diacritic(c: char) -> char {
+    const FOLDS: [(&str, char); 19] = [
+        ("àáâãäåāăą", 'a'),
+        ("çćĉċč", 'c'),
+        ("ďđ", 'd'),
+        ("èéêëēĕėęě", 'e'),
+        ("ĝğġģ", 'g'),
+        ("ĥħ", 'h'),
+        ("ìíîïĩīĭįı", 'i'),
+        ("ĵ", 'j'),
+        ("ķ", 'k'),
+        ("ĺļľŀł", 'l'),
+        ("ñńņň", 'n'),
+        ("òóôõöøōŏő", 'o'),
+        ("ŕŗř", 'r'),
+        ("śŝşš", 's'),
+        ("ţťŧ", 't'),
+        ("ùúûüũūŭůűų", 'u'),
+        ("ŵ", 'w'),
+        ("ýÿŷ", 'y'),
+        ("źżž", 'z'),
+    ];
+
+    FOLDS
+        .iter()
+        .find(|(accented, _)| accented.contains(c))
+        .map_or(c, |(_, plain)| *plain)
+}
+
+/// Returns how similar two normalized names are, from `0.0` to `1.0`.
+///
+/// The words are compared in their given order and sorted, so that the
+/// order of the given and family names doesn't matter, and the best of
+/// the two is returned.
+///
+/// # Arguments
+///
+/// * `name` - The words of the name.
+/// * `query` - The words of the query.
+fn similarity(name: &[String], query: &[String]) -> f32 {
+    let sorted = |words: &[String]| {
+        let mut words = words.to_vec();
+        words.sort_unstable();
+        words.concat()
+    };
+
+    dice(&name.concat(), &query.concat()).max(dice(&sorted(name), &sorted(query)))
+}
+
+/// Returns the Sørensen–Dice coefficient of the character bigrams of two
+/// strings.
+///
+/// # Arguments
+///
+/// * `a` - The first string.
+/// * `b` - The second string.
+pub(super) fn dice(a: &str, b: &str) -> f32 {
+    if a == b {
+        return if a.is_empty() { 0.0 } else { 1.0 };
+    }
+
+    let bigrams = |text: &str| {
+        let chars: Vec<char> = text.chars().collect();
+        chars
+            .windows(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect::<Vec<_>>()
+    };
+    let a = bigrams(a);
+    let mut b = bigrams(b);
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let total = (a.len() + b.len()) as f32;
+    let mut common = 0;
+    for bigram in &a {
+        if let Some(i) = b.iter().position(|other| other == bigram) {
+            b.swap_remove(i);
+            common += 1;
+        }
+    }
+
+    2.0 * common as f32 / total
 }
 
 #[cfg(test)]
@@ -122,6 +415,113 @@ mod tests {
         assert_eq!(name.spoiler(), Some(vec!["J.D.".to_string()]));
     }
 
+    #[test]
+    fn test_formatted() {
+        let name = Name {
+            first: Some("John".to_string()),
+            middle: Some("Doe".to_string()),
+            last: Some("Smith".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(name.formatted(NameOrder::Western), "John Doe Smith");
+        assert_eq!(name.formatted(NameOrder::Native), "Smith John Doe");
+        assert_eq!(
+            name.formatted(NameOrder::SortableLastFirst),
+            "Smith, John Doe"
+        );
+    }
+
+    #[test]
+    fn test_formatted_missing_parts() {
+        let first_only = Name {
+            first: Some("Spike".to_string()),
+            last: Some(" ".to_string()),
+            ..Default::default()
+        };
+        let native_only = Name {
+            native: Some("スパイク".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(first_only.formatted(NameOrder::SortableLastFirst), "Spike");
+        assert_eq!(native_only.formatted(NameOrder::Western), "スパイク");
+        assert_eq!(Name::default().formatted(NameOrder::Native), "");
+    }
+
+    #[test]
+    fn test_display() {
+        let name = Name {
+            first: Some("Spike".to_string()),
+            last: Some("Spiegel".to_string()),
+            full: Some("Spike Spiegel".to_string()),
+            native: Some("スパイク・スピーゲル".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(name.to_string(), "Spike Spiegel");
+        assert_eq!(
+            Name {
+                full: None,
+                ..name.clone()
+            }
+            .to_string(),
+            "スパイク・スピーゲル"
+        );
+        assert_eq!(
+            Name {
+                user_preferred: Some("Spiegel Spike".to_string()),
+                ..name
+            }
+            .to_string(),
+            "Spiegel Spike"
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        let name = Name {
+            first: Some("Hayao".to_string()),
+            last: Some("Miyazaki".to_string()),
+            full: Some("Hayao Miyazaki".to_string()),
+            native: Some("宮崎駿".to_string()),
+            ..Default::default()
+        };
+
+        assert!(name.matches("Miyazaki, Hayao"));
+        assert!(name.matches("HAYAO MIYAZAKI"));
+        assert!(name.matches("Hayao Miyazak"));
+        assert!(name.matches("宮崎駿"));
+        assert!(!name.matches("Hayao"));
+        assert!(!name.matches(""));
+        assert_eq!(name.score("miyazaki hayao"), 1.0);
+    }
+
+    #[test]
+    fn test_matches_diacritics() {
+        let name = Name {
+            full: Some("Shōji Kawamori".to_string()),
+            alternative: vec!["Kawamori Shouji".to_string()],
+            ..Default::default()
+        };
+
+        assert!(name.matches("shoji kawamori"));
+        assert!(name.matches("Kawamori Shouji"));
+    }
+
+    #[test]
+    fn test_matches_spoilers() {
+        let name = Name {
+            full: Some("Lelouch Lamperouge".to_string()),
+            alternative_spoiler: Some(vec!["Zero".to_string()]),
+            ..Default::default()
+        };
+
+        assert!(!name.matches("zero"));
+        assert!(name.matches_including_spoilers("zero"));
+        assert!(name.score_including_spoilers("zero") > name.score("zero"));
+    }
+
     #[test]
     fn test_user_preferred() {
         let name = Name {
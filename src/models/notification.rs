@@ -1,17 +1,182 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
 
-//! This module contains the `Notification` struct and its related types.
+//! This module contains the `Notification` enum and its related types.
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-/// Represents a notification.
+use super::{Anime, AnimeId, User};
+
+/// Represents a notification of the authenticated user.
+///
+/// Notifications of a type this crate doesn't model yet are kept as
+/// [`Notification::Other`], holding the JSON object sent by AniList.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Notification {
+    /// An episode of an anime in the list of the user aired.
+    Airing(AiringNotification),
+    /// A user started following the user.
+    Following(FollowingNotification),
+    /// A user sent a message to the user.
+    ActivityMessage(ActivityNotification),
+    /// A user mentioned the user in an activity.
+    ActivityMention(ActivityNotification),
+    /// A user replied to an activity of the user.
+    ActivityReply(ActivityNotification),
+    /// A user replied to an activity the user is subscribed to.
+    ActivityReplySubscribed(ActivityNotification),
+    /// A user liked an activity of the user.
+    ActivityLike(ActivityNotification),
+    /// A user liked a reply of the user.
+    ActivityReplyLike(ActivityNotification),
+    /// A notification of another type.
+    #[serde(untagged)]
+    Other(Value),
+}
+
+impl Notification {
+    /// Returns the ID of the notification, if known.
+    pub fn id(&self) -> Option<i64> {
+        match self {
+            Notification::Airing(notification) => Some(notification.id),
+            Notification::Following(notification) => Some(notification.id),
+            Notification::ActivityMessage(notification)
+            | Notification::ActivityMention(notification)
+            | Notification::ActivityReply(notification)
+            | Notification::ActivityReplySubscribed(notification)
+            | Notification::ActivityLike(notification)
+            | Notification::ActivityReplyLike(notification) => Some(notification.id),
+            Notification::Other(notification) => notification["id"].as_i64(),
+        }
+    }
+
+    /// Returns when the notification was created, as a Unix timestamp.
+    pub fn created_at(&self) -> Option<i64> {
+        match self {
+            Notification::Airing(notification) => Some(notification.created_at),
+            Notification::Following(notification) => Some(notification.created_at),
+            Notification::ActivityMessage(notification)
+            | Notification::ActivityMention(notification)
+            | Notification::ActivityReply(notification)
+            | Notification::ActivityReplySubscribed(notification)
+            | Notification::ActivityLike(notification)
+            | Notification::ActivityReplyLike(notification) => Some(notification.created_at),
+            Notification::Other(notification) => notification["createdAt"].as_i64(),
+        }
+    }
+
+    /// Returns the activity notification, if this is one.
+    #[cfg(feature = "client")]
+    pub(crate) fn activity_mut(&mut self) -> Option<&mut ActivityNotification> {
+        match self {
+            Notification::ActivityMessage(notification)
+            | Notification::ActivityMention(notification)
+            | Notification::ActivityReply(notification)
+            | Notification::ActivityReplySubscribed(notification)
+            | Notification::ActivityLike(notification)
+            | Notification::ActivityReplyLike(notification) => Some(notification),
+            _ => None,
+        }
+    }
+
+    /// Returns the type of the notification, if known.
+    pub fn notification_type(&self) -> Option<NotificationType> {
+        match self {
+            Notification::Airing(_) => Some(NotificationType::Airing),
+            Notification::Following(_) => Some(NotificationType::Following),
+            Notification::ActivityMessage(_) => Some(NotificationType::ActivityMessage),
+            Notification::ActivityMention(_) => Some(NotificationType::ActivityMention),
+            Notification::ActivityReply(_) => Some(NotificationType::ActivityReply),
+            Notification::ActivityReplySubscribed(_) => {
+                Some(NotificationType::ActivityReplySubscribed)
+            }
+            Notification::ActivityLike(_) => Some(NotificationType::ActivityLike),
+            Notification::ActivityReplyLike(_) => Some(NotificationType::ActivityReplyLike),
+            Notification::Other(notification) => {
+                NotificationType::deserialize(&notification["type"]).ok()
+            }
+        }
+    }
+}
+
+/// Represents the airing of an episode of an anime.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-pub struct Notification {}
+#[serde(rename_all = "camelCase")]
+pub struct AiringNotification {
+    /// The ID of the notification.
+    pub id: i64,
+    /// The ID of the anime.
+    pub anime_id: AnimeId,
+    /// The number of the episode.
+    pub episode: u32,
+    /// The pieces of text around the episode and the title, e.g.
+    /// `["Episode ", " of ", " aired."]`.
+    #[serde(default)]
+    pub contexts: Vec<String>,
+    /// The anime, if sent.
+    pub media: Option<Anime>,
+    /// When the notification was created, as a Unix timestamp.
+    pub created_at: i64,
+}
+
+impl AiringNotification {
+    /// Returns the text of the notification, e.g. "Episode 5 of Cowboy
+    /// Bebop aired.".
+    ///
+    /// The title is in the language set on the client.
+    pub fn message(&self) -> String {
+        let title = self
+            .media
+            .as_ref()
+            .map(|anime| anime.title.preferred().to_string())
+            .unwrap_or_else(|| format!("anime {}", self.anime_id));
+
+        match self.contexts.as_slice() {
+            [before, between, after] => {
+                format!("{before}{}{between}{title}{after}", self.episode)
+            }
+            _ => format!("Episode {} of {title} aired.", self.episode),
+        }
+    }
+}
+
+/// Represents a user following the authenticated user.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowingNotification {
+    /// The ID of the notification.
+    pub id: i64,
+    /// The text following the name of the user, e.g. " started
+    /// following you.".
+    pub context: Option<String>,
+    /// The user who followed.
+    pub user: Option<User>,
+    /// When the notification was created, as a Unix timestamp.
+    pub created_at: i64,
+}
+
+/// Represents an interaction of a user with an activity.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityNotification {
+    /// The ID of the notification.
+    pub id: i64,
+    /// The ID of the activity.
+    pub activity_id: i64,
+    /// The text following the name of the user, e.g. " liked your
+    /// activity.".
+    pub context: Option<String>,
+    /// The user who interacted with the activity.
+    pub user: Option<User>,
+    /// When the notification was created, as a Unix timestamp.
+    pub created_at: i64,
+}
 
 /// Represents the options for a notification.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct NotificationOption {
     /// The type of the notification.
     notification_type: NotificationType,
@@ -21,7 +186,7 @@ pub struct NotificationOption {
 
 /// Represents the type of a notification.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "SCREAMING_SNAKE_CASE"))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum NotificationType {
     /// Notification for an activity message.
     #[default]
@@ -52,6 +217,9 @@ pub enum NotificationType {
     MediaMerge,
     /// Notification for a media deletion.
     MediaDeletion,
+    /// A value unknown to this version of the crate, as sent by AniList.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NotificationType {
@@ -71,6 +239,38 @@ impl std::fmt::Display for NotificationType {
             NotificationType::MediaDataChange => write!(f, "Media Data Change"),
             NotificationType::MediaMerge => write!(f, "Media Merge"),
             NotificationType::MediaDeletion => write!(f, "Media Deletion"),
+            NotificationType::Unknown(notification_type) => write!(f, "{notification_type}"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_other() {
+        let json = serde_json::json!({
+            "id": 4,
+            "type": "MEDIA_DELETION",
+            "context": " was deleted from the site",
+            "createdAt": 1700000000
+        });
+        let notification: Notification = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(notification, Notification::Other(json.clone()));
+        assert_eq!(notification.created_at(), Some(1700000000));
+        assert_eq!(serde_json::to_value(&notification).unwrap(), json);
+    }
+
+    #[test]
+    fn test_airing_message_without_contexts() {
+        let airing = AiringNotification {
+            anime_id: AnimeId::new(1),
+            episode: 3,
+            ..Default::default()
+        };
+
+        assert_eq!(airing.message(), "Episode 3 of anime 1 aired.");
+    }
+}
@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Occupation` enum.
+
+use std::fmt::Display;
+
+/// Represents a category of the occupations of a person.
+///
+/// AniList stores the primary occupations of a person as free text, such
+/// as `"Voice Actor"` or `"Key Animator"`; an `Occupation` groups the
+/// common ones into broad categories.
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum Occupation {
+    /// A voice actor, an actor or a narrator.
+    VoiceActor,
+    /// A director, including the sound and the episode directors.
+    Director,
+    /// An artist, such as an animator, an illustrator or a mangaka.
+    Artist,
+    /// A musician, such as a composer, a singer or a lyricist.
+    Musician,
+    /// An occupation that doesn't fall in any other category.
+    Other(String),
+}
+
+/// The keywords of the occupations of each category, in lowercase.
+const KEYWORDS: [(Occupation, &[&str]); 4] = [
+    (
+        Occupation::VoiceActor,
+        &["voice act", "seiyuu", "actor", "actress", "narrator"],
+    ),
+    (Occupation::Director, &["director"]),
+    (
+        Occupation::Musician,
+        &[
+            "music",
+            "composer",
+            "singer",
+            "lyricist",
+            "vocalist",
+            "songwriter",
+            "arranger",
+            "band",
+        ],
+    ),
+    (
+        Occupation::Artist,
+        &[
+            "artist",
+            "animator",
+            "illustrator",
+            "mangaka",
+            "designer",
+            "painter",
+        ],
+    ),
+];
+
+impl Occupation {
+    /// Returns the name of the occupation category.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Occupation;
+    /// assert_eq!(Occupation::VoiceActor.name(), "Voice Actor");
+    /// assert_eq!(Occupation::Other(String::from("Producer")).name(), "Producer");
+    /// ```
+    pub fn name(&self) -> &str {
+        match self {
+            Occupation::VoiceActor => "Voice Actor",
+            Occupation::Director => "Director",
+            Occupation::Artist => "Artist",
+            Occupation::Musician => "Musician",
+            Occupation::Other(occupation) => occupation,
+        }
+    }
+}
+
+impl From<&str> for Occupation {
+    fn from(value: &str) -> Self {
+        let lowercase = value.to_lowercase();
+
+        KEYWORDS
+            .into_iter()
+            .find(|(_, keywords)| keywords.iter().any(|keyword| lowercase.contains(keyword)))
+            .map(|(occupation, _)| occupation)
+            .unwrap_or_else(|| Occupation::Other(value.trim().to_string()))
+    }
+}
+
+impl From<String> for Occupation {
+    fn from(value: String) -> Self {
+        Occupation::from(value.as_str())
+    }
+}
+
+impl Display for Occupation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Occupation::from("Voice Actor"), Occupation::VoiceActor);
+        assert_eq!(Occupation::from("voice actress"), Occupation::VoiceActor);
+        assert_eq!(Occupation::from("Sound Director"), Occupation::Director);
+        assert_eq!(Occupation::from("Key Animator"), Occupation::Artist);
+        assert_eq!(Occupation::from("Mangaka"), Occupation::Artist);
+        assert_eq!(Occupation::from("Composer"), Occupation::Musician);
+        assert_eq!(Occupation::from("Singer"), Occupation::Musician);
+    }
+
+    #[test]
+    fn test_from_str_other() {
+        assert_eq!(
+            Occupation::from(" Producer "),
+            Occupation::Other(String::from("Producer"))
+        );
+    }
+}
@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Page` and `PageInfo` structs.
+
+use std::ops::Deref;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::Client;
+#[cfg(feature = "client")]
+use crate::Result;
+
+/// Parses the items of a page from its paginated object.
+pub(crate) type ParseItems<T> = fn(&Client, &Value) -> Vec<T>;
+
+/// Represents a page of results, such as the results of a search.
+///
+/// A page remembers the request it answers, so the following pages can
+/// be fetched with [`Page::next`] without repeating its arguments. It
+/// dereferences to a slice of its items and can be iterated over.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn f(client: rust_anilist::Client) -> rust_anilist::Result<()> {
+/// let mut page = client.search_anime("Naruto", 1, 10).await;
+///
+/// while let Some(current) = page {
+///     for anime in current.iter() {
+///         println!("{}", anime.title.romaji());
+///     }
+///
+///     page = current.next().await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    /// The items of the page.
+    pub items: Vec<T>,
+    /// The position of the page among the results.
+    pub page_info: PageInfo,
+
+    /// The request answered by the page, if it can be continued.
+    #[cfg_attr(not(feature = "client"), allow(dead_code))]
+    pub(crate) request: Option<PageRequest<T>>,
+}
+
+impl<T> Page<T> {
+    /// Fetches the page following this one.
+    ///
+    /// Returns `None` if this is the last page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    #[cfg(feature = "client")]
+    pub async fn next(&self) -> Result<Option<Page<T>>> {
+        let Some(request) = self
+            .request
+            .as_ref()
+            .filter(|_| self.page_info.has_next_page)
+        else {
+            return Ok(None);
+        };
+
+        let mut variables = request.variables.clone();
+        variables["page"] = (self.page_info.current_page + 1).into();
+
+        request
+            .client
+            .get_page_at(&request.query, variables, request.path, request.parse)
+            .await
+            .map(Some)
+    }
+
+    /// Returns whether a page follows this one.
+    pub fn has_next(&self) -> bool {
+        self.page_info.has_next_page
+    }
+}
+
+impl<T> Deref for Page<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Page<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+/// Represents the request answered by a page.
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "client"), allow(dead_code))]
+pub(crate) struct PageRequest<T> {
+    /// The client sending the request.
+    pub client: Client,
+    /// The GraphQL document of the request.
+    pub query: String,
+    /// The variables of the request, including `page`.
+    pub variables: Value,
+    /// The JSON pointer to the paginated object in the `data` object.
+    pub path: &'static str,
+    /// The parser of the items.
+    pub parse: ParseItems<T>,
+}
+
+/// Represents the position of a page among the results.
+///
+/// AniList may not report the totals of large result sets, so only
+/// `current_page` and `has_next_page` are always reliable.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PageInfo {
+    /// The total number of results, if known.
+    pub total: Option<u32>,
+    /// The number of results per page.
+    pub per_page: Option<u32>,
+    /// The number of the page, starting at 1.
+    pub current_page: u32,
+    /// The number of the last page, if known.
+    pub last_page: Option<u32>,
+    /// Whether a page follows this one.
+    pub has_next_page: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(has_next_page: bool) -> Page<i64> {
+        Page {
+            items: vec![1, 2, 3],
+            page_info: PageInfo {
+                current_page: 1,
+                has_next_page,
+                ..Default::default()
+            },
+            request: None,
+        }
+    }
+
+    #[test]
+    fn test_page_info_deserialize() {
+        let page_info: PageInfo = serde_json::from_value(serde_json::json!({
+            "total": 5000,
+            "perPage": 10,
+            "currentPage": 2,
+            "lastPage": 500,
+            "hasNextPage": true
+        }))
+        .unwrap();
+
+        assert_eq!(page_info.per_page, Some(10));
+        assert_eq!(page_info.current_page, 2);
+        assert!(page_info.has_next_page);
+    }
+
+    #[test]
+    fn test_iterate() {
+        let page = page(false);
+
+        assert_eq!(page.len(), 3);
+        assert_eq!(page[0], 1);
+        assert_eq!((&page).into_iter().sum::<i64>(), 6);
+        assert_eq!(page.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[cfg(feature = "client")]
+    #[tokio::test]
+    async fn test_next_without_request() {
+        assert!(page(true).next().await.unwrap().is_none());
+        assert!(!page(false).has_next());
+    }
+}
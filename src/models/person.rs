@@ -1,17 +1,23 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-use super::{Character, Date, Gender, Image, Language, Name};
-use crate::{Client, Result};
+use super::connections::Connection;
+use super::debug::{ClientPlaceholder, Truncated};
+#[cfg(feature = "client")]
+use super::Page;
+use super::{Character, Date, Gender, Image, Language, Name, Occupation, PersonId};
+use crate::Client;
+#[cfg(feature = "client")]
+use crate::Result;
 
 /// Represents a person.
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[derive(Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Person {
     /// The ID of the person.
-    pub id: i64,
+    pub id: PersonId,
     /// The name of the person.
     pub name: Name,
     /// The language of the person.
@@ -31,8 +37,10 @@ pub struct Person {
     pub date_of_death: Option<Date>,
     /// The age of the person, if any.
     pub age: Option<i64>,
-    // The years the person was active, if any.
-    // pub years_active: Option<(u64, u64)>,
+    /// The years the person was active, if any: the start year, and the
+    /// end year if the person is no longer active.
+    #[serde(default, deserialize_with = "deserialize_years_active")]
+    pub years_active: Option<(u32, Option<u32>)>,
     /// The hometown of the person, if any.
     pub home_town: Option<String>,
     /// The blood type of the person, if any.
@@ -44,23 +52,186 @@ pub struct Person {
     /// The URL of the person's site.
     #[serde(rename = "siteUrl")]
     pub url: String,
-    /// The characters associated with the person, if any.
-    #[serde(skip)]
+    /// The first page of the characters voiced by the person, if any.
+    ///
+    /// Use [`Person::get_characters`] to get the following pages.
+    #[serde(default, deserialize_with = "deserialize_characters")]
     pub characters: Option<Vec<Character>>,
     /// The number of favorites the person has.
     pub favourites: Option<i64>,
     /// The moderator notes for the person, if any.
     pub mod_notes: Option<String>,
+    /// The role of the person in the staff of a media, if any.
+    ///
+    /// Only set on the staff of an [`Anime`](super::Anime) or a
+    /// [`Manga`](super::Manga), e.g.
+    /// `"Director"` or `"Key Animation (ep 1)"`.
+    pub staff_role: Option<String>,
 
     /// The client used to fetch additional data.
     #[serde(skip)]
+    #[cfg_attr(not(feature = "client"), allow(dead_code))]
     pub(crate) client: Client,
     /// Whether the person's data is fully loaded.
     #[serde(default)]
     pub(crate) is_full_loaded: bool,
 }
 
+impl PartialEq for Person {
+    /// Compares every field of the people but their client and whether they
+    /// are fully loaded.
+    fn eq(&self, other: &Self) -> bool {
+        let Person {
+            id,
+            name,
+            language,
+            image,
+            description,
+            primary_occupations,
+            gender,
+            date_of_birth,
+            date_of_death,
+            age,
+            years_active,
+            home_town,
+            blood_type,
+            is_favourite,
+            is_favourite_blocked,
+            url,
+            characters,
+            favourites,
+            mod_notes,
+            staff_role,
+            client: _,
+            is_full_loaded: _,
+        } = self;
+
+        *id == other.id
+            && *name == other.name
+            && *language == other.language
+            && *image == other.image
+            && *description == other.description
+            && *primary_occupations == other.primary_occupations
+            && *gender == other.gender
+            && *date_of_birth == other.date_of_birth
+            && *date_of_death == other.date_of_death
+            && *age == other.age
+            && *years_active == other.years_active
+            && *home_town == other.home_town
+            && *blood_type == other.blood_type
+            && *is_favourite == other.is_favourite
+            && *is_favourite_blocked == other.is_favourite_blocked
+            && *url == other.url
+            && *characters == other.characters
+            && *favourites == other.favourites
+            && *mod_notes == other.mod_notes
+            && *staff_role == other.staff_role
+    }
+}
+
+/// Two equal people always have the same ID, so people are hashed by ID.
+impl Eq for Person {}
+
+impl std::hash::Hash for Person {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl std::fmt::Debug for Person {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Person {
+            id,
+            name,
+            language,
+            image,
+            description,
+            primary_occupations,
+            gender,
+            date_of_birth,
+            date_of_death,
+            age,
+            years_active,
+            home_town,
+            blood_type,
+            is_favourite,
+            is_favourite_blocked,
+            url,
+            characters,
+            favourites,
+            mod_notes,
+            staff_role,
+            client: _,
+            is_full_loaded,
+        } = self;
+
+        f.debug_struct("Person")
+            .field("id", id)
+            .field("name", name)
+            .field("language", language)
+            .field("image", image)
+            .field("description", &description.as_deref().map(Truncated))
+            .field("primary_occupations", primary_occupations)
+            .field("gender", gender)
+            .field("date_of_birth", date_of_birth)
+            .field("date_of_death", date_of_death)
+            .field("age", age)
+            .field("years_active", years_active)
+            .field("home_town", home_town)
+            .field("blood_type", blood_type)
+            .field("is_favourite", is_favourite)
+            .field("is_favourite_blocked", is_favourite_blocked)
+            .field("url", url)
+            .field("characters", characters)
+            .field("favourites", favourites)
+            .field("mod_notes", &mod_notes.as_deref().map(Truncated))
+            .field("staff_role", staff_role)
+            .field("client", &ClientPlaceholder)
+            .field("is_full_loaded", is_full_loaded)
+            .finish()
+    }
+}
+
 impl Person {
+    /// Creates a partially loaded person, to test code consuming people
+    /// without reaching the API.
+    ///
+    /// The other fields are left to their defaults, the site URL is
+    /// built from the ID, and the person is detached: the methods
+    /// sending requests fail with [`Error::Detached`](crate::Error::Detached).
+    ///
+    /// This function is only available with the `test-util` feature,
+    /// which `test-utils` enables as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the person.
+    /// * `name` - The full name of the person.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Person;
+    /// let person = Person::for_test(95061, "Shinichirou Watanabe");
+    ///
+    /// assert_eq!(person.url, "https://anilist.co/staff/95061");
+    /// ```
+    #[cfg(feature = "test-util")]
+    pub fn for_test(id: impl Into<PersonId>, name: &str) -> Self {
+        let id = id.into();
+
+        Self {
+            id,
+            name: Name {
+                full: Some(name.to_string()),
+                ..Default::default()
+            },
+            url: format!("https://anilist.co/staff/{id}"),
+            client: Client::detached(),
+            ..Default::default()
+        }
+    }
+
     /// Loads the full details of the person.
     ///
     /// # Errors
@@ -81,6 +252,7 @@ impl Person {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "client")]
     pub async fn load_full(self) -> Result<Self> {
         if !self.is_full_loaded {
             self.client.get_person(self.id).await
@@ -89,6 +261,201 @@ impl Person {
         }
     }
 
+    /// Detaches the person from its client.
+    ///
+    /// A detached person keeps its data, and the methods reading it,
+    /// such as [`Person::computed_age`] and [`Person::is_voice_actor`], keep working. The methods sending
+    /// requests, such as [`Person::load_full`] and [`Person::get_characters`], fail with
+    /// [`Error::Detached`](crate::Error::Detached) until a client is
+    /// attached with [`Person::attach`]. The nested models are detached
+    /// as well.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Person, Client, Result};
+    /// #
+    /// # async fn f(person: Person, client: Client) -> Result<()> {
+    /// let cached = person.detach();
+    /// // ...
+    /// let person = cached.attach(&client).load_full().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "client")]
+    pub fn detach(self) -> Self {
+        self.attach(&Client::detached())
+    }
+
+    /// Attaches the person to a client, which sends its requests from now on.
+    ///
+    /// The nested models are attached as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to attach.
+    #[cfg(feature = "client")]
+    pub fn attach(mut self, client: &Client) -> Self {
+        self.set_client(client);
+        self
+    }
+
+    /// Gives the client to the person and its nested models.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to give.
+    #[cfg(feature = "client")]
+    pub(crate) fn set_client(&mut self, client: &Client) {
+        self.client = client.clone();
+        for character in self.characters.iter_mut().flatten() {
+            character.set_client(client);
+        }
+    }
+
+    /// Returns whether the person has the given role in the staff of the
+    /// media it was loaded from.
+    ///
+    /// Roles are compared case-insensitively, ignoring the qualifier in
+    /// parentheses AniList appends to some of them.
+    pub(crate) fn has_staff_role(&self, role: &str) -> bool {
+        self.staff_role.as_deref().is_some_and(|staff_role| {
+            let staff_role = staff_role.split(" (").next().unwrap_or_default();
+            staff_role.trim().eq_ignore_ascii_case(role.trim())
+        })
+    }
+
+    /// Returns the categories of the primary occupations of the person,
+    /// without duplicates, in the order of the occupations.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::models::{Occupation, Person};
+    /// #
+    /// # fn f(staff: Vec<Person>) {
+    /// let directors: Vec<_> = staff
+    ///     .iter()
+    ///     .filter(|person| person.occupation_categories().contains(&Occupation::Director))
+    ///     .collect();
+    /// # }
+    /// ```
+    pub fn occupation_categories(&self) -> Vec<Occupation> {
+        let mut categories = Vec::new();
+
+        for occupation in self.primary_occupations.iter().flatten() {
+            let category = Occupation::from(occupation.as_str());
+            if !categories.contains(&category) {
+                categories.push(category);
+            }
+        }
+
+        categories
+    }
+
+    /// Returns whether one of the primary occupations of the person is
+    /// voice acting.
+    pub fn is_voice_actor(&self) -> bool {
+        self.occupation_categories()
+            .contains(&Occupation::VoiceActor)
+    }
+
+    /// Returns the age of the person, computed from its dates if AniList
+    /// doesn't provide it.
+    ///
+    /// The age provided by AniList is preferred when `as_of` is `None`.
+    /// Otherwise the age is computed at `as_of`, or today, from the date
+    /// of birth; for a deceased person, it is computed at the date of
+    /// death if that comes first. The birthday is assumed to have passed
+    /// if the month or the day of either date is unknown.
+    ///
+    /// Returns `None` if the age is unknown and the year of birth, or the
+    /// year of the date it is computed at, is unknown.
+    ///
+    /// # Arguments
+    ///
+    /// * `as_of` - The date to compute the age at, or `None` for today.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::models::{Date, Person};
+    /// #
+    /// # fn f(person: Person) {
+    /// let age = person.computed_age(None);
+    /// let age_in_2000 = person.computed_age(Some(Date::new(Some(2000), Some(1), Some(1))));
+    /// # }
+    /// ```
+    pub fn computed_age(&self, as_of: Option<Date>) -> Option<u32> {
+        if as_of.is_none() {
+            if let Some(age) = self.age.and_then(|age| u32::try_from(age).ok()) {
+                return Some(age);
+            }
+        }
+
+        let birth = self.date_of_birth.as_ref()?;
+        let mut at = as_of.unwrap_or_else(Date::now);
+        if let Some(death) = &self.date_of_death {
+            if death.year.is_some() && date_key(death) < date_key(&at) {
+                at = death.clone();
+            }
+        }
+
+        let mut age = at.year? - birth.year?;
+        if let (Some(month), Some(day), Some(birth_month), Some(birth_day)) =
+            (at.month, at.day, birth.month, birth.day)
+        {
+            if (month, day) < (birth_month, birth_day) {
+                age -= 1;
+            }
+        } else if let (Some(month), Some(birth_month)) = (at.month, birth.month) {
+            if month < birth_month {
+                age -= 1;
+            }
+        }
+
+        u32::try_from(age).ok()
+    }
+
+    /// Retrieves a page of the characters voiced by the person, by
+    /// relevance.
+    ///
+    /// The first page is the one loaded in [`Person::characters`] by
+    /// [`Client::get_person`](crate::Client::get_person).
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Person, Result};
+    /// #
+    /// # async fn f(person: Person) -> Result<()> {
+    /// let mut page = Some(person.get_characters(2).await?);
+    ///
+    /// while let Some(current) = page {
+    ///     for character in current.iter() {
+    ///         println!("{}", character.name);
+    ///     }
+    ///
+    ///     page = current.next().await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "client")]
+    pub async fn get_characters(&self, page: u16) -> Result<Page<Character>> {
+        self.client
+            .get_person_characters(self.id, page, CHARACTERS_PER_PAGE)
+            .await
+    }
+
     /// Retrieves the media associated with the person.
     ///
     /// # Errors
@@ -109,6 +476,7 @@ impl Person {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "client")]
     pub async fn get_medias<T>(&self) -> Result<Vec<T>> {
         unimplemented!()
     }
@@ -137,7 +505,195 @@ impl Person {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "client")]
     pub async fn get_character_medias<T>(&self, _character_id: i64) -> Result<Vec<T>> {
         unimplemented!()
     }
 }
+
+/// The number of characters in the first page loaded with a person.
+#[cfg(feature = "client")]
+const CHARACTERS_PER_PAGE: u16 = 25;
+
+/// Returns a key ordering dates, the unknown parts coming first.
+fn date_key(date: &Date) -> (Option<i32>, Option<u32>, Option<u32>) {
+    (date.year, date.month, date.day)
+}
+
+fn deserialize_years_active<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<(u32, Option<u32>)>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let years: Option<Vec<Option<u32>>> = Option::deserialize(deserializer)?;
+    let mut years = years.into_iter().flatten().flatten();
+
+    Ok(years.next().map(|start| (start, years.next())))
+}
+
+fn deserialize_characters<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Vec<Character>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct CharacterConnection {
+        nodes: Vec<Character>,
+    }
+
+    let characters: Option<Connection<CharacterConnection, Character>> =
+        Option::deserialize(deserializer)?;
+
+    Ok(characters.map(|characters| match characters {
+        Connection::Connection(conn) => conn.nodes,
+        Connection::List(characters) => characters,
+    }))
+}
+
+impl std::fmt::Display for Person {
+    /// Writes the name of the person followed by their role in the
+    /// media they were fetched with, or else their primary occupation,
+    /// e.g. `Kouichi Yamadera [Voice Actor]`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+
+        let role = self.staff_role.as_deref().or_else(|| {
+            self.primary_occupations
+                .as_ref()?
+                .first()
+                .map(String::as_str)
+        });
+        if let Some(role) = role.filter(|role| !role.is_empty()) {
+            write!(f, " [{role}]")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn years_active(years: serde_json::Value) -> Option<(u32, Option<u32>)> {
+        #[derive(Deserialize)]
+        struct YearsActive {
+            #[serde(default, deserialize_with = "deserialize_years_active")]
+            years: Option<(u32, Option<u32>)>,
+        }
+
+        serde_json::from_value::<YearsActive>(serde_json::json!({ "years": years }))
+            .unwrap()
+            .years
+    }
+
+    #[test]
+    fn test_years_active() {
+        assert_eq!(
+            years_active(serde_json::json!([1998, 2020])),
+            Some((1998, Some(2020)))
+        );
+        assert_eq!(years_active(serde_json::json!([1998])), Some((1998, None)));
+    }
+
+    #[test]
+    fn test_years_active_empty() {
+        assert_eq!(years_active(serde_json::json!([])), None);
+        assert_eq!(years_active(serde_json::json!(null)), None);
+    }
+
+    fn person(birth: Date, death: Option<Date>) -> Person {
+        Person {
+            date_of_birth: Some(birth),
+            date_of_death: death,
+            ..Default::default()
+        }
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date::new(Some(year), Some(month), Some(day))
+    }
+
+    #[test]
+    fn test_computed_age_birthday_boundary() {
+        let person = person(date(1961, 6, 27), None);
+
+        assert_eq!(person.computed_age(Some(date(2021, 6, 26))), Some(59));
+        assert_eq!(person.computed_age(Some(date(2021, 6, 27))), Some(60));
+        assert_eq!(person.computed_age(Some(date(2021, 6, 28))), Some(60));
+    }
+
+    #[test]
+    fn test_computed_age_partial_dates() {
+        let month_only = person(Date::new(Some(1961), Some(6), None), None);
+        assert_eq!(month_only.computed_age(Some(date(2021, 5, 30))), Some(59));
+        assert_eq!(month_only.computed_age(Some(date(2021, 6, 1))), Some(60));
+
+        let year_only = person(Date::new(Some(1961), None, None), None);
+        assert_eq!(year_only.computed_age(Some(date(2021, 1, 1))), Some(60));
+
+        let no_year = person(Date::new(None, Some(6), Some(27)), None);
+        assert_eq!(no_year.computed_age(Some(date(2021, 7, 1))), None);
+
+        assert_eq!(Person::default().computed_age(Some(date(2021, 7, 1))), None);
+    }
+
+    #[test]
+    fn test_computed_age_at_death() {
+        let person = person(date(1940, 10, 9), Some(date(1980, 12, 8)));
+
+        assert_eq!(person.computed_age(Some(date(2024, 1, 1))), Some(40));
+        assert_eq!(person.computed_age(Some(date(1970, 1, 1))), Some(29));
+    }
+
+    #[test]
+    fn test_computed_age_prefers_api_age() {
+        let person = Person {
+            age: Some(45),
+            ..person(date(1961, 6, 27), None)
+        };
+
+        assert_eq!(person.computed_age(None), Some(45));
+        assert_eq!(person.computed_age(Some(date(2021, 6, 27))), Some(60));
+    }
+
+    #[test]
+    fn test_computed_age_before_birth() {
+        let person = person(date(1961, 6, 27), None);
+
+        assert_eq!(person.computed_age(Some(date(1950, 1, 1))), None);
+    }
+
+    #[test]
+    fn test_occupation_categories() {
+        let person = Person {
+            primary_occupations: Some(vec![
+                String::from("Voice Actor"),
+                String::from("Singer"),
+                String::from("Actor"),
+                String::from("Producer"),
+            ]),
+            ..Default::default()
+        };
+
+        assert!(person.is_voice_actor());
+        assert_eq!(
+            person.occupation_categories(),
+            vec![
+                Occupation::VoiceActor,
+                Occupation::Musician,
+                Occupation::Other(String::from("Producer")),
+            ]
+        );
+        assert!(!Person::default().is_voice_actor());
+    }
+
+    #[test]
+    fn test_years_active_round_trip() {
+        let serialized = serde_json::to_value((1998, None::<u32>)).unwrap();
+
+        assert_eq!(years_active(serialized), Some((1998, None)));
+    }
+}
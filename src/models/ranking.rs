@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Ranking` struct and the `RankingType` enum.
+
+use serde::{Deserialize, Serialize};
+
+use super::{Format, Season};
+
+/// Represents a ranking of a media, such as "#3 most popular Spring 2016".
+#[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ranking {
+    /// The ID of the ranking.
+    pub id: i64,
+    /// The position of the media in the ranking.
+    pub rank: u32,
+    /// The type of the ranking.
+    #[serde(rename = "type")]
+    pub ranking_type: RankingType,
+    /// The format the media is ranked within.
+    pub format: Format,
+    /// The year the media is ranked within, if any.
+    pub year: Option<u32>,
+    /// The season the media is ranked within, if any.
+    pub season: Option<Season>,
+    /// Whether the ranking covers all time instead of a year or a season.
+    pub all_time: Option<bool>,
+    /// The description of the ranking, e.g. `"highest rated all time"`.
+    pub context: String,
+}
+
+impl Ranking {
+    /// Returns whether the ranking covers all time.
+    pub fn is_all_time(&self) -> bool {
+        self.all_time.unwrap_or_default()
+    }
+
+    /// Returns whether the ranking covers a single season.
+    pub fn is_seasonal(&self) -> bool {
+        !self.is_all_time() && self.season.is_some()
+    }
+}
+
+/// Represents the type of a ranking.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum RankingType {
+    /// Ranked by score.
+    #[default]
+    Rated,
+    /// Ranked by popularity.
+    Popular,
+    /// A value unknown to this version of the crate.
+    ///
+    /// Unlike the `Unknown(String)` of the other enums, the value isn't
+    /// kept, so that the enum stays `Copy`.
+    #[serde(other)]
+    Unknown,
+}
+
+impl RankingType {
+    /// Returns the name of the ranking type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::RankingType;
+    /// assert_eq!(RankingType::Rated.name(), "Highest Rated");
+    /// assert_eq!(RankingType::Popular.name(), "Most Popular");
+    /// ```
+    pub fn name(&self) -> &str {
+        match self {
+            RankingType::Rated => "Highest Rated",
+            RankingType::Popular => "Most Popular",
+            RankingType::Unknown => "Unknown",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let ranking: Ranking = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "rank": 3,
+            "type": "POPULAR",
+            "format": "TV",
+            "year": 2016,
+            "season": "SPRING",
+            "allTime": false,
+            "context": "most popular"
+        }))
+        .unwrap();
+
+        assert_eq!(ranking.rank, 3);
+        assert_eq!(ranking.ranking_type, RankingType::Popular);
+        assert_eq!(ranking.season, Some(Season::Spring));
+        assert!(ranking.is_seasonal());
+        assert!(!ranking.is_all_time());
+    }
+
+    #[test]
+    fn test_all_time() {
+        let ranking = Ranking {
+            all_time: Some(true),
+            ..Default::default()
+        };
+
+        assert!(ranking.is_all_time());
+        assert!(!ranking.is_seasonal());
+    }
+}
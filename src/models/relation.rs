@@ -6,7 +6,11 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use super::{Anime, Cover, Format, Manga, Media, Status, Title};
+use super::debug::ClientPlaceholder;
+use super::{Anime, Cover, Format, Manga, Media, MediaType, Status, Title};
+use crate::Client;
+#[cfg(feature = "client")]
+use crate::{models::AnimeId, Error, Result};
 
 /// Represents a relation between different media types.
 ///
@@ -14,8 +18,8 @@ use super::{Anime, Cover, Format, Manga, Media, Status, Title};
 /// between different media types, such as anime and manga, including
 /// the related media, relation ID, relation type, and whether it is
 /// the main studio.
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[derive(Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Relation {
     /// The related media.
     pub(crate) node: Value,
@@ -25,20 +29,93 @@ pub struct Relation {
     pub relation_type: RelationType,
     /// Whether the relation is the main studio.
     pub is_main_studio: bool,
+
+    /// The client used to fetch additional data.
+    #[serde(skip)]
+    pub(crate) client: Client,
+}
+
+impl PartialEq for Relation {
+    /// Compares every field of the relations but their client.
+    fn eq(&self, other: &Self) -> bool {
+        let Relation {
+            node,
+            id,
+            relation_type,
+            is_main_studio,
+            client: _,
+        } = self;
+
+        *node == other.node
+            && *id == other.id
+            && *relation_type == other.relation_type
+            && *is_main_studio == other.is_main_studio
+    }
+}
+
+impl std::fmt::Debug for Relation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Relation")
+            .field("node", &self.node)
+            .field("id", &self.id)
+            .field("relation_type", &self.relation_type)
+            .field("is_main_studio", &self.is_main_studio)
+            .field("client", &ClientPlaceholder)
+            .finish()
+    }
 }
 
 impl Relation {
+    /// Detaches the relation from its client.
+    ///
+    /// A detached relation keeps its data, and [`Relation::media`] keeps
+    /// working, but [`Relation::load`] fails with
+    /// [`Error::Detached`](crate::Error::Detached) until a client is
+    /// attached with [`Relation::attach`].
+    #[cfg(feature = "client")]
+    pub fn detach(self) -> Self {
+        self.attach(&Client::detached())
+    }
+
+    /// Attaches the relation to a client, which sends its requests from
+    /// now on.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to attach.
+    #[cfg(feature = "client")]
+    pub fn attach(mut self, client: &Client) -> Self {
+        self.client = client.clone();
+        self
+    }
+
+    /// Returns the type of the related media.
+    fn media_type(&self) -> MediaType {
+        MediaType::from(self.node["type"].as_str().unwrap_or_default())
+    }
+
+    /// Returns the ID of the related media if it is an anime.
+    #[cfg(feature = "client")]
+    pub(crate) fn anime_id(&self) -> Option<AnimeId> {
+        match self.media_type() {
+            MediaType::Anime => self.node["id"].as_i64().map(AnimeId::from),
+            _ => None,
+        }
+    }
+
     /// Returns the related media.
     pub fn media(&self) -> Media {
         let media = self.node.clone();
 
-        match self.node["type"].as_str() {
-            Some("ANIME") => Media::Anime(Anime {
-                id: media["id"].as_i64().unwrap(),
+        match self.media_type() {
+            MediaType::Anime => Media::Anime(Anime {
+                id: media["id"].as_i64().unwrap().into(),
                 id_mal: media["idMal"].as_i64(),
-                title: Title::deserialize(&media["title"]).unwrap(),
+                title: Title::deserialize(&media["title"])
+                    .unwrap()
+                    .with_language(self.client.title_language()),
                 format: Format::deserialize(&media["format"]).unwrap(),
-                status: Status::deserialize(&media["status"]).unwrap(),
+                status: Status::deserialize(&media["status"]).unwrap_or_default(),
                 description: media["description"].as_str().unwrap().to_string(),
                 cover: Cover::deserialize(&media["coverImage"]).unwrap(),
                 banner: media["bannerImage"].as_str().map(String::from),
@@ -46,14 +123,17 @@ impl Relation {
                 mean_score: media["meanScore"].as_u64().map(|x| x as u8),
                 url: media["siteUrl"].as_str().unwrap().to_string(),
 
+                client: self.client.clone(),
                 ..Default::default()
             }),
-            Some("MANGA") => Media::Manga(Manga {
-                id: media["id"].as_i64().unwrap(),
+            MediaType::Manga => Media::Manga(Manga {
+                id: media["id"].as_i64().unwrap().into(),
                 id_mal: media["idMal"].as_i64(),
-                title: Title::deserialize(&media["title"]).unwrap(),
+                title: Title::deserialize(&media["title"])
+                    .unwrap()
+                    .with_language(self.client.title_language()),
                 format: Format::deserialize(&media["format"]).unwrap(),
-                status: Status::deserialize(&media["status"]).unwrap(),
+                status: Status::deserialize(&media["status"]).unwrap_or_default(),
                 description: media["description"].as_str().unwrap().to_string(),
                 cover: Cover::deserialize(&media["coverImage"]).unwrap(),
                 banner: media["bannerImage"].as_str().map(String::from),
@@ -61,11 +141,48 @@ impl Relation {
                 mean_score: media["meanScore"].as_u64().map(|x| x as u8),
                 url: media["siteUrl"].as_str().unwrap().to_string(),
 
+                client: self.client.clone(),
                 ..Default::default()
             }),
             _ => Media::Unknown,
         }
     }
+
+    /// Loads the full details of the related media.
+    ///
+    /// The anime or the manga is fetched depending on the type of the
+    /// related media, through the client of the media the relation comes
+    /// from, or the one given to [`Relation::attach`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the related media is neither an anime nor a
+    /// manga, or if its details cannot be loaded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::{Anime, Media, RelationType}, Result};
+    /// #
+    /// # async fn f(anime: Anime) -> Result<()> {
+    /// for relation in anime.relations()? {
+    ///     if relation.relation_type == RelationType::Sequel {
+    ///         let sequel = relation.load().await?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "client")]
+    pub async fn load(&self) -> Result<Media> {
+        let id = self.node["id"].as_i64().ok_or(Error::InvalidId)?;
+
+        match self.media_type() {
+            MediaType::Anime => Ok(Media::Anime(self.client.get_anime(id).await?)),
+            MediaType::Manga => Ok(Media::Manga(self.client.get_manga(id).await?)),
+            _ => Err(Error::InvalidMediaType),
+        }
+    }
 }
 
 /// Represents the type of relation between different media.
@@ -74,7 +191,7 @@ impl Relation {
 /// can exist between different media, such as adaptations, sequels,
 /// prequels, and more.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "SCREAMING_SNAKE_CASE"))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RelationType {
     /// The media is an adaptation of another work.
     Adaptation,
@@ -103,6 +220,9 @@ pub enum RelationType {
     Compilation,
     /// The media contains another work.
     Contains,
+    /// A value unknown to this version of the crate, as sent by AniList.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl RelationType {
@@ -124,6 +244,7 @@ impl RelationType {
             RelationType::Source => "The source material the media was adapted from",
             RelationType::Compilation => "A compilation of the media",
             RelationType::Contains => "A media that contains the relation",
+            RelationType::Unknown(_) => "Unknown",
         }
     }
 }
@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `SearchResults` and `MediaSearchOptions`
+//! structs.
+
+use serde::{Deserialize, Serialize};
+
+use super::{Anime, Character, Genre, Manga, MediaType, Person, Studio};
+
+/// Represents the results of a search across every entity type, as
+/// returned by `Client::search_all`.
+///
+/// Every list holds the best matches first and is partially loaded.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SearchResults {
+    /// The matching animes.
+    pub anime: Vec<Anime>,
+    /// The matching mangas.
+    pub manga: Vec<Manga>,
+    /// The matching characters.
+    pub characters: Vec<Character>,
+    /// The matching staff.
+    pub staff: Vec<Person>,
+    /// The matching studios.
+    pub studios: Vec<Studio>,
+}
+
+impl SearchResults {
+    /// Returns the number of results, of every type.
+    pub fn len(&self) -> usize {
+        self.anime.len()
+            + self.manga.len()
+            + self.characters.len()
+            + self.staff.len()
+            + self.studios.len()
+    }
+
+    /// Returns whether nothing matched.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Represents the filters of a media search, shared by the searches and
+/// `Client::count_media`.
+///
+/// # Example
+///
+/// ```
+/// # use rust_anilist::models::{Genre, MediaSearchOptions, MediaType};
+/// let options = MediaSearchOptions::new()
+///     .media_type(MediaType::Manga)
+///     .genres(&[Genre::Romance]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(feature = "client"), allow(dead_code))]
+pub struct MediaSearchOptions {
+    /// The type of the medias.
+    pub(crate) media_type: MediaType,
+    /// The text the titles must match, if set.
+    pub(crate) search: Option<String>,
+    /// The genres the medias must all have.
+    pub(crate) genres: Vec<Genre>,
+}
+
+impl MediaSearchOptions {
+    /// Creates options matching every anime.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the type of the medias, either anime or manga.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_type` - The type of the medias.
+    pub fn media_type(mut self, media_type: MediaType) -> Self {
+        self.media_type = media_type;
+        self
+    }
+
+    /// Only keeps the medias whose title matches the given text.
+    ///
+    /// # Arguments
+    ///
+    /// * `search` - The text to search.
+    pub fn search(mut self, search: &str) -> Self {
+        self.search = Some(search.to_string());
+        self
+    }
+
+    /// Only keeps the medias having all the given genres.
+    ///
+    /// # Arguments
+    ///
+    /// * `genres` - The genres the medias must have.
+    pub fn genres(mut self, genres: &[Genre]) -> Self {
+        self.genres = genres.to_vec();
+        self
+    }
+
+    /// Returns the variables of the filters, without the type of the
+    /// medias, which the search documents set themselves.
+    #[cfg(feature = "client")]
+    pub(crate) fn variables(&self) -> serde_json::Value {
+        let mut variables = serde_json::json!({});
+        if let Some(search) = &self.search {
+            variables["search"] = search.as_str().into();
+        }
+        if !self.genres.is_empty() {
+            variables["genres"] = serde_json::json!(self.genres);
+        }
+
+        variables
+    }
+}
+
+impl Default for MediaSearchOptions {
+    fn default() -> Self {
+        Self {
+            media_type: MediaType::Anime,
+            search: None,
+            genres: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        let mut results = SearchResults::default();
+        assert!(results.is_empty());
+
+        results.anime.push(Anime::default());
+        results.studios.push(Studio::default());
+        assert_eq!(results.len(), 2);
+        assert!(!results.is_empty());
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn test_media_search_options_variables() {
+        assert_eq!(MediaSearchOptions::new().variables(), serde_json::json!({}));
+
+        let options = MediaSearchOptions::new()
+            .search("bebop")
+            .genres(&[Genre::SciFi]);
+        assert_eq!(
+            options.variables(),
+            serde_json::json!({ "search": "bebop", "genres": ["Sci-Fi"] })
+        );
+    }
+}
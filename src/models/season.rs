@@ -3,6 +3,7 @@
 
 //! This module contains the `Season` enum.
 
+use chrono::{Datelike, Local};
 use serde::{Deserialize, Serialize};
 
 /// Represents the four seasons of the year.
@@ -11,7 +12,7 @@ use serde::{Deserialize, Serialize};
 /// and Fall. This can be used to categorize or filter data based on
 /// the season.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "UPPERCASE"))]
+#[serde(rename_all = "UPPERCASE")]
 pub enum Season {
     /// Represents the winter season.
     #[default]
@@ -22,6 +23,9 @@ pub enum Season {
     Summer,
     /// Represents the fall season.
     Fall,
+    /// A value unknown to this version of the crate, as sent by AniList.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl Season {
@@ -40,6 +44,7 @@ impl Season {
             Season::Spring => "Spring",
             Season::Summer => "Summer",
             Season::Fall => "Fall",
+            Season::Unknown(season) => season,
         }
     }
 
@@ -49,9 +54,108 @@ impl Season {
             Season::Winter => "Winter is the coldest season of the year in polar and temperate zones; it does not occur in most of the tropical zone.",
             Season::Spring => "Spring is one of the four temperate seasons, following winter and preceding summer.",
             Season::Summer => "Summer is the hottest of the four temperate seasons, falling after spring and before autumn.",
-            Season::Fall => "Autumn, also known as fall in North American English, is one of the four temperate seasons."
+            Season::Fall => "Autumn, also known as fall in North American English, is one of the four temperate seasons.",
+            Season::Unknown(_) => "Unknown",
         }
     }
+
+    /// Returns the index of the season used by AniList's `seasonInt`.
+    ///
+    /// Winter is `1`, spring is `2`, summer is `3` and fall is `4`.
+    pub fn index(&self) -> u64 {
+        match self {
+            Season::Winter => 1,
+            Season::Spring => 2,
+            Season::Summer => 3,
+            Season::Fall => 4,
+            Season::Unknown(_) => 0,
+        }
+    }
+
+    /// Encodes the season and the given year as AniList's `seasonInt`.
+    ///
+    /// AniList encodes the season as the last two digits of the year
+    /// followed by the season index, so spring 2013 becomes `132` and
+    /// spring 1998 becomes `982`. The values therefore don't sort
+    /// chronologically across centuries: `982` is greater than `132`
+    /// although spring 1998 comes first; sort by the decoded year and
+    /// season instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The year of the season.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Season;
+    /// assert_eq!(Season::Spring.to_int(2013), 132);
+    /// assert_eq!(Season::Fall.to_int(2002), 24);
+    /// ```
+    pub fn to_int(&self, year: u32) -> u64 {
+        (year as u64 % 100) * 10 + self.index()
+    }
+
+    /// Decodes AniList's `seasonInt` into a season and a year, relative
+    /// to the current year.
+    ///
+    /// See [`Season::from_int_relative`] for how the century is inferred.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The `seasonInt` value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Season;
+    /// assert_eq!(Season::from_int(132), Some((Season::Spring, 2013)));
+    /// assert_eq!(Season::from_int(982), Some((Season::Spring, 1998)));
+    /// ```
+    pub fn from_int(value: u64) -> Option<(Season, u32)> {
+        Season::from_int_relative(value, Local::now().year() as u32)
+    }
+
+    /// Decodes AniList's `seasonInt` into a season and a year, relative
+    /// to the given year.
+    ///
+    /// As AniList only keeps the last two digits of the year, the century
+    /// is inferred: the year is the latest one ending with these digits
+    /// that is at most ten years after `reference_year`, which leaves
+    /// room for the seasons announced ahead.
+    ///
+    /// Returns `None` if the season index is not between `1` and `4`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The `seasonInt` value.
+    /// * `reference_year` - The year the century is inferred from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Season;
+    /// assert_eq!(Season::from_int_relative(282, 2026), Some((Season::Spring, 2028)));
+    /// assert_eq!(Season::from_int_relative(982, 2026), Some((Season::Spring, 1998)));
+    /// ```
+    pub fn from_int_relative(value: u64, reference_year: u32) -> Option<(Season, u32)> {
+        let season = match value % 10 {
+            1 => Season::Winter,
+            2 => Season::Spring,
+            3 => Season::Summer,
+            4 => Season::Fall,
+            _ => return None,
+        };
+
+        let short_year = ((value / 10) % 100) as u32;
+        let latest_year = reference_year + 10;
+        let mut year = latest_year - latest_year % 100 + short_year;
+        if year > latest_year && year >= 100 {
+            year -= 100;
+        }
+
+        Some((season, year))
+    }
 }
 
 impl From<&str> for Season {
@@ -61,7 +165,7 @@ impl From<&str> for Season {
             "SPRING" => Season::Spring,
             "SUMMER" => Season::Summer,
             "FALL" => Season::Fall,
-            _ => Season::default(),
+            _ => Season::Unknown(value.to_string()),
         }
     }
 }
@@ -96,7 +200,10 @@ mod tests {
         assert_eq!(Season::from("SPRING"), Season::Spring);
         assert_eq!(Season::from("Summer"), Season::Summer);
         assert_eq!(Season::from("fall"), Season::Fall);
-        assert_eq!(Season::from("unknown"), Season::Winter); // Default case
+        assert_eq!(
+            Season::from("unknown"),
+            Season::Unknown("unknown".to_string())
+        );
     }
 
     #[test]
@@ -105,6 +212,64 @@ mod tests {
         assert_eq!(Season::from("SPRING".to_string()), Season::Spring);
         assert_eq!(Season::from("Summer".to_string()), Season::Summer);
         assert_eq!(Season::from("fall".to_string()), Season::Fall);
-        assert_eq!(Season::from("unknown".to_string()), Season::Winter); // Default case
+        assert_eq!(
+            Season::from("unknown".to_string()),
+            Season::Unknown("unknown".to_string())
+        );
+    }
+
+    #[test]
+    fn test_to_int() {
+        assert_eq!(Season::Spring.to_int(1998), 982); // Cowboy Bebop
+        assert_eq!(Season::Fall.to_int(2002), 24); // Naruto
+        assert_eq!(Season::Spring.to_int(2013), 132); // Shingeki no Kyojin
+        assert_eq!(Season::Winter.to_int(2020), 201);
+        assert_eq!(Season::Summer.to_int(2016), 163);
+    }
+
+    #[test]
+    fn test_from_int() {
+        assert_eq!(Season::from_int(982), Some((Season::Spring, 1998)));
+        assert_eq!(Season::from_int(24), Some((Season::Fall, 2002)));
+        assert_eq!(Season::from_int(132), Some((Season::Spring, 2013)));
+        assert_eq!(Season::from_int(201), Some((Season::Winter, 2020)));
+        assert_eq!(Season::from_int(130), None);
+        assert_eq!(Season::from_int(135), None);
+    }
+
+    #[test]
+    fn test_from_int_relative() {
+        assert_eq!(
+            Season::from_int_relative(282, 2026),
+            Some((Season::Spring, 2028))
+        );
+        assert_eq!(
+            Season::from_int_relative(364, 2026),
+            Some((Season::Fall, 2036))
+        );
+        assert_eq!(
+            Season::from_int_relative(374, 2026),
+            Some((Season::Fall, 1937))
+        );
+        assert_eq!(
+            Season::from_int_relative(52, 2096),
+            Some((Season::Spring, 2105))
+        );
+        assert_eq!(
+            Season::from_int_relative(982, 2096),
+            Some((Season::Spring, 2098))
+        );
+    }
+
+    #[test]
+    fn test_int_round_trip() {
+        for year in [1963, 1998, 2002, 2013, 2024, 2030] {
+            for season in [Season::Winter, Season::Spring, Season::Summer, Season::Fall] {
+                assert_eq!(
+                    Season::from_int_relative(season.to_int(year), 2026),
+                    Some((season, year))
+                );
+            }
+        }
     }
 }
@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `MediaSort` enum.
+
+use serde::{Deserialize, Serialize};
+
+/// Represents the order in which medias are listed.
+///
+/// It serializes to the names used by AniList, e.g. `POPULARITY_DESC`,
+/// so it can be sent as a variable.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MediaSort {
+    /// Most popular first.
+    #[default]
+    PopularityDesc,
+    /// Best scored first.
+    ScoreDesc,
+    /// Most trending first.
+    TrendingDesc,
+    /// Most favourited first.
+    FavouritesDesc,
+    /// Oldest first.
+    StartDate,
+    /// Newest first.
+    StartDateDesc,
+    /// Alphabetically by Romaji title.
+    TitleRomaji,
+    /// Lowest ID first.
+    Id,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            serde_json::to_value(MediaSort::PopularityDesc).unwrap(),
+            "POPULARITY_DESC"
+        );
+        assert_eq!(
+            serde_json::to_value(MediaSort::StartDateDesc).unwrap(),
+            "START_DATE_DESC"
+        );
+        assert_eq!(serde_json::to_value(MediaSort::Id).unwrap(), "ID");
+    }
+}
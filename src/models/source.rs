@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 
 /// Represents the source of a media.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "SCREAMING_SNAKE_CASE"))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Source {
     /// The original source.
     Original,
@@ -40,6 +40,9 @@ pub enum Source {
     MultimediaProject,
     /// Picture book source.
     PictureBook,
+    /// A value unknown to this version of the crate, as sent by AniList.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl Source {
@@ -69,6 +72,7 @@ impl Source {
             Source::Comic => "A publication that consists of comic art in the form of sequential panels excluding manga",
             Source::MultimediaProject => "A work that is a collection of multiple media",
             Source::PictureBook => "A book with pictures and little text",
+            Source::Unknown(_) => "Unknown",
         }
     }
 }
@@ -91,7 +95,7 @@ impl From<&str> for Source {
             "COMIC" => Source::Comic,
             "MULTIMEDIA_PROJECT" => Source::MultimediaProject,
             "PICTURE_BOOK" => Source::PictureBook,
-            _ => Source::Other,
+            _ => Source::Unknown(source.to_string()),
         }
     }
 }
@@ -120,6 +124,7 @@ impl std::fmt::Display for Source {
             Source::Comic => write!(f, "Comic"),
             Source::MultimediaProject => write!(f, "Multimedia Project"),
             Source::PictureBook => write!(f, "Picture Book"),
+            Source::Unknown(source) => write!(f, "{source}"),
         }
     }
 }
@@ -148,7 +153,10 @@ mod tests {
             Source::MultimediaProject
         );
         assert_eq!(Source::from("picture_book"), Source::PictureBook);
-        assert_eq!(Source::from("unknown"), Source::Other); // Default case
+        assert_eq!(
+            Source::from("unknown"),
+            Source::Unknown("unknown".to_string())
+        );
     }
 
     #[test]
@@ -177,6 +185,9 @@ mod tests {
             Source::from("picture_book".to_string()),
             Source::PictureBook
         );
-        assert_eq!(Source::from("unknown".to_string()), Source::Other); // Default case
+        assert_eq!(
+            Source::from("unknown".to_string()),
+            Source::Unknown("unknown".to_string())
+        );
     }
 }
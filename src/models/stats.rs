@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `MediaStats` and `MediaTrend` structs and
+//! their related types.
+
+use serde::{Deserialize, Serialize};
+
+use super::MediaListStatus;
+
+/// Represents how the users rated and listed a media.
+#[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaStats {
+    /// The number of users per score, in steps of 10 out of 100.
+    pub score_distribution: Option<Vec<ScoreDistribution>>,
+    /// The number of users per list status.
+    pub status_distribution: Option<Vec<StatusDistribution>>,
+}
+
+impl MediaStats {
+    /// Returns the number of users per score, sorted by score.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::{MediaStats, ScoreDistribution};
+    /// let stats = MediaStats {
+    ///     score_distribution: Some(vec![
+    ///         ScoreDistribution { score: 90, amount: 30 },
+    ///         ScoreDistribution { score: 10, amount: 2 },
+    ///     ]),
+    ///     status_distribution: None,
+    /// };
+    ///
+    /// assert_eq!(stats.scores(), vec![(10, 2), (90, 30)]);
+    /// ```
+    pub fn scores(&self) -> Vec<(u8, u32)> {
+        let mut scores: Vec<(u8, u32)> = self
+            .score_distribution
+            .iter()
+            .flatten()
+            .map(|distribution| (distribution.score, distribution.amount))
+            .collect();
+        scores.sort_unstable();
+
+        scores
+    }
+}
+
+/// Represents the number of users who gave a media a score.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub struct ScoreDistribution {
+    /// The score, out of 100.
+    pub score: u8,
+    /// The number of users who gave this score.
+    pub amount: u32,
+}
+
+/// Represents the number of users who have a media with a list status.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub struct StatusDistribution {
+    /// The list status.
+    pub status: MediaListStatus,
+    /// The number of users with this status.
+    pub amount: u32,
+}
+
+/// Represents the activity of a media on a day.
+///
+/// AniList records one trend per day while a media is trending.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaTrend {
+    /// The day, as a Unix timestamp.
+    pub date: i64,
+    /// The amount of activity of the media on the day.
+    pub trending: u32,
+    /// The number of users with the media in their list on the day.
+    pub popularity: Option<u32>,
+    /// The average score of the media on the day, out of 100.
+    pub average_score: Option<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let stats: MediaStats = serde_json::from_value(serde_json::json!({
+            "scoreDistribution": [
+                { "score": 100, "amount": 40 },
+                { "score": 50, "amount": 5 }
+            ],
+            "statusDistribution": [
+                { "status": "COMPLETED", "amount": 300 },
+                { "status": "DROPPED", "amount": 10 }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(stats.scores(), vec![(50, 5), (100, 40)]);
+        assert_eq!(
+            stats.status_distribution.unwrap()[0].status,
+            MediaListStatus::Completed
+        );
+    }
+
+    #[test]
+    fn test_deserialize_trend() {
+        let trend: MediaTrend = serde_json::from_value(serde_json::json!({
+            "date": 1_609_459_200,
+            "trending": 120,
+            "popularity": 50_000,
+            "averageScore": null
+        }))
+        .unwrap();
+
+        assert_eq!(trend.date, 1_609_459_200);
+        assert_eq!(trend.trending, 120);
+        assert_eq!(trend.popularity, Some(50_000));
+        assert_eq!(trend.average_score, None);
+    }
+
+    #[test]
+    fn test_scores_empty() {
+        assert!(MediaStats::default().scores().is_empty());
+    }
+}
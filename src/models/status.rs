@@ -5,9 +5,11 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::MediaListStatus;
+
 /// Represents the status of a media.
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "SCREAMING_SNAKE_CASE"))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Status {
     /// The media is finished.
     Finished,
@@ -32,9 +34,65 @@ pub enum Status {
     Paused,
     /// The media is repeating.
     Repeating,
+    /// A value unknown to this version of the crate, as sent by AniList.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl Status {
+    /// Returns whether the media has started releasing, whether it is
+    /// still releasing or not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Status;
+    /// assert!(Status::Hiatus.is_released());
+    /// assert!(!Status::NotYetReleased.is_released());
+    /// ```
+    pub fn is_released(&self) -> bool {
+        matches!(
+            self,
+            Status::Finished
+                | Status::Releasing
+                | Status::Hiatus
+                | Status::Current
+                | Status::Completed
+        )
+    }
+
+    /// Returns whether new episodes or chapters of the media are being
+    /// released.
+    pub fn is_airing(&self) -> bool {
+        matches!(self, Status::Releasing | Status::Current)
+    }
+
+    /// Returns whether the media is yet to be released.
+    pub fn is_upcoming(&self) -> bool {
+        matches!(self, Status::NotYetReleased | Status::Planning)
+    }
+
+    /// Returns the list status a media with this status is added to
+    /// the list with by default.
+    ///
+    /// An airing media is being watched or read, a media on hiatus is
+    /// paused and any other media is planned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::{MediaListStatus, Status};
+    /// assert_eq!(Status::NotYetReleased.to_list_default(), MediaListStatus::Planning);
+    /// assert_eq!(Status::Releasing.to_list_default(), MediaListStatus::Current);
+    /// ```
+    pub fn to_list_default(&self) -> MediaListStatus {
+        match self {
+            Status::Releasing | Status::Current => MediaListStatus::Current,
+            Status::Hiatus => MediaListStatus::Paused,
+            _ => MediaListStatus::Planning,
+        }
+    }
+
     /// Returns a summary of the status.
     pub fn summary(&self) -> &str {
         match self {
@@ -51,6 +109,7 @@ impl Status {
             }
             Status::Paused => "Currently paused.",
             Status::Repeating => "Repeating the same content.",
+            Status::Unknown(_) => "Unknown.",
         }
     }
 }
@@ -69,6 +128,68 @@ impl std::fmt::Display for Status {
             Status::Dropped => write!(f, "Dropped"),
             Status::Paused => write!(f, "Paused"),
             Status::Repeating => write!(f, "Repeating"),
+            Status::Unknown(status) => write!(f, "{status}"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let statuses: Vec<Status> = serde_json::from_value(serde_json::json!([
+            "CANCELLED",
+            "HIATUS",
+            "NOT_YET_RELEASED"
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            statuses,
+            [Status::Cancelled, Status::Hiatus, Status::NotYetReleased]
+        );
+    }
+
+    #[test]
+    fn test_predicates() {
+        assert!(Status::Finished.is_released());
+        assert!(!Status::Finished.is_airing());
+        assert!(Status::Releasing.is_released());
+        assert!(Status::Releasing.is_airing());
+        assert!(Status::Hiatus.is_released());
+        assert!(!Status::Hiatus.is_airing());
+        assert!(!Status::Cancelled.is_upcoming());
+        assert!(Status::NotYetReleased.is_upcoming());
+        assert!(!Status::NotYetReleased.is_released());
+        assert!(!Status::Unknown("REBOOTING".to_string()).is_released());
+    }
+
+    #[test]
+    fn test_to_list_default() {
+        assert_eq!(
+            Status::Releasing.to_list_default(),
+            MediaListStatus::Current
+        );
+        assert_eq!(Status::Hiatus.to_list_default(), MediaListStatus::Paused);
+        assert_eq!(
+            Status::Finished.to_list_default(),
+            MediaListStatus::Planning
+        );
+        assert_eq!(
+            Status::Cancelled.to_list_default(),
+            MediaListStatus::Planning
+        );
+        assert_eq!(
+            Status::NotYetReleased.to_list_default(),
+            MediaListStatus::Planning
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Status::NotYetReleased.to_string(), "Not Yet Released");
+        assert_eq!(Status::Hiatus.to_string(), "Hiatus");
+    }
+}
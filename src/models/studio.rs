@@ -3,8 +3,17 @@
 
 //! This module contains the `Studio` struct.
 
+#[cfg(feature = "client")]
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
+use super::debug::{ClientPlaceholder, ConnectionSummary};
+use super::StudioId;
+#[cfg(feature = "client")]
+use super::{Anime, Page};
+use crate::Client;
+#[cfg(feature = "client")]
 use crate::Result;
 
 /// Represents a studio with various attributes.
@@ -12,11 +21,11 @@ use crate::Result;
 /// The `Studio` struct contains detailed information about a studio,
 /// including its ID, name, whether it is an animation studio, URL,
 /// whether it is a favorite, and the number of favorites.
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[derive(Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Studio {
     /// The ID of the studio.
-    pub id: i64,
+    pub id: StudioId,
     /// The name of the studio.
     pub name: String,
     /// Whether the studio is an animation studio.
@@ -28,18 +37,228 @@ pub struct Studio {
     pub is_favourite: Option<bool>,
     /// The number of favorites the studio has.
     pub favourites: Option<i64>,
+    /// The medias produced by the studio.
+    #[serde(rename = "media")]
+    pub(crate) medias: Option<Value>,
+    /// Whether the studio is the main studio of the media it was loaded
+    /// from, if known.
+    ///
+    /// This is only set for the studios of an [`Anime`](super::Anime).
+    #[serde(default)]
+    pub is_main: Option<bool>,
+
+    /// The client used to fetch additional data.
+    #[serde(skip)]
+    #[cfg_attr(not(feature = "client"), allow(dead_code))]
+    pub(crate) client: Client,
+    /// Whether the studio's data is fully loaded.
+    #[serde(default)]
+    pub(crate) is_full_loaded: bool,
+}
+
+impl PartialEq for Studio {
+    /// Compares every field of the studios but their client and whether they
+    /// are fully loaded.
+    fn eq(&self, other: &Self) -> bool {
+        let Studio {
+            id,
+            name,
+            is_animation_studio,
+            url,
+            is_favourite,
+            favourites,
+            medias,
+            is_main,
+            client: _,
+            is_full_loaded: _,
+        } = self;
+
+        *id == other.id
+            && *name == other.name
+            && *is_animation_studio == other.is_animation_studio
+            && *url == other.url
+            && *is_favourite == other.is_favourite
+            && *favourites == other.favourites
+            && *medias == other.medias
+            && *is_main == other.is_main
+    }
+}
+
+/// Two equal studios always have the same ID, so studios are hashed by ID.
+impl Eq for Studio {}
+
+impl std::hash::Hash for Studio {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl std::fmt::Debug for Studio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Studio {
+            id,
+            name,
+            is_animation_studio,
+            url,
+            is_favourite,
+            favourites,
+            medias,
+            is_main,
+            client: _,
+            is_full_loaded,
+        } = self;
+
+        f.debug_struct("Studio")
+            .field("id", id)
+            .field("name", name)
+            .field("is_animation_studio", is_animation_studio)
+            .field("url", url)
+            .field("is_favourite", is_favourite)
+            .field("favourites", favourites)
+            .field(
+                "medias",
+                &medias
+                    .as_ref()
+                    .map(|medias| ConnectionSummary(medias, "media")),
+            )
+            .field("is_main", is_main)
+            .field("client", &ClientPlaceholder)
+            .field("is_full_loaded", is_full_loaded)
+            .finish()
+    }
 }
 
 impl Studio {
+    /// Loads the full details of the studio.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the studio details cannot be loaded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the studio is already fully loaded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Studio, Result};
+    /// #
+    /// # async fn f(studio: Studio) -> Result<()> {
+    /// let studio = studio.load_full().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "client")]
+    pub async fn load_full(self) -> Result<Self> {
+        if !self.is_full_loaded {
+            self.client.get_studio(self.id).await
+        } else {
+            panic!("This studio is already full loaded")
+        }
+    }
+
+    /// Detaches the studio from its client.
+    ///
+    /// A detached studio keeps its data, and the methods reading it,
+    /// such as [`Studio::main_studio`], keep working. The methods sending
+    /// requests, such as [`Studio::load_full`] and [`Studio::get_animes`], fail with
+    /// [`Error::Detached`](crate::Error::Detached) until a client is
+    /// attached with [`Studio::attach`]. The nested models are detached
+    /// as well.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Studio, Client, Result};
+    /// #
+    /// # async fn f(studio: Studio, client: Client) -> Result<()> {
+    /// let cached = studio.detach();
+    /// // ...
+    /// let studio = cached.attach(&client).load_full().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "client")]
+    pub fn detach(self) -> Self {
+        self.attach(&Client::detached())
+    }
+
+    /// Attaches the studio to a client, which sends its requests from now on.
+    ///
+    /// The nested models are attached as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to attach.
+    #[cfg(feature = "client")]
+    pub fn attach(mut self, client: &Client) -> Self {
+        self.set_client(client);
+        self
+    }
+
+    /// Gives the client to the studio and its nested models.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to give.
+    #[cfg(feature = "client")]
+    pub(crate) fn set_client(&mut self, client: &Client) {
+        self.client = client.clone();
+    }
+
+    /// Returns whether the studio is the main studio of the media.
+    ///
+    /// AniList marks the studio that animated a media as its main
+    /// studio, the other ones being producers. This is only known for
+    /// the studios of an [`Anime`](super::Anime), and is `false` for a
+    /// studio loaded on its own.
+    pub fn main_studio(&self) -> bool {
+        self.is_main == Some(true)
+    }
+
+    /// Returns whether the name of the studio matches the given one.
+    ///
+    /// The names are compared case-insensitively, ignoring punctuation
+    /// and spacing.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name to compare.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Studio;
+    /// let studio: Studio = serde_json::from_value(serde_json::json!({
+    ///     "id": 561,
+    ///     "name": "A-1 Pictures",
+    ///     "isAnimationStudio": true,
+    /// }))
+    /// .unwrap();
+    ///
+    /// assert!(studio.matches_name("a1 pictures"));
+    /// assert!(!studio.matches_name("CloverWorks"));
+    /// ```
+    pub fn matches_name(&self, name: &str) -> bool {
+        normalize_name(&self.name) == normalize_name(name)
+    }
+
     /// Retrieves media associated with the studio.
     ///
     /// This function fetches media related to the studio and returns a
-    /// result containing the media data of type `T`.
+    /// result containing the media data of type `T`, most popular first.
+    /// The studio is loaded first if it is not fully loaded.
     ///
     /// # Type Parameters
     ///
     /// * `T` - The type of the media to be returned.
     ///
+    /// # Errors
+    ///
+    /// Returns an error if the studio cannot be loaded or a media cannot
+    /// be parsed as `T`.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -50,7 +269,160 @@ impl Studio {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_medias<T>(&self) -> Result<Vec<T>> {
-        unimplemented!()
+    #[cfg(feature = "client")]
+    pub async fn get_medias<T: DeserializeOwned>(&self) -> Result<Vec<T>> {
+        parse_medias(&self.load_medias().await?, None)
+    }
+
+    /// Retrieves the medias the studio had the given role in, most
+    /// popular first.
+    ///
+    /// AniList only tells whether a studio is the main studio of a
+    /// media, the one that animated it; every other studio of the media
+    /// is counted as a producer. It doesn't tell apart the other roles,
+    /// such as licensing or music production. This is unrelated to
+    /// [`Studio::is_animation_studio`], which tells whether the company
+    /// is an animation studio at all. Medias whose role is unknown, such
+    /// as the ones of a studio serialized without it, are left out.
+    ///
+    /// The studio is loaded first if it is not fully loaded.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type of the media to be returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - The role the studio had in the medias.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the studio cannot be loaded or a media cannot
+    /// be parsed as `T`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::{Anime, Studio, StudioRole}, Result};
+    /// #
+    /// # async fn f(studio: Studio) -> Result<()> {
+    /// let animated = studio.get_medias_by_role::<Anime>(StudioRole::Animation).await?;
+    /// let produced = studio.get_medias_by_role::<Anime>(StudioRole::Production).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "client")]
+    pub async fn get_medias_by_role<T: DeserializeOwned>(
+        &self,
+        role: StudioRole,
+    ) -> Result<Vec<T>> {
+        parse_medias(&self.load_medias().await?, Some(role))
+    }
+
+    /// Returns the media connection of the studio, loading the studio
+    /// if it is not fully loaded.
+    #[cfg(feature = "client")]
+    async fn load_medias(&self) -> Result<Value> {
+        Ok(match &self.medias {
+            Some(medias) => medias.clone(),
+            None => self
+                .client
+                .get_studio(self.id)
+                .await?
+                .medias
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Retrieves a page of the animes produced by the studio, most
+    /// popular first.
+    ///
+    /// Unlike [`Studio::get_medias`], this doesn't load every media of
+    /// the studio at once, and the [`PageInfo`](super::PageInfo) of the
+    /// page tells how many there are in total.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number to get.
+    /// * `per_page` - The number of animes to get per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::Studio, Result};
+    /// #
+    /// # async fn f(studio: Studio) -> Result<()> {
+    /// let animes = studio.get_animes(1, 10).await?;
+    /// let total = animes.page_info.total.unwrap_or_default();
+    ///
+    /// println!("{} productions", total);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "client")]
+    pub async fn get_animes(&self, page: u16, per_page: u16) -> Result<Page<Anime>> {
+        self.client.get_studio_animes(self.id, page, per_page).await
+    }
+}
+
+/// Represents the role of a studio in a media.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum StudioRole {
+    /// The studio animated the media: it is its main studio.
+    Animation,
+    /// The studio took part in the production of the media without
+    /// animating it.
+    Production,
+}
+
+/// Parses the medias of a media connection of a studio.
+///
+/// Both the edges, which tell the role of the studio, and the nodes are
+/// supported. Without the edges, the medias are only returned when no
+/// role is asked for.
+///
+/// # Arguments
+///
+/// * `medias` - The media connection.
+/// * `role` - The role the studio must have had in the medias, if any.
+#[cfg(feature = "client")]
+fn parse_medias<T: DeserializeOwned>(medias: &Value, role: Option<StudioRole>) -> Result<Vec<T>> {
+    let nodes: Vec<&Value> = match (medias["edges"].as_array(), role) {
+        (Some(edges), _) => edges
+            .iter()
+            .filter(|edge| {
+                role.is_none_or(|role| {
+                    edge["isMainStudio"].as_bool() == Some(role == StudioRole::Animation)
+                })
+            })
+            .map(|edge| &edge["node"])
+            .collect(),
+        (None, None) => medias["nodes"].as_array().into_iter().flatten().collect(),
+        (None, Some(_)) => Vec::new(),
+    };
+
+    nodes
+        .into_iter()
+        .map(|media| Ok(serde_json::from_value(media.clone())?))
+        .collect()
+}
+
+/// Lowercases a studio name and strips everything but letters and
+/// digits from it.
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+impl std::fmt::Display for Studio {
+    /// Writes the name of the studio.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
     }
 }
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 
 /// Represents a tag in the system.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(default, rename_all = "camelCase")]
 pub struct Tag {
     /// The ID of the tag.
     pub id: i64,
@@ -1,13 +1,15 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
 
-//! This module contains the `Title` struct.
+//! This module contains the `Title` struct and the `TitleLanguage` enum.
 
 use serde::{Deserialize, Serialize};
 
+use super::name::{dice, normalize};
+
 /// Represents a title with various language options.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "lowercase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Title {
     /// The title in Romaji (Latin script).
     romaji: Option<String>,
@@ -17,9 +19,28 @@ pub struct Title {
     native: String,
     /// The title preferred by the user.
     user_preferred: Option<String>,
+
+    /// The language set on the client that fetched the title, if any.
+    #[serde(skip)]
+    pub(crate) language: Option<TitleLanguage>,
 }
 
 impl Title {
+    /// Creates a title holding the same text in Romaji and in the native
+    /// language, for [`Anime::for_test`](super::Anime::for_test).
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The text of the title.
+    #[cfg(feature = "test-util")]
+    pub(crate) fn for_test(title: &str) -> Self {
+        Self {
+            romaji: Some(title.to_string()),
+            native: title.to_string(),
+            ..Default::default()
+        }
+    }
+
     /// Returns the title in Romaji (Latin script).
     pub fn romaji(&self) -> &str {
         self.romaji.as_deref().unwrap_or(&self.native)
@@ -40,6 +61,46 @@ impl Title {
         self.user_preferred.as_deref().unwrap_or(&self.native)
     }
 
+    /// Returns the title in the given language.
+    ///
+    /// Falls back to the native title if the title is unavailable in
+    /// that language.
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The language of the title.
+    pub fn get(&self, language: TitleLanguage) -> &str {
+        match language {
+            TitleLanguage::Romaji => self.romaji(),
+            TitleLanguage::English => self.english(),
+            TitleLanguage::Native => self.native(),
+        }
+    }
+
+    /// Returns the title in the language set on the client.
+    ///
+    /// If the client that fetched the title has no title language, see
+    /// [`ClientBuilder::title_language`], the title preferred by the
+    /// authenticated user is returned instead.
+    ///
+    /// [`ClientBuilder::title_language`]: crate::ClientBuilder::title_language
+    pub fn preferred(&self) -> &str {
+        match self.language {
+            Some(language) => self.get(language),
+            None => self.user_preferred(),
+        }
+    }
+
+    /// Sets the language returned by [`Title::preferred`].
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The language set on the client, if any.
+    pub(crate) fn with_language(mut self, language: Option<TitleLanguage>) -> Self {
+        self.language = language;
+        self
+    }
+
     /// Checks if the title is empty.
     ///
     /// A title is considered empty if all of its fields are either `None` or empty.
@@ -57,6 +118,59 @@ impl Title {
     }
 }
 
+/// Represents the language a title is displayed in.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TitleLanguage {
+    /// The title in Romaji (Latin script).
+    Romaji,
+    /// The title in English.
+    English,
+    /// The title in the native language.
+    Native,
+}
+
+impl std::str::FromStr for TitleLanguage {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "romaji" => Ok(TitleLanguage::Romaji),
+            "english" => Ok(TitleLanguage::English),
+            "native" => Ok(TitleLanguage::Native),
+            _ => Err(format!("unknown title language: `{s}`")),
+        }
+    }
+}
+
+/// Returns how closely a title or one of its synonyms matches a query,
+/// from `0.0` to `1.0`.
+///
+/// The texts are compared lowercased, without diacritics or punctuation
+/// and with `&` read as `and`. `1.0` means one of them is equal to the
+/// query once normalized.
+///
+/// # Arguments
+///
+/// * `title` - The title of the media.
+/// * `synonyms` - The synonyms of the media.
+/// * `query` - The search query.
+pub(super) fn title_similarity(title: &Title, synonyms: &[String], query: &str) -> f32 {
+    let words = |text: &str| normalize(&text.replace('&', " and ")).concat();
+    let query = words(query);
+    if query.is_empty() {
+        return 0.0;
+    }
+
+    [&title.romaji, &title.english, &title.user_preferred]
+        .into_iter()
+        .flatten()
+        .chain([&title.native])
+        .chain(synonyms)
+        .map(|text| dice(&words(text), &query))
+        .fold(0.0, f32::max)
+}
+
 impl From<Title> for String {
     fn from(title: Title) -> Self {
         title.native().to_string()
@@ -73,6 +187,30 @@ impl std::fmt::Display for Title {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_title_similarity() {
+        let title = Title {
+            romaji: Some("Shingeki no Kyojin".to_string()),
+            english: Some("Attack on Titan".to_string()),
+            native: "進撃の巨人".to_string(),
+            user_preferred: None,
+            language: None,
+        };
+        let synonyms = ["AoT".to_string(), "Kōdō & Seigi".to_string()];
+
+        assert_eq!(title_similarity(&title, &[], "shingeki no kyojin"), 1.0);
+        assert_eq!(title_similarity(&title, &[], "ATTACK ON TITAN!"), 1.0);
+        assert_eq!(title_similarity(&title, &[], "進撃の巨人"), 1.0);
+        assert_eq!(title_similarity(&title, &synonyms, "aot"), 1.0);
+        assert_eq!(title_similarity(&title, &synonyms, "Kodo and Seigi"), 1.0);
+        assert_eq!(title_similarity(&title, &[], ""), 0.0);
+
+        let close = title_similarity(&title, &[], "Shingeki no Kyojin S3 Part 2");
+        let far = title_similarity(&title, &[], "Cowboy Bebop");
+        assert!(close > 0.7 && close < 1.0);
+        assert!(far < close);
+    }
+
     #[test]
     fn test_romaji_with_romaji() {
         let title = Title {
@@ -80,6 +218,7 @@ mod tests {
             english: None,
             native: "Native Title".to_string(),
             user_preferred: None,
+            language: None,
         };
 
         assert_eq!(title.romaji(), "Romaji Title");
@@ -92,6 +231,7 @@ mod tests {
             english: None,
             native: "Native Title".to_string(),
             user_preferred: None,
+            language: None,
         };
 
         assert_eq!(title.romaji(), "Native Title");
@@ -104,6 +244,7 @@ mod tests {
             english: Some("English Title".to_string()),
             native: "Native Title".to_string(),
             user_preferred: None,
+            language: None,
         };
 
         assert_eq!(title.english(), "English Title");
@@ -116,6 +257,7 @@ mod tests {
             english: None,
             native: "Native Title".to_string(),
             user_preferred: None,
+            language: None,
         };
 
         assert_eq!(title.english(), "Native Title");
@@ -128,6 +270,7 @@ mod tests {
             english: None,
             native: "Native Title".to_string(),
             user_preferred: None,
+            language: None,
         };
 
         assert_eq!(title.native(), "Native Title");
@@ -140,6 +283,7 @@ mod tests {
             english: None,
             native: "Native Title".to_string(),
             user_preferred: Some("User Preferred Title".to_string()),
+            language: None,
         };
 
         assert_eq!(title.user_preferred(), "User Preferred Title");
@@ -152,6 +296,7 @@ mod tests {
             english: None,
             native: "Native Title".to_string(),
             user_preferred: None,
+            language: None,
         };
 
         assert_eq!(title.user_preferred(), "Native Title");
@@ -164,6 +309,7 @@ mod tests {
             english: None,
             native: String::new(),
             user_preferred: None,
+            language: None,
         };
 
         assert!(title.is_empty());
@@ -176,6 +322,7 @@ mod tests {
             english: None,
             native: String::new(),
             user_preferred: None,
+            language: None,
         };
 
         assert!(!title.is_empty());
@@ -188,6 +335,7 @@ mod tests {
             english: Some(String::from("English")),
             native: String::new(),
             user_preferred: None,
+            language: None,
         };
 
         assert!(!title.is_empty());
@@ -200,6 +348,7 @@ mod tests {
             english: None,
             native: String::from("Native"),
             user_preferred: None,
+            language: None,
         };
 
         assert!(!title.is_empty());
@@ -212,11 +361,60 @@ mod tests {
             english: None,
             native: String::new(),
             user_preferred: Some(String::from("User Preferred")),
+            language: None,
         };
 
         assert!(!title.is_empty());
     }
 
+    #[test]
+    fn test_get() {
+        let title = Title {
+            romaji: Some("Romaji Title".to_string()),
+            english: None,
+            native: "Native Title".to_string(),
+            user_preferred: None,
+            language: None,
+        };
+
+        assert_eq!(title.get(TitleLanguage::Romaji), "Romaji Title");
+        assert_eq!(title.get(TitleLanguage::English), "Native Title");
+        assert_eq!(title.get(TitleLanguage::Native), "Native Title");
+    }
+
+    #[test]
+    fn test_preferred_with_language() {
+        let title = Title {
+            romaji: Some("Romaji Title".to_string()),
+            english: Some("English Title".to_string()),
+            native: "Native Title".to_string(),
+            user_preferred: Some("User Preferred Title".to_string()),
+            language: Some(TitleLanguage::English),
+        };
+
+        assert_eq!(title.preferred(), "English Title");
+    }
+
+    #[test]
+    fn test_preferred_without_language() {
+        let title = Title {
+            romaji: Some("Romaji Title".to_string()),
+            english: None,
+            native: "Native Title".to_string(),
+            user_preferred: Some("User Preferred Title".to_string()),
+            language: None,
+        };
+
+        assert_eq!(title.preferred(), "User Preferred Title");
+    }
+
+    #[test]
+    fn test_title_language_from_str() {
+        assert_eq!("english".parse(), Ok(TitleLanguage::English));
+        assert_eq!("Romaji".parse(), Ok(TitleLanguage::Romaji));
+        assert!("french".parse::<TitleLanguage>().is_err());
+    }
+
     #[test]
     fn test_from_title_to_string() {
         let title = Title {
@@ -224,6 +422,7 @@ mod tests {
             english: None,
             native: "Native Title".to_string(),
             user_preferred: None,
+            language: None,
         };
         let title_string: String = title.into();
 
@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `Trailer` struct.
+
+use serde::{Deserialize, Serialize};
+
+/// Represents the trailer of a media.
+#[derive(Debug, Default, Clone, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub struct Trailer {
+    /// The ID of the trailer on its site.
+    pub id: String,
+    /// The site hosting the trailer, `youtube` or `dailymotion`.
+    pub site: String,
+    /// The URL of the thumbnail of the trailer, if any.
+    pub thumbnail: Option<String>,
+}
+
+impl Trailer {
+    /// Returns the URL of the trailer's watch page.
+    ///
+    /// For a site other than YouTube and Dailymotion, the ID is returned
+    /// as is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_anilist::models::Trailer;
+    /// let trailer = Trailer {
+    ///     id: "gY5nDXOtv_o".to_string(),
+    ///     site: "youtube".to_string(),
+    ///     thumbnail: None,
+    /// };
+    ///
+    /// assert_eq!(trailer.url(), "https://www.youtube.com/watch?v=gY5nDXOtv_o");
+    /// ```
+    pub fn url(&self) -> String {
+        match self.site.to_lowercase().as_str() {
+            "youtube" => format!("https://www.youtube.com/watch?v={}", self.id),
+            "dailymotion" => format!("https://www.dailymotion.com/video/{}", self.id),
+            _ => self.id.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trailer(id: &str, site: &str) -> Trailer {
+        Trailer {
+            id: id.to_string(),
+            site: site.to_string(),
+            thumbnail: None,
+        }
+    }
+
+    #[test]
+    fn test_url_youtube() {
+        assert_eq!(
+            trailer("gY5nDXOtv_o", "youtube").url(),
+            "https://www.youtube.com/watch?v=gY5nDXOtv_o"
+        );
+    }
+
+    #[test]
+    fn test_url_dailymotion() {
+        assert_eq!(
+            trailer("x2f0c6q", "dailymotion").url(),
+            "https://www.dailymotion.com/video/x2f0c6q"
+        );
+        assert_eq!(
+            trailer("x2f0c6q", "Dailymotion").url(),
+            "https://www.dailymotion.com/video/x2f0c6q"
+        );
+    }
+
+    #[test]
+    fn test_url_unknown_site() {
+        assert_eq!(trailer("abc", "vimeo").url(), "abc");
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let trailer: Trailer = serde_json::from_value(serde_json::json!({
+            "id": "gY5nDXOtv_o",
+            "site": "youtube",
+            "thumbnail": "https://i.ytimg.com/vi/gY5nDXOtv_o/hqdefault.jpg"
+        }))
+        .unwrap();
+
+        assert_eq!(trailer.site, "youtube");
+        assert!(trailer.thumbnail.is_some());
+    }
+}
@@ -3,12 +3,18 @@
 
 //! This module contains the `User` struct and its related types.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
+use super::debug::{ClientPlaceholder, Truncated};
 use super::{
-    Anime, Character, Color, Format, Image, Manga, NotificationOption, Person, Status, Studio,
+    Anime, Character, Color, Format, Genre, Image, Manga, NotificationOption, Person, ScoreFormat,
+    Status, Studio, Tag, UserId,
 };
-use crate::{Client, Result};
+use crate::Client;
+#[cfg(feature = "client")]
+use crate::Result;
 
 /// Represents a user with various attributes.
 ///
@@ -17,11 +23,11 @@ use crate::{Client, Result};
 /// status, favourites, follow status, media list options, site URL,
 /// statistics, notification count, and timestamps for creation and
 /// updates.
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[derive(Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct User {
     /// The ID of the user.
-    pub id: i32,
+    pub id: UserId,
     /// The name of the user.
     pub name: String,
     /// The about of the user.
@@ -62,12 +68,111 @@ pub struct User {
 
     /// The client used to fetch additional data.
     #[serde(skip)]
+    #[cfg_attr(not(feature = "client"), allow(dead_code))]
     pub(crate) client: Client,
     /// Whether the person's data is fully loaded.
     #[serde(default)]
     pub(crate) is_full_loaded: bool,
 }
 
+impl PartialEq for User {
+    /// Compares every field of the users but their client and whether they are
+    /// fully loaded.
+    fn eq(&self, other: &Self) -> bool {
+        let User {
+            id,
+            name,
+            about,
+            avatar,
+            banner,
+            donator_badge,
+            donator_tier,
+            favourites,
+            is_blocked,
+            is_follower,
+            is_following,
+            media_list_options,
+            options,
+            url,
+            statistics,
+            unread_notification_count,
+            created_at,
+            updated_at,
+            client: _,
+            is_full_loaded: _,
+        } = self;
+
+        *id == other.id
+            && *name == other.name
+            && *about == other.about
+            && *avatar == other.avatar
+            && *banner == other.banner
+            && *donator_badge == other.donator_badge
+            && *donator_tier == other.donator_tier
+            && *favourites == other.favourites
+            && *is_blocked == other.is_blocked
+            && *is_follower == other.is_follower
+            && *is_following == other.is_following
+            && *media_list_options == other.media_list_options
+            && *options == other.options
+            && *url == other.url
+            && *statistics == other.statistics
+            && *unread_notification_count == other.unread_notification_count
+            && *created_at == other.created_at
+            && *updated_at == other.updated_at
+    }
+}
+
+impl std::fmt::Debug for User {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let User {
+            id,
+            name,
+            about,
+            avatar,
+            banner,
+            donator_badge,
+            donator_tier,
+            favourites,
+            is_blocked,
+            is_follower,
+            is_following,
+            media_list_options,
+            options,
+            url,
+            statistics,
+            unread_notification_count,
+            created_at,
+            updated_at,
+            client: _,
+            is_full_loaded,
+        } = self;
+
+        f.debug_struct("User")
+            .field("id", id)
+            .field("name", name)
+            .field("about", &about.as_deref().map(Truncated))
+            .field("avatar", avatar)
+            .field("banner", banner)
+            .field("donator_badge", donator_badge)
+            .field("donator_tier", donator_tier)
+            .field("favourites", favourites)
+            .field("is_blocked", is_blocked)
+            .field("is_follower", is_follower)
+            .field("is_following", is_following)
+            .field("media_list_options", media_list_options)
+            .field("options", options)
+            .field("url", url)
+            .field("statistics", statistics)
+            .field("unread_notification_count", unread_notification_count)
+            .field("created_at", created_at)
+            .field("updated_at", updated_at)
+            .field("client", &ClientPlaceholder)
+            .field("is_full_loaded", is_full_loaded)
+            .finish()
+    }
+}
+
 impl User {
     /// Loads the full details of the user.
     ///
@@ -89,6 +194,7 @@ impl User {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "client")]
     pub async fn load_full(self) -> Result<Self> {
         if !self.is_full_loaded {
             self.client.get_user(self.id).await
@@ -96,11 +202,75 @@ impl User {
             panic!("This user is already full loaded")
         }
     }
+
+    /// Detaches the user from its client.
+    ///
+    /// A detached user keeps its data, but the methods sending
+    /// requests, such as [`User::load_full`], fail with
+    /// [`Error::Detached`](crate::Error::Detached) until a client is
+    /// attached with [`User::attach`]. The nested models are detached
+    /// as well.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rust_anilist::{models::User, Client, Result};
+    /// #
+    /// # async fn f(user: User, client: Client) -> Result<()> {
+    /// let cached = user.detach();
+    /// // ...
+    /// let user = cached.attach(&client).load_full().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "client")]
+    pub fn detach(self) -> Self {
+        self.attach(&Client::detached())
+    }
+
+    /// Attaches the user to a client, which sends its requests from now on.
+    ///
+    /// The nested models are attached as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to attach.
+    #[cfg(feature = "client")]
+    pub fn attach(mut self, client: &Client) -> Self {
+        self.set_client(client);
+        self
+    }
+
+    /// Gives the client to the user and its nested models.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to give.
+    #[cfg(feature = "client")]
+    pub(crate) fn set_client(&mut self, client: &Client) {
+        self.client = client.clone();
+        let favourites = &mut self.favourites;
+        for anime in &mut favourites.anime {
+            anime.set_client(client);
+        }
+        for manga in &mut favourites.manga {
+            manga.set_client(client);
+        }
+        for character in &mut favourites.characters {
+            character.set_client(client);
+        }
+        for person in &mut favourites.staff {
+            person.set_client(client);
+        }
+        for studio in &mut favourites.studios {
+            studio.set_client(client);
+        }
+    }
 }
 
 /// The options of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct Options {
     /// The title language of the user.
     pub title_language: Option<UserTitleLanguage>,
@@ -131,7 +301,7 @@ pub struct Options {
 
 /// The title language of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "SCREAMING_SNAKE_CASE"))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum UserTitleLanguage {
     /// The Romaji title language.
     #[default]
@@ -146,11 +316,14 @@ pub enum UserTitleLanguage {
     EnglishStylised,
     /// The native stylised title language.
     NativeStylised,
+    /// A value unknown to this version of the crate, as sent by AniList.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The staff name language of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "SCREAMING_SNAKE_CASE"))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum UserStaffNameLanguage {
     /// The Romaji Western staff name language.
     RomajiWestern,
@@ -159,11 +332,14 @@ pub enum UserStaffNameLanguage {
     Romaji,
     /// The native staff name language.
     Native,
+    /// A value unknown to this version of the crate, as sent by AniList.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The list activity option of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct ListActivityOption {
     /// The status of the list activity.
     pub status: Status,
@@ -173,8 +349,10 @@ pub struct ListActivityOption {
 
 /// The media list options of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct MediaListOptions {
+    /// The score format of the media list options.
+    pub score_format: Option<ScoreFormat>,
     /// The row order of the media list options.
     pub row_order: String,
     /// The anime list of the media list options.
@@ -185,7 +363,7 @@ pub struct MediaListOptions {
 
 /// The media list type options of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct MediaListTypeOptions {
     /// The section order of the media list type options.
     pub section_order: Vec<String>,
@@ -216,7 +394,7 @@ pub struct Favourites {
 
 /// The statistics of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct UserStatisticTypes {
     /// The anime statistics of the user.
     pub anime: UserStatistics,
@@ -226,7 +404,7 @@ pub struct UserStatisticTypes {
 
 /// The statistics of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct UserStatistics {
     /// The count of the statistics.
     pub count: i32,
@@ -244,11 +422,75 @@ pub struct UserStatistics {
     pub formats: Option<Vec<UserFormatStatistic>>,
     /// The statuses of the statistics.
     pub statuses: Vec<UserStatusStatistic>,
+    /// The mean score of the statistics.
+    pub mean_score: Option<f32>,
+    /// The genres of the statistics.
+    #[serde(default)]
+    pub genres: Vec<UserGenreStatistic>,
+    /// The tags of the statistics.
+    #[serde(default)]
+    pub tags: Vec<UserTagStatistic>,
+    /// The release years of the statistics.
+    #[serde(default)]
+    pub release_years: Vec<UserReleaseYearStatistic>,
+}
+
+impl UserStatistics {
+    /// Returns the `n` genres with the most entries, most frequent
+    /// first.
+    ///
+    /// Genres with the same count keep the order returned by AniList.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The maximum number of genres to return.
+    pub fn top_genres(&self, n: usize) -> Vec<&UserGenreStatistic> {
+        let mut genres = self.genres.iter().collect::<Vec<_>>();
+        genres.sort_by_key(|genre| std::cmp::Reverse(genre.count));
+        genres.truncate(n);
+
+        genres
+    }
+
+    /// Returns the `n` tags with the most entries, most frequent first.
+    ///
+    /// Tags with the same count keep the order returned by AniList.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The maximum number of tags to return.
+    pub fn top_tags(&self, n: usize) -> Vec<&UserTagStatistic> {
+        let mut tags = self.tags.iter().collect::<Vec<_>>();
+        tags.sort_by_key(|tag| std::cmp::Reverse(tag.count));
+        tags.truncate(n);
+
+        tags
+    }
+
+    /// Returns the mean score of the entries released in each year.
+    ///
+    /// Years without a mean score, i.e. with no scored entries, are
+    /// left out.
+    pub fn mean_score_by_year(&self) -> BTreeMap<i32, f32> {
+        self.release_years
+            .iter()
+            .filter_map(|year| Some((year.release_year?, year.mean_score?)))
+            .collect()
+    }
+
+    /// Returns the total time spent watching, in days.
+    ///
+    /// Returns `None` when the minutes watched are unknown, as is the
+    /// case for manga statistics.
+    pub fn total_days_watched(&self) -> Option<f64> {
+        self.minutes_watched
+            .map(|minutes| f64::from(minutes) / (24.0 * 60.0))
+    }
 }
 
 /// The format statistics of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct UserFormatStatistic {
     /// The count of the format statistics.
     pub count: i32,
@@ -263,9 +505,57 @@ pub struct UserFormatStatistic {
     pub format: Format,
 }
 
+/// The genre statistics of a user.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserGenreStatistic {
+    /// The count of the genre statistics.
+    pub count: i32,
+    /// The mean score of the genre statistics.
+    pub mean_score: Option<f32>,
+    /// The minutes watched of the genre statistics.
+    pub minutes_watched: Option<i32>,
+    /// The chapters read of the genre statistics.
+    pub chapters_read: Option<i32>,
+    /// The genre of the genre statistics.
+    pub genre: Genre,
+}
+
+/// The tag statistics of a user.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTagStatistic {
+    /// The count of the tag statistics.
+    pub count: i32,
+    /// The mean score of the tag statistics.
+    pub mean_score: Option<f32>,
+    /// The minutes watched of the tag statistics.
+    pub minutes_watched: Option<i32>,
+    /// The chapters read of the tag statistics.
+    pub chapters_read: Option<i32>,
+    /// The tag of the tag statistics.
+    pub tag: Tag,
+}
+
+/// The release year statistics of a user.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserReleaseYearStatistic {
+    /// The count of the release year statistics.
+    pub count: i32,
+    /// The mean score of the release year statistics.
+    pub mean_score: Option<f32>,
+    /// The minutes watched of the release year statistics.
+    pub minutes_watched: Option<i32>,
+    /// The chapters read of the release year statistics.
+    pub chapters_read: Option<i32>,
+    /// The release year of the release year statistics.
+    pub release_year: Option<i32>,
+}
+
 /// The status statistics of a user.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
+#[serde(rename_all = "camelCase")]
 pub struct UserStatusStatistic {
     /// The count of the status statistics.
     pub count: i32,
@@ -280,3 +570,72 @@ pub struct UserStatusStatistic {
     /// The status of the status statistics.
     pub status: Status,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statistics() -> UserStatistics {
+        serde_json::from_value(serde_json::json!({
+            "count": 3,
+            "minutesWatched": 4320,
+            "statuses": [],
+            "genres": [
+                { "genre": "Drama", "count": 2 },
+                { "genre": "Action", "count": 5 },
+                { "genre": "Comedy", "count": 2 }
+            ],
+            "tags": [
+                { "tag": { "name": "Space" }, "count": 1 },
+                { "tag": { "name": "Male Protagonist" }, "count": 4 }
+            ],
+            "releaseYears": [
+                { "releaseYear": 2001, "count": 1, "meanScore": 70.0 },
+                { "releaseYear": 1998, "count": 2, "meanScore": 85.5 },
+                { "releaseYear": 2010, "count": 1 }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_top_genres() {
+        let statistics = statistics();
+        let genres = statistics
+            .top_genres(2)
+            .into_iter()
+            .map(|genre| &genre.genre)
+            .collect::<Vec<_>>();
+
+        assert_eq!(genres, [&Genre::Action, &Genre::Drama]);
+        assert_eq!(statistics.top_genres(10).len(), 3);
+    }
+
+    #[test]
+    fn test_top_tags() {
+        let statistics = statistics();
+        let tags = statistics
+            .top_tags(1)
+            .into_iter()
+            .map(|tag| tag.tag.name.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(tags, ["Male Protagonist"]);
+    }
+
+    #[test]
+    fn test_mean_score_by_year() {
+        let years = statistics().mean_score_by_year();
+
+        assert_eq!(
+            years.into_iter().collect::<Vec<_>>(),
+            [(1998, 85.5), (2001, 70.0)]
+        );
+    }
+
+    #[test]
+    fn test_total_days_watched() {
+        assert_eq!(statistics().total_days_watched(), Some(3.0));
+        assert_eq!(UserStatistics::default().total_days_watched(), None);
+    }
+}
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module re-exports the most used types of the crate.
+//!
+//! Their usual paths keep working; the prelude only saves listing them.
+//! `Error` and `Result` are left out so that a glob import doesn't shadow
+//! the ones of the standard library.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use rust_anilist::prelude::*;
+//!
+//! # async fn f() -> rust_anilist::Result<()> {
+//! let client = Client::default();
+//! let anime: Anime = client.get_anime(1).await?;
+//!
+//! if anime.status == Status::Finished && anime.format == Format::Tv {
+//!     println!("{}", anime.title.romaji());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(feature = "client")]
+pub use crate::{Client, ClientBuilder};
+
+pub use crate::models::{
+    Anime, AnimeId, Character, CharacterId, CharacterRole, Format, Gender, Genre, Language, Manga,
+    MangaId, Media, MediaListStatus, MediaSearchOptions, MediaSort, MediaType, Page, Person,
+    PersonId, Season, Source, Status, Studio, StudioId, Title, TitleLanguage, User, UserId,
+};
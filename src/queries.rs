@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the GraphQL documents sent by the client.
+//!
+//! They can be pasted into the AniList GraphQL explorer to replicate a
+//! call and check the fields it selects, or adjusted and sent with
+//! `Client::query_raw`.
+//!
+//! # Example
+//!
+//! ```
+//! println!("{}", rust_anilist::queries::GET_ANIME);
+//! ```
+
+/// Counts the medias matching the search filters, used by `Client::count_media`.
+pub const COUNT_MEDIA: &str = include_str!("../queries/count_media.graphql");
+
+/// Gets a page of the episodes airing in a time window, used by `Client::get_airing_calendar`.
+pub const GET_AIRING_CALENDAR: &str = include_str!("../queries/get_airing_calendar.graphql");
+
+/// Gets a page of the airing schedule of an anime, used by `Anime::get_airing_schedule`.
+pub const GET_AIRING_SCHEDULE: &str = include_str!("../queries/get_airing_schedule.graphql");
+
+/// Gets an anime by its ID, used by `Client::get_anime`.
+pub const GET_ANIME: &str = include_str!("../queries/get_anime.graphql");
+
+/// Gets a page of the characters of an anime, used by `Client::get_characters_of_anime`.
+pub const GET_ANIME_CHARACTERS: &str = include_str!("../queries/get_anime_characters.graphql");
+
+/// Gets a page of the staff of an anime, used by `Client::get_staff_of_anime`.
+pub const GET_ANIME_STAFF: &str = include_str!("../queries/get_anime_staff.graphql");
+
+/// Gets a character by its ID, used by `Client::get_character`.
+pub const GET_CHARACTER: &str = include_str!("../queries/get_character.graphql");
+
+/// Gets a page of the medias of a character, used by `Client::get_character_medias`.
+pub const GET_CHARACTER_MEDIAS: &str = include_str!("../queries/get_character_medias.graphql");
+
+/// Gets a chunk of the media IDs in the list of a user, used by `Client::get_airing_calendar`.
+pub const GET_LIST_MEDIA_IDS: &str = include_str!("../queries/get_list_media_ids.graphql");
+
+/// Gets a manga by its ID, used by `Client::get_manga`.
+pub const GET_MANGA: &str = include_str!("../queries/get_manga.graphql");
+
+/// Gets a chunk of the list of a user, used by `Client::get_media_list_collection`.
+pub const GET_MEDIA_LIST_COLLECTION: &str =
+    include_str!("../queries/get_media_list_collection.graphql");
+
+/// Gets the list entries of a user for some medias, used by `Client::get_media_list_entries`.
+pub const GET_MEDIA_LIST_ENTRIES: &str = include_str!("../queries/get_media_list_entries.graphql");
+
+/// Gets a page of the daily trends of a media, used by `Anime::get_trends`.
+pub const GET_MEDIA_TRENDS: &str = include_str!("../queries/get_media_trends.graphql");
+
+/// Gets the medias with the given MyAnimeList IDs, used by `Client::import_entries`.
+pub const GET_MEDIAS_BY_MAL_ID: &str = include_str!("../queries/get_medias_by_mal_id.graphql");
+
+/// Gets a page of the notifications of the authenticated user, used by `Client::get_notifications`.
+pub const GET_NOTIFICATIONS: &str = include_str!("../queries/get_notifications.graphql");
+
+/// Gets a person by their ID, used by `Client::get_person`.
+pub const GET_PERSON: &str = include_str!("../queries/get_person.graphql");
+
+/// Gets a page of the characters voiced by a person, used by `Client::get_person_characters`.
+pub const GET_PERSON_CHARACTERS: &str = include_str!("../queries/get_person_characters.graphql");
+
+/// Gets a studio by its ID, used by `Client::get_studio`.
+pub const GET_STUDIO: &str = include_str!("../queries/get_studio.graphql");
+
+/// Gets a page of the animes of a studio, used by `Client::get_studio_animes`.
+pub const GET_STUDIO_ANIMES: &str = include_str!("../queries/get_studio_animes.graphql");
+
+/// Gets a page of the characters with the most favourites, used by `Client::get_top_characters`.
+pub const GET_TOP_CHARACTERS: &str = include_str!("../queries/get_top_characters.graphql");
+
+/// Gets a page of the staff with the most favourites, used by `Client::get_top_staff`.
+pub const GET_TOP_STAFF: &str = include_str!("../queries/get_top_staff.graphql");
+
+/// Gets a user by their ID or name, used by `Client::get_user`.
+pub const GET_USER: &str = include_str!("../queries/get_user.graphql");
+
+/// Gets a page of each favourites connection of a user, used by `Client::get_user_favourites`.
+pub const GET_USER_FAVOURITES: &str = include_str!("../queries/get_user_favourites.graphql");
+
+/// Gets the ID and name of the authenticated user, used by `Client::get_airing_calendar`.
+pub const GET_VIEWER: &str = include_str!("../queries/get_viewer.graphql");
+
+/// The `preview` fragment selected for every media by `Client::get_media_previews`, which aliases one `Media` field per ID.
+pub const MEDIA_PREVIEW: &str = include_str!("../queries/get_media_previews.graphql");
+
+/// Creates or updates a list entry of the authenticated user, used by `Client::import_entries`.
+pub const SAVE_MEDIA_LIST_ENTRY: &str = include_str!("../queries/save_media_list_entry.graphql");
+
+/// Posts a message to the profile of a user, used by `Client::post_message_activity`.
+pub const SAVE_MESSAGE_ACTIVITY: &str = include_str!("../queries/save_message_activity.graphql");
+
+/// Posts a text activity, used by `Client::post_text_activity`.
+pub const SAVE_TEXT_ACTIVITY: &str = include_str!("../queries/save_text_activity.graphql");
+
+/// Searches animes, mangas, characters, staff and studios at once, used by `Client::search_all`.
+pub const SEARCH_ALL: &str = include_str!("../queries/search_all.graphql");
+
+/// Searches animes by title or genres, used by `Client::search_anime`.
+pub const SEARCH_ANIME: &str = include_str!("../queries/search_anime.graphql");
+
+/// Searches mangas by title or genres, used by `Client::search_manga`.
+pub const SEARCH_MANGA: &str = include_str!("../queries/search_manga.graphql");
+
+/// Searches studios by name, used by `Client::search_studio`.
+pub const SEARCH_STUDIO: &str = include_str!("../queries/search_studio.graphql");
+
+/// Searches users by name, used by `Client::search_user`.
+pub const SEARCH_USER: &str = include_str!("../queries/search_user.graphql");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the first line of a document that isn't a comment.
+    fn definition(document: &str) -> &str {
+        document
+            .lines()
+            .find(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_operation_names() {
+        assert!(definition(GET_ANIME).starts_with("query GetAnime"));
+        assert!(definition(GET_MANGA).starts_with("query GetManga("));
+        assert!(definition(SEARCH_ANIME).starts_with("query SearchAnime("));
+        assert!(definition(SAVE_TEXT_ACTIVITY).starts_with("mutation SaveTextActivity("));
+        assert!(definition(MEDIA_PREVIEW).starts_with("fragment preview on Media"));
+    }
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022-2025 Andriel Ferreira <https://github.com/AndrielFR>
+
+//! This module contains the `parse_url` function.
+
+use reqwest::Url;
+
+use crate::{models::MediaType, Error, Result};
+
+/// Extracts the type and the ID of the entity an AniList URL points to.
+///
+/// Anime, manga, character, staff and studio pages are supported, with
+/// or without the scheme and the trailing slug. Staff pages point to a
+/// [`MediaType::Person`]. This doesn't send any request.
+///
+/// # Arguments
+///
+/// * `url` - The URL of the page on anilist.co.
+///
+/// # Errors
+///
+/// Returns an error if the URL is not an anilist.co URL of a supported
+/// page or holds no valid ID.
+///
+/// # Example
+///
+/// ```
+/// # use rust_anilist::{models::MediaType, parse_url};
+/// let (media_type, id) = parse_url("https://anilist.co/anime/1/Cowboy-Bebop/").unwrap();
+///
+/// assert_eq!(media_type, MediaType::Anime);
+/// assert_eq!(id, 1);
+/// ```
+pub fn parse_url(url: &str) -> Result<(MediaType, i64)> {
+    let url = url.trim();
+    let invalid = || Error::InvalidUrl(url.to_string());
+
+    let parsed = match Url::parse(url) {
+        Ok(parsed) => parsed,
+        Err(_) => Url::parse(&format!("https://{url}")).map_err(|_| invalid())?,
+    };
+
+    let host = parsed.host_str().unwrap_or_default();
+    if !matches!(parsed.scheme(), "http" | "https")
+        || !matches!(host, "anilist.co" | "www.anilist.co")
+    {
+        return Err(invalid());
+    }
+
+    let mut segments = parsed.path_segments().ok_or_else(invalid)?;
+    let media_type = match segments.next() {
+        Some("anime") => MediaType::Anime,
+        Some("manga") => MediaType::Manga,
+        Some("character") => MediaType::Character,
+        Some("staff") => MediaType::Person,
+        Some("studio") => MediaType::Studio,
+        _ => return Err(invalid()),
+    };
+    let id = segments
+        .next()
+        .and_then(|id| id.parse::<i64>().ok())
+        .filter(|id| *id > 0)
+        .ok_or(Error::InvalidId)?;
+
+    Ok((media_type, id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_url() {
+        let cases = [
+            (
+                "https://anilist.co/anime/1/Cowboy-Bebop/",
+                MediaType::Anime,
+                1,
+            ),
+            ("https://anilist.co/manga/30013", MediaType::Manga, 30013),
+            (
+                "http://www.anilist.co/character/1/",
+                MediaType::Character,
+                1,
+            ),
+            (
+                "anilist.co/staff/95061/Shinichirou-Watanabe",
+                MediaType::Person,
+                95061,
+            ),
+            (
+                "https://anilist.co/studio/14/Sunrise?sort=START_DATE",
+                MediaType::Studio,
+                14,
+            ),
+        ];
+
+        for (url, media_type, id) in cases {
+            assert_eq!(parse_url(url).unwrap(), (media_type, id), "{url}");
+        }
+    }
+
+    #[test]
+    fn test_parse_url_invalid() {
+        assert!(matches!(
+            parse_url("https://myanimelist.net/anime/1"),
+            Err(Error::InvalidUrl(_))
+        ));
+        assert!(matches!(
+            parse_url("https://anilist.co/user/andrielfr"),
+            Err(Error::InvalidUrl(_))
+        ));
+        assert!(matches!(
+            parse_url("ftp://anilist.co/anime/1"),
+            Err(Error::InvalidUrl(_))
+        ));
+        assert!(matches!(
+            parse_url("https://anilist.co/anime/bebop"),
+            Err(Error::InvalidId)
+        ));
+        assert!(matches!(
+            parse_url("https://anilist.co/anime"),
+            Err(Error::InvalidId)
+        ));
+    }
+}
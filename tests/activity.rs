@@ -0,0 +1,86 @@
+use rust_anilist::models::ActivityType;
+use rust_anilist::{Client, Error, MockTransport};
+
+#[tokio::test]
+async fn post_text_activity_returns_the_created_activity() {
+    let mock = MockTransport::new().with_response(
+        "SaveTextActivity",
+        serde_json::json!({
+            "data": {
+                "SaveTextActivity": {
+                    "id": 42,
+                    "type": "TEXT",
+                    "text": "Hello, AniList!",
+                    "userId": 7,
+                    "replyCount": 0,
+                    "likeCount": 0,
+                    "siteUrl": "https://anilist.co/activity/42",
+                    "createdAt": 1_700_000_000
+                }
+            }
+        }),
+    );
+    let client = Client::builder()
+        .transport(mock.clone())
+        .token("token")
+        .build();
+
+    let activity = client.post_text_activity("Hello, AniList!").await.unwrap();
+
+    assert_eq!(activity.id, 42);
+    assert_eq!(activity.activity_type, Some(ActivityType::Text));
+    assert_eq!(activity.url, "https://anilist.co/activity/42");
+    assert_eq!(mock.requests()[0]["variables"]["text"], "Hello, AniList!");
+}
+
+#[tokio::test]
+async fn post_message_activity_sends_the_recipient() {
+    let mock = MockTransport::new().with_response(
+        "SaveMessageActivity",
+        serde_json::json!({
+            "data": {
+                "SaveMessageActivity": {
+                    "id": 43,
+                    "type": "MESSAGE",
+                    "text": "Hi there!",
+                    "messengerId": 7,
+                    "recipientId": 8,
+                    "isPrivate": true,
+                    "replyCount": 0,
+                    "likeCount": 0,
+                    "siteUrl": "https://anilist.co/activity/43",
+                    "createdAt": 1_700_000_100
+                }
+            }
+        }),
+    );
+    let client = Client::builder()
+        .transport(mock.clone())
+        .token("token")
+        .build();
+
+    let activity = client
+        .post_message_activity(8, "Hi there!", true)
+        .await
+        .unwrap();
+
+    assert_eq!(activity.recipient_id.unwrap(), 8);
+    assert_eq!(activity.text.as_deref(), Some("Hi there!"));
+
+    let variables = &mock.requests()[0]["variables"];
+    assert_eq!(variables["recipientId"], 8);
+    assert_eq!(variables["private"], true);
+}
+
+#[tokio::test]
+async fn posting_requires_a_token() {
+    let mock = MockTransport::new();
+    let client = Client::with_transport(mock.clone());
+
+    let text = client.post_text_activity("Hello").await;
+    let message = client.post_message_activity(8, "Hi", false).await;
+
+    assert!(matches!(text, Err(Error::MissingToken)));
+    assert!(matches!(message, Err(Error::MissingToken)));
+    assert!(mock.requests().is_empty());
+}
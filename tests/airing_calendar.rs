@@ -0,0 +1,206 @@
+use std::sync::{Arc, Mutex};
+
+use chrono::{FixedOffset, NaiveDate};
+use rust_anilist::{Client, Error, MockTransport, Transport, TransportFuture};
+
+/// 2023-11-14 22:13:20 UTC.
+const START: i64 = 1_700_000_000;
+
+/// A transport answering with two pages of airing episodes, and with a
+/// viewer whose list holds the animes 1 and 2.
+#[derive(Clone, Default)]
+struct Schedules {
+    requests: Arc<Mutex<Vec<serde_json::Value>>>,
+}
+
+fn schedule(id: i64, at: i64, media_id: i64) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "airingAt": at,
+        "timeUntilAiring": at - START,
+        "episode": 3,
+        "media": {
+            "id": media_id,
+            "idMal": null,
+            "title": { "romaji": format!("Anime {media_id}"), "native": "" },
+            "format": "TV",
+            "status": "RELEASING",
+            "description": null,
+            "coverImage": {},
+            "bannerImage": null,
+            "averageScore": null,
+            "meanScore": null,
+            "isAdult": false,
+            "siteUrl": format!("https://anilist.co/anime/{media_id}")
+        }
+    })
+}
+
+impl Transport for Schedules {
+    fn execute(&self, body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            self.requests.lock().unwrap().push(body.clone());
+            let query = body["query"].as_str().unwrap();
+            let variables = &body["variables"];
+
+            let data = if query.contains("GetViewer") {
+                serde_json::json!({ "Viewer": { "id": 7, "name": "viewer" } })
+            } else if query.contains("GetListMediaIds") {
+                serde_json::json!({
+                    "MediaListCollection": {
+                        "hasNextChunk": false,
+                        "lists": [
+                            { "entries": [{ "mediaId": 2 }, { "mediaId": 1 }] },
+                            { "entries": [{ "mediaId": 1 }] }
+                        ]
+                    }
+                })
+            } else if variables["page"] == 1 {
+                serde_json::json!({
+                    "Page": {
+                        "pageInfo": { "hasNextPage": true },
+                        "airingSchedules": [schedule(10, START + 600, 1), schedule(11, START + 3600, 2)]
+                    }
+                })
+            } else {
+                serde_json::json!({
+                    "Page": {
+                        "pageInfo": { "hasNextPage": false },
+                        "airingSchedules": [schedule(12, START + 86_400, 1)]
+                    }
+                })
+            };
+
+            Ok(serde_json::json!({ "data": data }))
+        })
+    }
+}
+
+fn day(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2023, 11, day).unwrap()
+}
+
+#[tokio::test]
+async fn episodes_are_grouped_by_local_day() {
+    let transport = Schedules::default();
+    let client = Client::with_transport(transport.clone());
+
+    let utc = FixedOffset::east_opt(0).unwrap();
+    let calendar = client
+        .get_airing_calendar(START, 2, utc, false)
+        .await
+        .unwrap();
+
+    // 22:23 and 23:13 UTC on the 14th, then 22:13 UTC on the 15th.
+    assert_eq!(
+        calendar.keys().copied().collect::<Vec<_>>(),
+        [day(14), day(15)]
+    );
+    assert_eq!(calendar[&day(14)].len(), 2);
+    assert_eq!(calendar[&day(14)][1].anime.title.romaji(), "Anime 2");
+    assert_eq!(calendar[&day(15)][0].schedule.id, 12);
+
+    let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+    let calendar = client
+        .get_airing_calendar(START, 2, tokyo, false)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        calendar.keys().copied().collect::<Vec<_>>(),
+        [day(15), day(16)]
+    );
+
+    let requests = transport.requests.lock().unwrap();
+    let variables = &requests[0]["variables"];
+    assert_eq!(variables["start"], START - 1);
+    assert_eq!(variables["end"], START + 2 * 86_400);
+    assert!(variables.get("mediaIds").is_none());
+}
+
+#[tokio::test]
+async fn only_list_passes_the_viewer_media_ids() {
+    let transport = Schedules::default();
+    let client = Client::builder()
+        .transport(transport.clone())
+        .token("token")
+        .build();
+
+    let utc = FixedOffset::east_opt(0).unwrap();
+    client
+        .get_airing_calendar(START, 7, utc, true)
+        .await
+        .unwrap();
+
+    let requests = transport.requests.lock().unwrap();
+    assert_eq!(requests[1]["variables"]["userId"], 7);
+    assert_eq!(
+        requests[2]["variables"]["mediaIds"],
+        serde_json::json!([1, 2])
+    );
+}
+
+#[tokio::test]
+async fn only_list_requires_a_token() {
+    let client = Client::with_transport(Schedules::default());
+
+    let utc = FixedOffset::east_opt(0).unwrap();
+    let calendar = client.get_airing_calendar(START, 7, utc, true).await;
+
+    assert!(matches!(calendar, Err(Error::MissingToken)));
+}
+
+#[tokio::test]
+async fn missing_anime_fields_are_left_to_their_default() {
+    let mut episode = schedule(10, START + 600, 1);
+    episode["media"]["format"] = serde_json::Value::Null;
+    episode["media"]["coverImage"] = serde_json::Value::Null;
+    episode["media"]["isAdult"] = serde_json::Value::Null;
+    let mock = MockTransport::new().with_response(
+        "GetAiringCalendar",
+        serde_json::json!({
+            "data": {
+                "Page": { "pageInfo": { "hasNextPage": false }, "airingSchedules": [episode] }
+            }
+        }),
+    );
+    let client = Client::with_transport(mock);
+
+    let utc = FixedOffset::east_opt(0).unwrap();
+    let calendar = client
+        .get_airing_calendar(START, 1, utc, false)
+        .await
+        .unwrap();
+
+    let anime = &calendar[&day(14)][0].anime;
+    assert_eq!(anime.id, 1);
+    assert_eq!(anime.format, Default::default());
+    assert!(!anime.is_adult);
+}
+
+#[tokio::test]
+async fn schedules_without_an_anime_id_are_skipped() {
+    let mut broken = schedule(10, START + 600, 1);
+    broken["media"]["id"] = serde_json::Value::Null;
+    let mock = MockTransport::new().with_response(
+        "GetAiringCalendar",
+        serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": { "hasNextPage": false },
+                    "airingSchedules": [broken, schedule(11, START + 3600, 2)]
+                }
+            }
+        }),
+    );
+    let client = Client::with_transport(mock);
+
+    let utc = FixedOffset::east_opt(0).unwrap();
+    let calendar = client
+        .get_airing_calendar(START, 1, utc, false)
+        .await
+        .unwrap();
+
+    assert_eq!(calendar[&day(14)].len(), 1);
+    assert_eq!(calendar[&day(14)][0].schedule.id, 11);
+}
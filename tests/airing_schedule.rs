@@ -0,0 +1,65 @@
+use std::sync::{Arc, Mutex};
+
+use rust_anilist::{Client, Transport, TransportFuture};
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+
+/// A transport answering the airing schedule in pages of two episodes.
+#[derive(Clone, Default)]
+struct Schedule {
+    pages: Arc<Mutex<Vec<i64>>>,
+}
+
+impl Transport for Schedule {
+    fn execute(&self, body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            if !body["query"]
+                .as_str()
+                .unwrap()
+                .contains("GetAiringSchedule")
+            {
+                return Ok(serde_json::from_str(ANIME).unwrap());
+            }
+
+            let page = body["variables"]["page"].as_i64().unwrap();
+            self.pages.lock().unwrap().push(page);
+
+            let nodes: Vec<_> = (page * 2 - 1..=page * 2)
+                .map(|episode| {
+                    serde_json::json!({
+                        "id": episode,
+                        "airingAt": 924_000_000 + episode * 604_800,
+                        "timeUntilAiring": -1_000_000,
+                        "episode": episode
+                    })
+                })
+                .collect();
+
+            Ok(serde_json::json!({
+                "data": {
+                    "Media": {
+                        "airingSchedule": {
+                            "pageInfo": { "hasNextPage": page < 3 },
+                            "nodes": nodes
+                        }
+                    }
+                }
+            }))
+        })
+    }
+}
+
+#[tokio::test]
+async fn get_airing_schedule_fetches_every_page() {
+    let transport = Schedule::default();
+    let client = Client::with_transport(transport.clone());
+
+    let anime = client.get_anime(1).await.unwrap();
+    let schedule = anime.get_airing_schedule().await.unwrap();
+
+    assert_eq!(*transport.pages.lock().unwrap(), vec![1, 2, 3]);
+    assert_eq!(schedule.len(), 6);
+    assert_eq!(schedule[0].episode, 1);
+    assert_eq!(schedule[5].episode, 6);
+    assert!(schedule[0].time_until < 0);
+}
@@ -0,0 +1,80 @@
+use std::sync::{Arc, Mutex};
+
+use rust_anilist::models::CharacterRole;
+use rust_anilist::{Client, Transport, TransportFuture};
+
+/// A transport answering the characters of an anime in pages of two.
+#[derive(Clone, Default)]
+struct Characters {
+    pages: Arc<Mutex<Vec<i64>>>,
+}
+
+impl Transport for Characters {
+    fn execute(&self, body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let page = body["variables"]["page"].as_i64().unwrap();
+            self.pages.lock().unwrap().push(page);
+
+            let edges: Vec<_> = (page * 2 - 1..=page * 2)
+                .map(|id| {
+                    serde_json::json!({
+                        "node": {
+                            "id": id,
+                            "name": { "full": format!("Character {id}"), "alternative": [] },
+                            "image": { "large": "", "medium": "" },
+                            "description": null,
+                            "siteUrl": format!("https://anilist.co/character/{id}")
+                        },
+                        "role": if id == 1 { "MAIN" } else { "SUPPORTING" },
+                        "voiceActors": [{
+                            "id": 100 + id,
+                            "name": { "full": format!("Voice {id}"), "alternative": [] },
+                            "languageV2": "Japanese",
+                            "image": { "large": "", "medium": "" },
+                            "gender": "Female",
+                            "siteUrl": format!("https://anilist.co/staff/{}", 100 + id)
+                        }]
+                    })
+                })
+                .collect();
+
+            Ok(serde_json::json!({
+                "data": {
+                    "Media": {
+                        "characters": {
+                            "pageInfo": { "currentPage": page, "hasNextPage": page < 3 },
+                            "edges": edges
+                        }
+                    }
+                }
+            }))
+        })
+    }
+}
+
+#[tokio::test]
+async fn get_characters_of_anime_fetches_a_page() {
+    let transport = Characters::default();
+    let client = Client::with_transport(transport.clone());
+
+    let page = client.get_characters_of_anime(1, 2, 2).await.unwrap();
+
+    assert_eq!(page.len(), 2);
+    assert_eq!(page[0].id, 3);
+    assert_eq!(page[0].role, Some(CharacterRole::Supporting));
+    assert_eq!(page[0].voice_actors.as_ref().unwrap()[0].id, 103);
+    assert!(page.has_next());
+}
+
+#[tokio::test]
+async fn get_all_characters_fetches_every_page() {
+    let transport = Characters::default();
+    let client = Client::with_transport(transport.clone());
+
+    let characters = client.anime(1).get_all_characters().await.unwrap();
+
+    assert_eq!(*transport.pages.lock().unwrap(), vec![1, 2, 3]);
+    assert_eq!(characters.len(), 6);
+    assert_eq!(characters[0].role, Some(CharacterRole::Main));
+    assert_eq!(characters[5].name.to_string(), "Character 6");
+}
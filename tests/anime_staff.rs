@@ -0,0 +1,70 @@
+use std::sync::{Arc, Mutex};
+
+use rust_anilist::{Client, Transport, TransportFuture};
+
+/// A transport answering the staff of an anime in pages of two.
+#[derive(Clone, Default)]
+struct Staff {
+    pages: Arc<Mutex<Vec<i64>>>,
+}
+
+impl Transport for Staff {
+    fn execute(&self, body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let page = body["variables"]["page"].as_i64().unwrap();
+            self.pages.lock().unwrap().push(page);
+
+            let edges: Vec<_> = (page * 2 - 1..=page * 2)
+                .map(|id| {
+                    serde_json::json!({
+                        "node": {
+                            "id": id,
+                            "name": { "full": format!("Person {id}"), "alternative": [] },
+                            "languageV2": "Japanese",
+                            "gender": "Male",
+                            "siteUrl": format!("https://anilist.co/staff/{id}")
+                        },
+                        "role": if id == 1 { "Director" } else { "Key Animation (ep 3)" }
+                    })
+                })
+                .collect();
+
+            Ok(serde_json::json!({
+                "data": {
+                    "Media": {
+                        "staff": {
+                            "pageInfo": { "currentPage": page, "hasNextPage": page < 2 },
+                            "edges": edges
+                        }
+                    }
+                }
+            }))
+        })
+    }
+}
+
+#[tokio::test]
+async fn get_staff_of_anime_fetches_a_page() {
+    let transport = Staff::default();
+    let client = Client::with_transport(transport.clone());
+
+    let page = client.get_staff_of_anime(1, 1, 2).await.unwrap();
+
+    assert_eq!(page.len(), 2);
+    assert_eq!(page[0].staff_role.as_deref(), Some("Director"));
+    assert_eq!(page[1].name.to_string(), "Person 2");
+    assert!(page.has_next());
+}
+
+#[tokio::test]
+async fn get_all_staff_fetches_every_page() {
+    let transport = Staff::default();
+    let client = Client::with_transport(transport.clone());
+
+    let staff = client.anime(1).get_all_staff().await.unwrap();
+
+    assert_eq!(*transport.pages.lock().unwrap(), vec![1, 2]);
+    assert_eq!(staff.len(), 4);
+    assert_eq!(staff[3].id, 4);
+    assert_eq!(staff[3].staff_role.as_deref(), Some("Key Animation (ep 3)"));
+}
@@ -0,0 +1,28 @@
+use rust_anilist::{blocking, Client, MockTransport};
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+
+fn client() -> blocking::Client {
+    let mock = MockTransport::new().with_response("GetAnime", serde_json::from_str(ANIME).unwrap());
+
+    Client::with_transport(mock).into()
+}
+
+#[test]
+fn get_anime() {
+    let anime = client().get_anime(1).unwrap();
+
+    assert_eq!(anime.id, 1);
+    assert_eq!(anime.title.romaji(), "Cowboy Bebop");
+}
+
+#[test]
+fn get_manga_without_response() {
+    assert!(client().get_manga(1).is_err());
+}
+
+#[tokio::test]
+#[should_panic(expected = "cannot be used from within an async runtime")]
+async fn panics_within_a_runtime() {
+    let _ = client().get_anime(1);
+}
@@ -0,0 +1,114 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use rust_anilist::{Client, Url};
+
+/// Serves a single HTTP request with the given JSON body and sends the
+/// raw request back through the returned channel.
+fn serve_once(body: &'static str) -> (Url, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut request = String::new();
+        let mut content_length = 0;
+
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                content_length = value.trim().parse().unwrap();
+            }
+            request.push_str(&line);
+            if line == "\r\n" {
+                break;
+            }
+        }
+
+        let mut content = vec![0; content_length];
+        reader.read_exact(&mut content).unwrap();
+        request.push_str(&String::from_utf8(content).unwrap());
+
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nX-RateLimit-Limit: 90\r\nX-RateLimit-Remaining: 89\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        reader.get_mut().write_all(response.as_bytes()).unwrap();
+        sender.send(request).unwrap();
+    });
+
+    (url, receiver)
+}
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+
+#[tokio::test]
+async fn builder_uses_api_url_and_user_agent() {
+    let (url, request) = serve_once(ANIME);
+    let client = Client::builder()
+        .api_url(url)
+        .user_agent("rust-anilist-tests")
+        .timeout(Duration::from_secs(5))
+        .build();
+
+    let anime = client.get_anime(1).await.unwrap();
+    let request = request.recv().unwrap();
+
+    assert_eq!(anime.id, 1);
+    assert_eq!(anime.title.romaji(), "Cowboy Bebop");
+    assert!(request
+        .to_lowercase()
+        .contains("user-agent: rust-anilist-tests"));
+    assert_eq!(client.rate_limit_status().unwrap().remaining, Some(89));
+}
+
+#[tokio::test]
+async fn builder_uses_supplied_http_client() {
+    let (url, request) = serve_once(ANIME);
+    let http_client = reqwest::Client::builder()
+        .default_headers(
+            [("x-custom".parse().unwrap(), "yes".parse().unwrap())]
+                .into_iter()
+                .collect(),
+        )
+        .build()
+        .unwrap();
+    let client = Client::builder()
+        .api_url(url)
+        .with_http_client(http_client)
+        .build();
+
+    client.get_anime(1).await.unwrap();
+    let request = request.recv().unwrap();
+
+    assert!(request.to_lowercase().contains("x-custom: yes"));
+}
+
+#[tokio::test]
+async fn builder_calls_response_hook() {
+    let (url, _request) = serve_once(ANIME);
+    let responses = Arc::new(Mutex::new(Vec::new()));
+    let client = Client::builder()
+        .api_url(url)
+        .on_response({
+            let responses = responses.clone();
+            move |info| responses.lock().unwrap().push(info.clone())
+        })
+        .build();
+
+    client.get_anime(1).await.unwrap();
+
+    let responses = responses.lock().unwrap();
+
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0].operation, "GetAnime");
+    assert_eq!(responses[0].status, Some(200));
+    assert_eq!(responses[0].rate_limit.unwrap().remaining, Some(89));
+}
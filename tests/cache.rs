@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use rust_anilist::{Client, MockTransport};
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+
+fn mock() -> MockTransport {
+    MockTransport::new().with_response("GetAnime", serde_json::from_str(ANIME).unwrap())
+}
+
+#[tokio::test]
+async fn cached_responses_are_reused() {
+    let mock = mock();
+    let client = Client::builder()
+        .transport(mock.clone())
+        .cache(Duration::from_secs(60))
+        .build();
+
+    let first = client.get_anime(1).await.unwrap();
+    let second = client.get_anime(1).await.unwrap();
+
+    assert_eq!(first, second);
+    assert_eq!(mock.requests().len(), 1);
+
+    client.invalidate_cache("GetAnime");
+    client.get_anime(1).await.unwrap();
+
+    assert_eq!(mock.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn responses_are_not_cached_by_default() {
+    let mock = mock();
+    let client = Client::with_transport(mock.clone());
+
+    client.get_anime(1).await.unwrap();
+    client.get_anime(1).await.unwrap();
+
+    assert_eq!(mock.requests().len(), 2);
+}
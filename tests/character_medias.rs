@@ -0,0 +1,184 @@
+use std::sync::{Arc, Mutex};
+
+use rust_anilist::models::{
+    CharacterMediaFilter, CharacterRole, MediaSort, MediaType, TitleLanguage,
+};
+use rust_anilist::{Client, Transport, TransportFuture};
+
+/// A transport answering for a character appearing in 60 medias, the
+/// first of which is the only one where it is a main character.
+#[derive(Clone, Default)]
+struct Medias {
+    requests: Arc<Mutex<Vec<serde_json::Value>>>,
+}
+
+fn edges(page: usize, per_page: usize) -> (Vec<serde_json::Value>, bool) {
+    let ids = (1..=60).skip((page - 1) * per_page).take(per_page);
+    let edges = ids
+        .map(|id| {
+            serde_json::json!({
+                "characterRole": if id == 1 { "MAIN" } else { "SUPPORTING" },
+                "voiceActors": [],
+                "node": {
+                    "id": id,
+                    "title": {
+                        "romaji": format!("Romaji {id}"),
+                        "english": format!("English {id}"),
+                        "native": format!("Native {id}")
+                    },
+                    "type": if id % 2 == 0 { "MANGA" } else { "ANIME" },
+                    "format": if id % 2 == 0 { "MANGA" } else { "TV" }
+                }
+            })
+        })
+        .collect();
+
+    (edges, page * per_page < 60)
+}
+
+impl Transport for Medias {
+    fn execute(&self, body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            self.requests.lock().unwrap().push(body.clone());
+            let variables = &body["variables"];
+
+            if body["query"]
+                .as_str()
+                .unwrap()
+                .contains("GetCharacterMedias")
+            {
+                let page = variables["page"].as_u64().unwrap() as usize;
+                let per_page = variables["per_page"].as_u64().unwrap() as usize;
+                let (edges, has_next_page) = edges(page, per_page);
+
+                return Ok(serde_json::json!({
+                    "data": {
+                        "Character": {
+                            "media": {
+                                "pageInfo": { "currentPage": page, "hasNextPage": has_next_page },
+                                "edges": edges
+                            }
+                        }
+                    }
+                }));
+            }
+
+            let (edges, has_next_page) = edges(1, 25);
+            Ok(serde_json::json!({
+                "data": {
+                    "Character": {
+                        "id": variables["id"],
+                        "name": { "full": "Spike Spiegel", "alternative": [] },
+                        "image": { "large": "", "medium": "" },
+                        "description": "",
+                        "siteUrl": "https://anilist.co/character/1",
+                        "media": {
+                            "pageInfo": { "hasNextPage": has_next_page },
+                            "edges": edges
+                        }
+                    }
+                }
+            }))
+        })
+    }
+}
+
+#[tokio::test]
+async fn get_character_loads_the_first_page_of_medias() {
+    let transport = Medias::default();
+    let client = Client::builder()
+        .transport(transport.clone())
+        .title_language(TitleLanguage::English)
+        .build();
+
+    let character = client.get_character(1).await.unwrap();
+    let medias = character.medias.as_ref().unwrap();
+
+    assert_eq!(medias.len(), 25);
+    assert_eq!(medias[1].media_type, MediaType::Manga);
+    assert_eq!(medias[0].title.preferred(), "English 1");
+
+    let main = character.medias_by_role(CharacterRole::Main);
+    assert_eq!(main.len(), 1);
+    assert_eq!(main[0].media_id, 1);
+    assert_eq!(transport.requests.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn get_medias_fetches_the_following_pages() {
+    let transport = Medias::default();
+    let client = Client::with_transport(transport.clone());
+
+    let character = client.get_character(1).await.unwrap();
+    let medias = character.get_medias().await.unwrap();
+
+    let ids: Vec<i64> = medias.iter().map(|media| media.media_id).collect();
+    assert_eq!(ids, (1..=60).collect::<Vec<_>>());
+
+    let pages: Vec<_> = transport
+        .requests
+        .lock()
+        .unwrap()
+        .iter()
+        .skip(1)
+        .map(|body| body["variables"]["page"].as_u64().unwrap())
+        .collect();
+    assert_eq!(pages, vec![2, 3]);
+}
+
+#[tokio::test]
+async fn get_medias_of_an_unloaded_character_starts_at_the_first_page() {
+    let transport = Medias::default();
+    let client = Client::with_transport(transport.clone());
+
+    let medias = client.character(1).get_medias().await.unwrap();
+
+    assert_eq!(medias.len(), 60);
+    assert_eq!(transport.requests.lock().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn get_medias_filtered_sends_the_filter_and_paginates() {
+    let transport = Medias::default();
+    let client = Client::with_transport(transport.clone());
+    let filter = CharacterMediaFilter::new()
+        .sort(MediaSort::StartDate)
+        .on_list(true);
+
+    let page = client
+        .character(1)
+        .get_medias_filtered(filter, 1, 50)
+        .await
+        .unwrap();
+    assert_eq!(page.len(), 50);
+    assert_eq!(page[0].character_role, Some(CharacterRole::Main));
+
+    let next = page.next().await.unwrap().unwrap();
+    assert_eq!(next.len(), 10);
+    assert!(!next.has_next());
+
+    let requests = transport.requests.lock().unwrap();
+    for variables in requests.iter().map(|body| &body["variables"]) {
+        assert_eq!(variables["sort"], serde_json::json!(["START_DATE"]));
+        assert_eq!(variables["onList"], true);
+    }
+    assert_eq!(requests[1]["variables"]["page"], 2);
+}
+
+#[tokio::test]
+async fn the_default_filter_sorts_by_popularity() {
+    let transport = Medias::default();
+    let client = Client::with_transport(transport.clone());
+
+    client
+        .get_character_medias_filtered(1, CharacterMediaFilter::new(), 1, 25)
+        .await
+        .unwrap();
+
+    let requests = transport.requests.lock().unwrap();
+    assert_eq!(
+        requests[0]["variables"]["sort"],
+        serde_json::json!(["POPULARITY_DESC"])
+    );
+    assert!(requests[0]["variables"].get("onList").is_none());
+}
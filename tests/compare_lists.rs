@@ -0,0 +1,96 @@
+use std::sync::{Arc, Mutex};
+
+use rust_anilist::{models::MediaType, Client, Error, Transport, TransportFuture};
+
+/// A transport answering with the lists of two users: "tenpoint", who
+/// scores out of 10 and whose list comes in two chunks, and "stars",
+/// who scores out of 5 stars. The scores are on the 100-point scale, as
+/// the query asks for.
+#[derive(Clone, Default)]
+struct Lists {
+    requests: Arc<Mutex<Vec<serde_json::Value>>>,
+}
+
+fn entry(media_id: i64, score: f64) -> serde_json::Value {
+    serde_json::json!({
+        "id": media_id + 1000,
+        "userId": 1,
+        "mediaId": media_id,
+        "status": "COMPLETED",
+        "score": score
+    })
+}
+
+impl Transport for Lists {
+    fn execute(&self, body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let variables = body["variables"].clone();
+            self.requests.lock().unwrap().push(variables.clone());
+
+            let (format, entries, has_next_chunk) =
+                match (variables["userName"].as_str(), variables["chunk"].as_i64()) {
+                    (Some("tenpoint"), Some(1)) => {
+                        ("POINT_10", vec![entry(1, 90.0), entry(2, 60.0)], true)
+                    }
+                    (Some("tenpoint"), _) => ("POINT_10", vec![entry(3, 0.0)], false),
+                    _ => (
+                        "POINT_5",
+                        vec![entry(1, 80.0), entry(3, 100.0), entry(4, 40.0)],
+                        false,
+                    ),
+                };
+
+            Ok(serde_json::json!({
+                "data": {
+                    "MediaListCollection": {
+                        "hasNextChunk": has_next_chunk,
+                        "user": { "mediaListOptions": { "scoreFormat": format } },
+                        "lists": [{ "entries": entries }]
+                    }
+                }
+            }))
+        })
+    }
+}
+
+#[tokio::test]
+async fn lists_are_compared_on_the_same_scale() {
+    let transport = Lists::default();
+    let client = Client::with_transport(transport.clone());
+
+    let comparison = client
+        .compare_lists("tenpoint", "stars", MediaType::Anime)
+        .await
+        .unwrap();
+
+    let shared = comparison
+        .shared
+        .iter()
+        .map(|(first, _)| first.media_id)
+        .collect::<Vec<_>>();
+    assert_eq!(shared, [1, 3]);
+    assert_eq!(comparison.only_in_first[0].media_id, 2);
+    assert_eq!(comparison.only_in_first[0].score, 60.0);
+    assert_eq!(comparison.only_in_second[0].media_id, 4);
+    assert_eq!(comparison.score_differences.len(), 1);
+    assert_eq!(comparison.score_differences[0].first_score, 90.0);
+    assert_eq!(comparison.score_differences[0].second_score, 80.0);
+    assert_eq!(comparison.mean_score_difference(), Some(10.0));
+
+    let requests = transport.requests.lock().unwrap();
+    assert_eq!(requests.len(), 3);
+    assert!(requests
+        .iter()
+        .all(|variables| variables["type"] == "ANIME"));
+}
+
+#[tokio::test]
+async fn only_medias_can_be_compared() {
+    let client = Client::with_transport(Lists::default());
+
+    let result = client
+        .compare_lists("tenpoint", "stars", MediaType::Character)
+        .await;
+
+    assert!(matches!(result, Err(Error::InvalidMediaType)));
+}
@@ -0,0 +1,60 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rust_anilist::{Client, Transport, TransportFuture};
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+
+/// A slow transport recording the most requests it had in flight.
+#[derive(Default)]
+struct Counting {
+    in_flight: AtomicUsize,
+    peak: Arc<AtomicUsize>,
+}
+
+impl Transport for Counting {
+    fn execute(&self, _body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            Ok(serde_json::from_str(ANIME).unwrap())
+        })
+    }
+}
+
+async fn peak(max_concurrent: Option<usize>) -> usize {
+    let transport = Counting::default();
+    let peak = transport.peak.clone();
+    let mut builder = Client::builder().transport(transport);
+    if let Some(max) = max_concurrent {
+        builder = builder.max_concurrent(max);
+    }
+    let client = builder.build();
+
+    let handles = (1..=8)
+        .map(|id| {
+            let client = client.clone();
+            tokio::spawn(async move { client.get_anime(id).await })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.await.unwrap().unwrap();
+    }
+
+    peak.load(Ordering::SeqCst)
+}
+
+#[tokio::test]
+async fn requests_in_flight_are_capped() {
+    assert_eq!(peak(Some(2)).await, 2);
+    assert_eq!(peak(Some(1)).await, 1);
+}
+
+#[tokio::test]
+async fn requests_are_not_capped_by_default() {
+    assert!(peak(None).await > 2);
+}
@@ -0,0 +1,61 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rust_anilist::{Client, Error, Transport, TransportFuture};
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+
+/// A transport answering every anime but `404`, recording how many
+/// requests are in flight at once.
+#[derive(Clone, Default)]
+struct Counting {
+    in_flight: Arc<AtomicUsize>,
+    max_in_flight: Arc<AtomicUsize>,
+}
+
+impl Transport for Counting {
+    fn execute(&self, body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            let id = body["variables"]["id"].as_i64().unwrap();
+            if id == 404 {
+                return Err(Error::ApiError("Not Found.".to_string()));
+            }
+
+            let mut response: serde_json::Value = serde_json::from_str(ANIME).unwrap();
+            response["data"]["Media"]["id"] = id.into();
+            Ok(response)
+        })
+    }
+}
+
+#[tokio::test]
+async fn get_animes_concurrent_limits_requests_in_flight() {
+    let transport = Counting::default();
+    let client = Client::with_transport(transport.clone());
+    let ids: Vec<i64> = (1..=10).collect();
+
+    let animes = client.get_animes_concurrent(&ids, 3).await;
+
+    assert_eq!(animes.len(), 10);
+    assert!(animes.iter().all(|(_, anime)| anime.is_ok()));
+    assert_eq!(transport.max_in_flight.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn get_animes_concurrent_keeps_going_after_a_failure() {
+    let client = Client::with_transport(Counting::default());
+
+    let animes = client.get_animes_concurrent(&[1, 404, 5], 2).await;
+    let ids: Vec<i64> = animes.iter().map(|(id, _)| id.get()).collect();
+
+    assert_eq!(ids, vec![1, 404, 5]);
+    assert_eq!(animes[0].1.as_ref().unwrap().id, 1);
+    assert!(animes[1].1.is_err());
+    assert_eq!(animes[2].1.as_ref().unwrap().id, 5);
+}
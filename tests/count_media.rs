@@ -0,0 +1,68 @@
+use rust_anilist::models::{Genre, MediaSearchOptions, MediaType};
+use rust_anilist::{Client, Error, MockTransport};
+
+fn count_response(total: u32) -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "Page": {
+                "pageInfo": { "total": total },
+                "media": [{ "id": 1 }]
+            }
+        }
+    })
+}
+
+#[tokio::test]
+async fn count_media_returns_the_total() {
+    let mock = MockTransport::new().with_response("CountMedia", count_response(5000));
+    let client = Client::with_transport(mock.clone());
+
+    let options = MediaSearchOptions::new()
+        .media_type(MediaType::Manga)
+        .genres(&[Genre::Romance]);
+    let total = client.count_media(options).await.unwrap();
+
+    assert_eq!(total, 5000);
+
+    let requests = mock.requests();
+    assert_eq!(requests[0]["variables"]["type"], "MANGA");
+    assert_eq!(
+        requests[0]["variables"]["genres"],
+        serde_json::json!(["Romance"])
+    );
+    assert!(requests[0]["variables"]["search"].is_null());
+}
+
+#[tokio::test]
+async fn count_media_shares_the_filters_of_the_search() {
+    let mock = MockTransport::new()
+        .with_response("CountMedia", count_response(3))
+        .with_response(
+            "SearchAnime",
+            serde_json::json!({ "data": { "Page": { "pageInfo": {}, "media": [] } } }),
+        );
+    let client = Client::with_transport(mock.clone());
+
+    client
+        .count_media(MediaSearchOptions::new().search("bebop"))
+        .await
+        .unwrap();
+    client.search_anime("bebop", 1, 10).await.unwrap();
+
+    let requests = mock.requests();
+    assert_eq!(requests[0]["variables"]["type"], "ANIME");
+    assert_eq!(
+        requests[0]["variables"]["search"],
+        requests[1]["variables"]["search"]
+    );
+}
+
+#[tokio::test]
+async fn count_media_rejects_other_types() {
+    let client = Client::with_transport(MockTransport::new());
+
+    let options = MediaSearchOptions::new().media_type(MediaType::Studio);
+    let result = client.count_media(options).await;
+
+    assert!(matches!(result, Err(Error::InvalidMediaType)));
+}
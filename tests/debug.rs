@@ -0,0 +1,24 @@
+use rust_anilist::{Client, MockTransport};
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+const TOKEN: &str = "secret-bearer-token";
+
+#[tokio::test]
+async fn debug_output_never_shows_the_token() {
+    let client = Client::builder()
+        .token(TOKEN)
+        .transport(
+            MockTransport::new().with_response("GetAnime", serde_json::from_str(ANIME).unwrap()),
+        )
+        .build();
+
+    let anime = client.get_anime(1).await.unwrap();
+    let output = format!("{anime:?}");
+
+    assert!(!output.contains(TOKEN));
+    assert!(output.contains("client: Client { .. }"));
+    assert!(output.contains("relations: <1 relation edges>"));
+    assert!(!format!("{anime:#?}").contains(TOKEN));
+    assert!(!format!("{:?}", anime.relations().unwrap()).contains(TOKEN));
+    assert!(!format!("{client:?}").contains(TOKEN));
+}
@@ -0,0 +1,27 @@
+use std::time::{Duration, Instant};
+
+use rust_anilist::models::{Anime, Character, Person};
+use rust_anilist::Client;
+
+#[test]
+fn default_models_are_cheap() {
+    let start = Instant::now();
+
+    for _ in 0..10_000 {
+        let anime = Anime::default();
+        let character = Character::default();
+        let person = Person::default();
+
+        assert_eq!(anime.id, 0);
+        assert_eq!(character.id, 0);
+        assert_eq!(person.id, 0);
+    }
+
+    // Building an HTTP client for each model would take seconds.
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn default_client_is_not_detached() {
+    assert!(!Client::default().is_detached());
+}
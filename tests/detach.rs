@@ -0,0 +1,64 @@
+use rust_anilist::models::{Anime, CharacterRole};
+use rust_anilist::{AnimeFields, Client, Error, MockTransport};
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+
+fn mock() -> MockTransport {
+    MockTransport::new().with_response("GetAnime", serde_json::from_str(ANIME).unwrap())
+}
+
+#[tokio::test]
+async fn detached_models_keep_their_data() {
+    let client = Client::with_transport(mock());
+    let anime = client.get_anime(1).await.unwrap();
+
+    let detached = anime.clone().detach();
+
+    assert_eq!(detached.title, anime.title);
+    assert_eq!(detached.relations().unwrap().len(), 1);
+    assert_eq!(
+        detached.characters_by_role(CharacterRole::Main).len(),
+        anime.characters_by_role(CharacterRole::Main).len()
+    );
+    // Comparisons ignore the client.
+    assert_eq!(detached, anime);
+}
+
+#[tokio::test]
+async fn detached_models_refuse_requests() {
+    let mock = mock();
+    let client = Client::with_transport(mock.clone());
+    let anime = client
+        .get_anime_with(1, AnimeFields::basic())
+        .await
+        .unwrap()
+        .detach();
+
+    let result = anime.clone().load_full().await;
+
+    assert!(matches!(result, Err(Error::Detached)));
+    assert_eq!(mock.requests().len(), 1);
+
+    let anime = anime.attach(&client).load_full().await.unwrap();
+
+    assert_eq!(anime.id.get(), 1);
+    assert_eq!(mock.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn detached_models_can_be_cached_and_reattached() {
+    let client = Client::with_transport(mock());
+    let anime = client.get_anime(1).await.unwrap();
+
+    let cached = serde_json::to_string(&anime.clone().detach()).unwrap();
+    let restored: Anime = serde_json::from_str(&cached).unwrap();
+    let restored = restored.attach(&client);
+
+    assert_eq!(restored.title, anime.title);
+    assert!(restored.relations().unwrap()[0]
+        .clone()
+        .detach()
+        .load()
+        .await
+        .is_err());
+}
@@ -0,0 +1,35 @@
+use rust_anilist::{Client, MockTransport};
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+const MANGA: &str = include_str!("fixtures/manga.json");
+
+fn client() -> Client {
+    Client::with_transport(
+        MockTransport::new()
+            .with_response("GetAnime", serde_json::from_str(ANIME).unwrap())
+            .with_response("GetManga", serde_json::from_str(MANGA).unwrap()),
+    )
+}
+
+#[tokio::test]
+async fn anime_and_its_people_display_in_one_line() {
+    let anime = client().get_anime(1).await.unwrap();
+
+    assert_eq!(anime.to_string(), "Cowboy Bebop (1998, TV, 26 eps)");
+    assert_eq!(
+        anime.characters.as_ref().unwrap()[0].to_string(),
+        "Spike Spiegel [Main]"
+    );
+    assert_eq!(
+        anime.staff.as_ref().unwrap()[0].to_string(),
+        "Shinichirou Watanabe [Director]"
+    );
+    assert_eq!(anime.main_studio().unwrap().to_string(), "Sunrise");
+}
+
+#[tokio::test]
+async fn manga_without_counts_displays_without_them() {
+    let manga = client().get_manga(30002).await.unwrap();
+
+    assert_eq!(manga.to_string(), "Berserk (1989, Manga)");
+}
@@ -0,0 +1,48 @@
+// The client of the models has interior mutability, but it takes no part
+// in their hash.
+#![allow(clippy::mutable_key_type)]
+
+use std::collections::HashSet;
+
+use rust_anilist::{Client, MockTransport};
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+
+fn client(token: Option<&str>) -> Client {
+    let mock = MockTransport::new().with_response("GetAnime", serde_json::from_str(ANIME).unwrap());
+    let builder = Client::builder().transport(mock);
+
+    match token {
+        Some(token) => builder.token(token).build(),
+        None => builder.build(),
+    }
+}
+
+#[tokio::test]
+async fn copies_fetched_by_different_clients_are_equal() {
+    let first = client(None).get_anime(1).await.unwrap();
+    let second = client(Some("token")).get_anime(1).await.unwrap();
+
+    assert_eq!(first, second);
+    assert_eq!(first.characters, second.characters);
+    assert_eq!(HashSet::from([first, second]).len(), 1);
+}
+
+#[tokio::test]
+async fn models_are_hashed_by_id() {
+    let anime = client(None).get_anime(1).await.unwrap();
+    let characters: HashSet<_> = anime
+        .characters
+        .iter()
+        .flatten()
+        .chain(anime.characters.iter().flatten())
+        .collect();
+
+    assert_eq!(characters.len(), anime.characters.as_ref().unwrap().len());
+
+    let mut edited = anime.clone();
+    edited.episodes = Some(1);
+    let animes = HashSet::from([anime, edited]);
+
+    assert_eq!(animes.len(), 2);
+}
@@ -0,0 +1,81 @@
+use rust_anilist::{
+    models::{MediaType, ScoreFormat},
+    Client, MockTransport,
+};
+
+fn client() -> Client {
+    let entry = |id: i64, media_id: i64, score: f64, title: &str| {
+        serde_json::json!({
+            "id": id,
+            "userId": 1,
+            "mediaId": media_id,
+            "status": "COMPLETED",
+            "score": score,
+            "progress": 12,
+            "notes": "Rewatch, someday",
+            "startedAt": { "year": 2016, "month": 4, "day": null },
+            "completedAt": { "year": null, "month": null, "day": null },
+            "media": {
+                "id": media_id,
+                "idMal": media_id,
+                "title": { "romaji": title, "english": null, "native": title }
+            }
+        })
+    };
+    let cowboy_bebop = entry(100, 1, 90.0, "Cowboy Bebop");
+
+    Client::with_transport(MockTransport::new().with_response(
+        "GetMediaListCollection",
+        serde_json::json!({
+            "data": {
+                "MediaListCollection": {
+                    "hasNextChunk": false,
+                    "user": { "mediaListOptions": { "scoreFormat": "POINT_5" } },
+                    "lists": [
+                        { "entries": [cowboy_bebop.clone(), entry(101, 5, 0.0, "Trigun")] },
+                        { "entries": [cowboy_bebop] }
+                    ]
+                }
+            }
+        }),
+    ))
+}
+
+#[tokio::test]
+async fn entries_in_custom_lists_are_returned_once() {
+    let list = client()
+        .get_media_list_collection("andrielfr", MediaType::Anime)
+        .await
+        .unwrap();
+
+    assert_eq!(list.score_format, ScoreFormat::Point5);
+    assert_eq!(list.entries.len(), 2);
+    assert_eq!(list.entries[0].score, 90.0);
+    assert_eq!(
+        list.entries[0].media.as_ref().unwrap().title.romaji(),
+        "Cowboy Bebop"
+    );
+}
+
+#[tokio::test]
+async fn lists_are_exported_to_csv() {
+    let list = client()
+        .get_media_list_collection("andrielfr", MediaType::Anime)
+        .await
+        .unwrap();
+
+    let mut csv = Vec::new();
+    list.to_csv(&mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let lines = csv.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), 3);
+    assert_eq!(
+        lines[1],
+        "1,1,Cowboy Bebop,Cowboy Bebop,COMPLETED,90,12,2016-04,,0,\"Rewatch, someday\""
+    );
+    assert_eq!(
+        lines[2],
+        "5,5,Trigun,Trigun,COMPLETED,,12,2016-04,,0,\"Rewatch, someday\""
+    );
+}
@@ -0,0 +1,31 @@
+use rust_anilist::models::{Anime, Character, Person};
+use rust_anilist::Error;
+
+#[test]
+fn models_hold_the_given_data() {
+    let anime = Anime::for_test(1, "Cowboy Bebop");
+    let character = Character::for_test(1, "Spike Spiegel");
+    let person = Person::for_test(95061, "Shinichirou Watanabe");
+
+    assert_eq!(anime.id, 1);
+    assert_eq!(anime.title.romaji(), "Cowboy Bebop");
+    assert_eq!(anime.title.native(), "Cowboy Bebop");
+    assert_eq!(anime.url, "https://anilist.co/anime/1");
+    assert_eq!(character.name.full(), Some("Spike Spiegel".to_string()));
+    assert_eq!(character.url, "https://anilist.co/character/1");
+    assert_eq!(person.name.full(), Some("Shinichirou Watanabe".to_string()));
+    assert_eq!(person.url, "https://anilist.co/staff/95061");
+}
+
+#[tokio::test]
+async fn models_refuse_requests() {
+    let anime = Anime::for_test(1, "Cowboy Bebop").load_full().await;
+    let character = Character::for_test(1, "Spike Spiegel").load_full().await;
+    let person = Person::for_test(95061, "Shinichirou Watanabe")
+        .load_full()
+        .await;
+
+    assert!(matches!(anime, Err(Error::Detached)));
+    assert!(matches!(character, Err(Error::Detached)));
+    assert!(matches!(person, Err(Error::Detached)));
+}
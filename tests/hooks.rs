@@ -0,0 +1,61 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+
+use rust_anilist::{
+    Client, Error, ErrorKind, ResponseInfo, RetryPolicy, Transport, TransportFuture,
+};
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+
+/// A transport failing its first request with a server error.
+struct Flaky {
+    calls: AtomicU32,
+}
+
+impl Transport for Flaky {
+    fn execute(&self, _body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            if self.calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(Error::HttpError(503))
+            } else {
+                Ok(serde_json::from_str(ANIME).unwrap())
+            }
+        })
+    }
+}
+
+#[tokio::test]
+async fn hooks_are_called_for_every_attempt() {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let responses = Arc::new(Mutex::new(Vec::<ResponseInfo>::new()));
+    let client = Client::builder()
+        .transport(Flaky {
+            calls: AtomicU32::new(0),
+        })
+        .retry_policy(RetryPolicy::default().base_delay(std::time::Duration::ZERO))
+        .on_request({
+            let requests = requests.clone();
+            move |info| requests.lock().unwrap().push(info.clone())
+        })
+        .on_response({
+            let responses = responses.clone();
+            move |info| responses.lock().unwrap().push(info.clone())
+        })
+        .build();
+
+    client.get_anime(1).await.unwrap();
+
+    let requests = requests.lock().unwrap();
+    let responses = responses.lock().unwrap();
+
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].operation, "GetAnime");
+    assert_eq!(requests[1].attempt, 2);
+    assert!(!requests[0].is_mutation);
+
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[0].status, Some(503));
+    assert_eq!(responses[0].error, Some(ErrorKind::Server));
+    assert_eq!(responses[1].error, None);
+    assert!(!responses[1].has_errors);
+}
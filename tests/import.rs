@@ -0,0 +1,170 @@
+use std::sync::{Arc, Mutex};
+
+use rust_anilist::import::{self, ConflictPolicy, ImportOptions, ImportOutcome, ImportedEntry};
+use rust_anilist::models::{MediaListCollection, MediaListStatus, MediaType};
+use rust_anilist::{Client, Error, MockTransport};
+
+/// A mock knowing two medias: MyAnimeList's 1, already in the list with
+/// 10 episodes watched, and 5, not in the list.
+fn mock() -> MockTransport {
+    MockTransport::new()
+        .with_response(
+            "GetMediasByMalId",
+            serde_json::json!({
+                "data": {
+                    "Page": {
+                        "media": [
+                            { "id": 1, "idMal": 1, "mediaListEntry": { "id": 7, "progress": 10 } },
+                            { "id": 6, "idMal": 5, "mediaListEntry": null }
+                        ]
+                    }
+                }
+            }),
+        )
+        .with_response(
+            "SaveMediaListEntry",
+            serde_json::json!({ "data": { "SaveMediaListEntry": { "id": 8, "mediaId": 6 } } }),
+        )
+}
+
+fn entries() -> Vec<ImportedEntry> {
+    [1, 5, 99]
+        .into_iter()
+        .map(|mal_id| ImportedEntry {
+            mal_id,
+            media_type: MediaType::Anime,
+            status: Some(MediaListStatus::Completed),
+            score: 80.0,
+            progress: 26,
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn saved(mock: &MockTransport) -> Vec<serde_json::Value> {
+    mock.requests()
+        .into_iter()
+        .filter(|request| request["query"].as_str().unwrap().contains("mutation"))
+        .map(|request| request["variables"].clone())
+        .collect()
+}
+
+#[tokio::test]
+async fn existing_entries_are_skipped_by_default() {
+    let mock = mock();
+    let client = Client::builder()
+        .transport(mock.clone())
+        .token("token")
+        .build();
+
+    let report = client
+        .import_entries(&entries(), ImportOptions::new())
+        .await
+        .unwrap();
+
+    assert_eq!(report.created, [5]);
+    assert_eq!(report.skipped, [1]);
+    assert_eq!(report.not_found, [99]);
+
+    let saved = saved(&mock);
+    assert_eq!(saved.len(), 1);
+    assert_eq!(saved[0]["mediaId"], 6);
+    assert_eq!(saved[0]["status"], "COMPLETED");
+    assert_eq!(saved[0]["scoreRaw"], 80);
+    assert!(saved[0].get("notes").is_none());
+
+    let lookup = &mock.requests()[0]["variables"];
+    assert_eq!(lookup["idMal"], serde_json::json!([1, 5, 99]));
+    assert_eq!(lookup["type"], "ANIME");
+}
+
+#[tokio::test]
+async fn higher_progress_is_merged() {
+    let mock = mock();
+    let client = Client::builder()
+        .transport(mock.clone())
+        .token("token")
+        .build();
+    let mut entries = entries();
+    entries[1].progress = 0;
+
+    let options = ImportOptions::new().conflict_policy(ConflictPolicy::MergeHigherProgress);
+    let report = client.import_entries(&entries, options).await.unwrap();
+
+    assert_eq!(report.updated, [1]);
+    assert_eq!(report.created, [5]);
+    assert_eq!(saved(&mock).len(), 2);
+}
+
+#[tokio::test]
+async fn dry_runs_save_nothing() {
+    let mock = mock();
+    let client = Client::builder()
+        .transport(mock.clone())
+        .token("token")
+        .build();
+    let progress = Arc::new(Mutex::new(Vec::new()));
+    let recorded = progress.clone();
+
+    let options = ImportOptions::new()
+        .conflict_policy(ConflictPolicy::Overwrite)
+        .dry_run(true)
+        .on_progress(move |progress| recorded.lock().unwrap().push(progress.clone()));
+    let report = client.import_entries(&entries(), options).await.unwrap();
+
+    assert_eq!(report.updated, [1]);
+    assert!(saved(&mock).is_empty());
+
+    let progress = progress.lock().unwrap();
+    assert_eq!(progress.len(), 3);
+    assert_eq!(progress[2].done, 3);
+    assert_eq!(progress[2].total, 3);
+    assert_eq!(progress[2].outcome, ImportOutcome::NotFound);
+}
+
+#[tokio::test]
+async fn importing_requires_a_token() {
+    let client = Client::with_transport(mock());
+
+    let report = client
+        .import_entries(&entries(), ImportOptions::new())
+        .await;
+
+    assert!(matches!(report, Err(Error::MissingToken)));
+}
+
+#[test]
+fn exported_lists_are_read_back() {
+    let collection: MediaListCollection = serde_json::from_value(serde_json::json!({
+        "scoreFormat": "POINT_100",
+        "entries": [{
+            "id": 100,
+            "userId": 1,
+            "mediaId": 1,
+            "status": "CURRENT",
+            "score": 85.0,
+            "progress": 12,
+            "repeat": 1,
+            "notes": "<3 & ]]> more",
+            "startedAt": { "year": 2016, "month": 4, "day": null },
+            "media": { "id": 1, "idMal": 1, "title": { "romaji": "Cowboy Bebop", "native": "カウボーイビバップ" } }
+        }]
+    }))
+    .unwrap();
+
+    let export = collection.to_mal_xml(MediaType::Anime).unwrap();
+    let entries = import::parse_mal_xml(export.xml.as_bytes()).unwrap();
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].mal_id, 1);
+    assert_eq!(entries[0].title, "Cowboy Bebop");
+    assert_eq!(entries[0].status, Some(MediaListStatus::Current));
+    assert_eq!(entries[0].score, 90.0);
+    assert_eq!(entries[0].progress, 12);
+    assert_eq!(entries[0].repeat, 1);
+    assert_eq!(entries[0].notes.as_deref(), Some("<3 & ]]> more"));
+    assert_eq!(
+        entries[0].started_at.as_ref().map(|date| date.format_iso()),
+        Some("2016-04".to_string())
+    );
+}
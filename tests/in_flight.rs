@@ -0,0 +1,79 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rust_anilist::{Client, Error, Transport, TransportFuture};
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+
+/// A slow transport failing its first request.
+struct Slow {
+    calls: Arc<AtomicU32>,
+    fail_first: bool,
+}
+
+impl Transport for Slow {
+    fn execute(&self, _body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+
+            if self.fail_first && call == 0 {
+                Err(Error::HttpError(502))
+            } else {
+                Ok(serde_json::from_str(ANIME).unwrap())
+            }
+        })
+    }
+}
+
+fn client(fail_first: bool) -> (Client, Arc<AtomicU32>) {
+    let calls = Arc::new(AtomicU32::new(0));
+    let client = Client::with_transport(Slow {
+        calls: calls.clone(),
+        fail_first,
+    });
+
+    (client, calls)
+}
+
+#[tokio::test]
+async fn identical_queries_are_sent_once() {
+    let (client, calls) = client(false);
+    let clone = client.clone();
+
+    let (a, b, c) = tokio::join!(client.get_anime(1), client.get_anime(1), clone.get_anime(1));
+
+    assert_eq!(a.unwrap().id, 1);
+    assert_eq!(b.unwrap().id, 1);
+    assert_eq!(c.unwrap().id, 1);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    client.get_anime(1).await.unwrap();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn different_queries_are_not_shared() {
+    let (client, calls) = client(false);
+
+    let (a, b) = tokio::join!(client.get_anime(1), client.get_anime(2));
+
+    assert!(a.is_ok() && b.is_ok());
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn errors_are_shared_then_retried() {
+    let (client, calls) = client(true);
+
+    let (a, b) = tokio::join!(client.get_anime(1), client.get_anime(1));
+
+    assert!(matches!(a, Err(Error::HttpError(502))));
+    assert!(matches!(b, Err(Error::HttpError(502))));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    assert!(client.get_anime(1).await.is_ok());
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
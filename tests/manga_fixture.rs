@@ -0,0 +1,30 @@
+use rust_anilist::models::{CharacterRole, MangaId};
+use rust_anilist::{Client, MockTransport};
+
+const MANGA: &str = include_str!("fixtures/manga.json");
+
+#[tokio::test]
+async fn get_manga_loads_every_connection() {
+    let mock = MockTransport::new().with_response("GetManga", serde_json::from_str(MANGA).unwrap());
+    let client = Client::with_transport(mock.clone());
+
+    let manga = client.get_manga(30002).await.unwrap();
+
+    assert_eq!(manga.id, MangaId::new(30002));
+    assert_eq!(manga.title.romaji(), "Berserk");
+    assert_eq!(manga.characters_by_role(CharacterRole::Main).len(), 3);
+    assert_eq!(manga.staff.as_ref().unwrap().len(), 3);
+    assert_eq!(manga.relations().unwrap().len(), 8);
+    assert_eq!(mock.requests()[0]["variables"]["id"], 30002);
+}
+
+#[tokio::test]
+async fn load_full_fetches_the_manga() {
+    let mock = MockTransport::new().with_response("GetManga", serde_json::from_str(MANGA).unwrap());
+    let client = Client::with_transport(mock.clone());
+
+    let manga = client.manga(30002).load_full().await.unwrap();
+
+    assert_eq!(manga.rankings.as_ref().unwrap().len(), 3);
+    assert_eq!(mock.requests().len(), 1);
+}
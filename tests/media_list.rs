@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use rust_anilist::{Client, Transport, TransportFuture};
+
+/// A transport answering from a list of 120 medias, in pages of 20
+/// entries.
+#[derive(Clone, Default)]
+struct List {
+    requests: Arc<Mutex<Vec<serde_json::Value>>>,
+}
+
+impl Transport for List {
+    fn execute(&self, body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let variables = body["variables"].clone();
+            self.requests.lock().unwrap().push(variables.clone());
+
+            let page = variables["page"].as_u64().unwrap() as usize;
+            let in_list: Vec<i64> = variables["mediaIds"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|id| id.as_i64().unwrap())
+                .filter(|id| *id <= 120)
+                .collect();
+            let entries: Vec<_> = in_list
+                .iter()
+                .skip((page - 1) * 20)
+                .take(20)
+                .map(|id| {
+                    serde_json::json!({
+                        "id": id + 1000,
+                        "userId": variables["userId"],
+                        "mediaId": id,
+                        "status": "CURRENT",
+                        "score": 0,
+                        "progress": id % 12
+                    })
+                })
+                .collect();
+
+            Ok(serde_json::json!({
+                "data": {
+                    "Page": {
+                        "pageInfo": { "hasNextPage": page * 20 < in_list.len() },
+                        "mediaList": entries
+                    }
+                }
+            }))
+        })
+    }
+}
+
+#[tokio::test]
+async fn get_media_list_entries_batches_and_paginates() {
+    let transport = List::default();
+    let client = Client::with_transport(transport.clone());
+    let ids: Vec<i64> = (1..=150).collect();
+
+    let entries = client.get_media_list_entries(&ids, 7).await.unwrap();
+    let requests = transport.requests.lock().unwrap();
+    let pages: Vec<u64> = requests
+        .iter()
+        .map(|variables| variables["page"].as_u64().unwrap())
+        .collect();
+
+    assert_eq!(entries.len(), 120);
+    assert!(entries.iter().all(|entry| entry.user_id == 7));
+    assert_eq!(pages, vec![1, 2, 3, 1, 2, 3, 1]);
+    assert_eq!(requests[0]["mediaIds"].as_array().unwrap().len(), 50);
+
+    let progress: HashMap<i64, u32> = entries
+        .iter()
+        .map(|entry| (entry.media_id, entry.progress))
+        .collect();
+
+    assert_eq!(progress[&25], 1);
+    assert!(!progress.contains_key(&121));
+}
+
+#[tokio::test]
+async fn get_media_list_entries_without_ids() {
+    let transport = List::default();
+    let client = Client::with_transport(transport.clone());
+
+    let entries = client.get_media_list_entries(&[], 7).await.unwrap();
+
+    assert!(entries.is_empty());
+    assert!(transport.requests.lock().unwrap().is_empty());
+}
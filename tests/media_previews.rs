@@ -0,0 +1,90 @@
+use std::sync::{Arc, Mutex};
+
+use rust_anilist::models::{Format, MediaType, TitleLanguage};
+use rust_anilist::{Client, Error, Transport, TransportFuture};
+
+/// A transport answering the aliased `Media` fields of a query, for the
+/// medias with an ID below 100. The other medias are not found, which
+/// AniList reports with an error next to the found medias.
+#[derive(Clone, Default)]
+struct Previews {
+    queries: Arc<Mutex<Vec<String>>>,
+}
+
+impl Transport for Previews {
+    fn execute(&self, body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let query = body["query"].as_str().unwrap().to_string();
+            self.queries.lock().unwrap().push(query.clone());
+
+            let mut data = serde_json::Map::new();
+            let mut errors = Vec::new();
+            for line in query.lines().filter(|line| line.contains(": Media(id: ")) {
+                let (alias, rest) = line.split_once(": Media(id: ").unwrap();
+                let id: i64 = rest.split(',').next().unwrap().parse().unwrap();
+
+                let media = if id < 100 {
+                    serde_json::json!({
+                        "id": id,
+                        "title": {
+                            "romaji": format!("Romaji {id}"),
+                            "english": format!("English {id}"),
+                            "native": format!("Native {id}")
+                        },
+                        "coverImage": { "large": format!("https://example.com/{id}.png") },
+                        "format": "TV",
+                        "seasonYear": 1998
+                    })
+                } else {
+                    errors.push(serde_json::json!({ "message": "Not Found.", "status": 404 }));
+                    serde_json::Value::Null
+                };
+                data.insert(alias.trim().to_string(), media);
+            }
+
+            Ok(serde_json::json!({ "errors": errors, "data": data }))
+        })
+    }
+}
+
+#[tokio::test]
+async fn get_media_previews_batches_the_ids() {
+    let transport = Previews::default();
+    let client = Client::builder()
+        .transport(transport.clone())
+        .title_language(TitleLanguage::English)
+        .build();
+    let ids: Vec<i64> = (1..=30).chain([150]).rev().collect();
+
+    let previews = client
+        .get_media_previews(&ids, MediaType::Anime)
+        .await
+        .unwrap();
+
+    assert_eq!(previews.len(), 30);
+    assert_eq!(previews[0].id, 30);
+    assert_eq!(previews[29].id, 1);
+    assert_eq!(previews[0].title.preferred(), "English 30");
+    assert_eq!(
+        previews[0].cover.as_deref(),
+        Some("https://example.com/30.png")
+    );
+    assert_eq!(previews[0].format, Some(Format::Tv));
+    assert_eq!(previews[0].season_year, Some(1998));
+
+    let queries = transport.queries.lock().unwrap();
+    assert_eq!(queries.len(), 2);
+    assert!(queries[0].contains("type: ANIME"));
+    assert!(queries[0].contains("fragment preview on Media"));
+}
+
+#[tokio::test]
+async fn get_media_previews_rejects_other_types() {
+    let transport = Previews::default();
+    let client = Client::with_transport(transport.clone());
+
+    let result = client.get_media_previews(&[1], MediaType::User).await;
+
+    assert!(matches!(result, Err(Error::InvalidMediaType)));
+    assert!(transport.queries.lock().unwrap().is_empty());
+}
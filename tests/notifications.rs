@@ -0,0 +1,142 @@
+use rust_anilist::models::{Notification, NotificationType, TitleLanguage};
+use rust_anilist::{Client, Error, MockTransport};
+
+fn response() -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "Page": {
+                "pageInfo": { "currentPage": 1, "hasNextPage": true },
+                "notifications": [
+                    {
+                        "id": 1,
+                        "type": "AIRING",
+                        "animeId": 1,
+                        "episode": 5,
+                        "contexts": ["Episode ", " of ", " aired."],
+                        "createdAt": 1_700_000_000,
+                        "media": {
+                            "id": 1,
+                            "idMal": 1,
+                            "title": {
+                                "romaji": "Cowboy Bebop",
+                                "english": "Cowboy Bebop",
+                                "native": "カウボーイビバップ",
+                                "userPreferred": "Cowboy Bebop"
+                            },
+                            "format": "TV",
+                            "status": "FINISHED",
+                            "description": null,
+                            "coverImage": {},
+                            "bannerImage": null,
+                            "averageScore": 86,
+                            "meanScore": 86,
+                            "isAdult": false,
+                            "siteUrl": "https://anilist.co/anime/1"
+                        }
+                    },
+                    {
+                        "id": 2,
+                        "type": "FOLLOWING",
+                        "context": " started following you.",
+                        "createdAt": 1_700_000_100,
+                        "user": { "id": 7, "name": "spike", "about": null, "avatar": null, "bannerImage": null }
+                    },
+                    {
+                        "id": 3,
+                        "type": "ACTIVITY_LIKE",
+                        "activityId": 99,
+                        "context": " liked your activity.",
+                        "createdAt": 1_700_000_200,
+                        "user": { "id": 8, "name": "faye", "about": null, "avatar": null, "bannerImage": null }
+                    },
+                    {
+                        "id": 4,
+                        "type": "THREAD_LIKE",
+                        "context": " liked your forum thread",
+                        "createdAt": 1_700_000_300
+                    }
+                ]
+            }
+        }
+    })
+}
+
+#[tokio::test]
+async fn get_notifications_parses_every_type() {
+    let mock = MockTransport::new().with_response("GetNotifications", response());
+    let client = Client::builder()
+        .transport(mock.clone())
+        .token("token")
+        .title_language(TitleLanguage::Native)
+        .build();
+
+    let page = client.get_notifications(1, 25).await.unwrap();
+
+    assert_eq!(mock.requests()[0]["variables"]["per_page"], 25);
+    assert!(page.has_next());
+    assert_eq!(page.len(), 4);
+
+    let Notification::Airing(airing) = &page[0] else {
+        panic!("expected an airing notification, got {:?}", page[0]);
+    };
+    assert_eq!(airing.anime_id, 1);
+    assert_eq!(airing.message(), "Episode 5 of カウボーイビバップ aired.");
+
+    let Notification::Following(following) = &page[1] else {
+        panic!("expected a following notification, got {:?}", page[1]);
+    };
+    assert_eq!(following.user.as_ref().unwrap().name, "spike");
+
+    let Notification::ActivityLike(like) = &page[2] else {
+        panic!("expected an activity like notification, got {:?}", page[2]);
+    };
+    assert_eq!(like.activity_id, 99);
+    assert_eq!(like.user.as_ref().unwrap().id, 8);
+
+    assert!(matches!(page[3], Notification::Other(_)));
+    assert_eq!(page[3].id(), Some(4));
+    assert_eq!(
+        page[3].notification_type(),
+        Some(NotificationType::ThreadLike)
+    );
+}
+
+#[tokio::test]
+async fn get_notifications_requires_a_token() {
+    let mock = MockTransport::new().with_response("GetNotifications", response());
+    let client = Client::with_transport(mock.clone());
+
+    let result = client.get_notifications(1, 25).await;
+
+    assert!(matches!(result, Err(Error::MissingToken)));
+    assert!(mock.requests().is_empty());
+}
+
+#[tokio::test]
+async fn incomplete_users_and_animes_do_not_break_the_page() {
+    let mut response = response();
+    let notifications = &mut response["data"]["Page"]["notifications"];
+    notifications[0]["media"]["format"] = serde_json::Value::Null;
+    notifications[0]["media"]["isAdult"] = serde_json::Value::Null;
+    notifications[1]["user"]["name"] = serde_json::Value::Null;
+    notifications[2]["user"]["id"] = serde_json::Value::Null;
+    let mock = MockTransport::new().with_response("GetNotifications", response);
+    let client = Client::builder().transport(mock).token("token").build();
+
+    let page = client.get_notifications(1, 25).await.unwrap();
+
+    let Notification::Airing(airing) = &page[0] else {
+        panic!("expected an airing notification, got {:?}", page[0]);
+    };
+    assert_eq!(airing.media.as_ref().unwrap().id, 1);
+
+    let Notification::Following(following) = &page[1] else {
+        panic!("expected a following notification, got {:?}", page[1]);
+    };
+    assert_eq!(following.user.as_ref().unwrap().id, 7);
+
+    let Notification::ActivityLike(like) = &page[2] else {
+        panic!("expected an activity like notification, got {:?}", page[2]);
+    };
+    assert!(like.user.is_none());
+}
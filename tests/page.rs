@@ -0,0 +1,101 @@
+use rust_anilist::models::Genre;
+use rust_anilist::{Client, MockTransport};
+
+fn search_response(has_next_page: bool) -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "Page": {
+                "pageInfo": {
+                    "total": 2,
+                    "perPage": 1,
+                    "currentPage": 1,
+                    "lastPage": 2,
+                    "hasNextPage": has_next_page
+                },
+                "media": [{
+                    "id": 1,
+                    "idMal": 1,
+                    "title": { "romaji": "Cowboy Bebop", "english": "Cowboy Bebop", "native": "カウボーイビバップ" },
+                    "format": "TV",
+                    "status": "FINISHED",
+                    "description": "",
+                    "coverImage": { "extraLarge": "", "large": "", "medium": "", "color": null },
+                    "bannerImage": null,
+                    "averageScore": 86,
+                    "meanScore": 86,
+                    "isAdult": false,
+                    "siteUrl": "https://anilist.co/anime/1"
+                }]
+            }
+        }
+    })
+}
+
+#[tokio::test]
+async fn search_page_fetches_the_next_page() {
+    let mock = MockTransport::new().with_response("SearchAnime", search_response(true));
+    let client = Client::with_transport(mock.clone());
+
+    let page = client.search_anime("bebop", 1, 1).await.unwrap();
+
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].title.romaji(), "Cowboy Bebop");
+    assert_eq!(page.page_info.last_page, Some(2));
+    assert!(page.has_next());
+
+    let next = page.next().await.unwrap().unwrap();
+    let requests = mock.requests();
+
+    assert_eq!(next.items.len(), 1);
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1]["variables"]["page"], 2);
+    assert_eq!(requests[1]["variables"]["search"], "bebop");
+    assert_eq!(requests[1]["variables"]["per_page"], 1);
+}
+
+#[tokio::test]
+async fn last_page_has_no_next_page() {
+    let mock = MockTransport::new().with_response("SearchAnime", search_response(false));
+    let client = Client::with_transport(mock.clone());
+
+    let page = client.search_anime("bebop", 1, 1).await.unwrap();
+
+    assert!(page.next().await.unwrap().is_none());
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn search_by_genres_sends_the_genre_names() {
+    let mock = MockTransport::new().with_response("SearchAnime", search_response(true));
+    let client = Client::with_transport(mock.clone());
+
+    let page = client
+        .search_anime_by_genres(&[Genre::SciFi, Genre::SliceOfLife], 1, 1)
+        .await
+        .unwrap();
+    page.next().await.unwrap().unwrap();
+    let requests = mock.requests();
+
+    assert_eq!(
+        requests[0]["variables"]["genres"],
+        serde_json::json!(["Sci-Fi", "Slice of Life"])
+    );
+    assert!(requests[0]["variables"]["search"].is_null());
+    assert_eq!(
+        requests[1]["variables"]["genres"],
+        requests[0]["variables"]["genres"]
+    );
+    assert_eq!(requests[1]["variables"]["page"], 2);
+}
+
+#[tokio::test]
+async fn search_results_without_a_site_url_get_one() {
+    let mut response = search_response(false);
+    response["data"]["Page"]["media"][0]["siteUrl"] = "".into();
+    let mock = MockTransport::new().with_response("SearchAnime", response);
+    let client = Client::with_transport(mock);
+
+    let page = client.search_anime("bebop", 1, 1).await.unwrap();
+
+    assert_eq!(page[0].url, "https://anilist.co/anime/1");
+}
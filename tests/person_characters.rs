@@ -0,0 +1,79 @@
+use rust_anilist::{Client, MockTransport};
+
+fn character(id: i64, name: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "name": { "full": name, "alternative": [] },
+        "image": { "large": "", "medium": "" },
+        "description": null,
+        "siteUrl": format!("https://anilist.co/character/{id}")
+    })
+}
+
+fn client() -> (Client, MockTransport) {
+    let mock = MockTransport::new()
+        .with_response(
+            "GetPerson",
+            serde_json::json!({
+                "data": {
+                    "Staff": {
+                        "id": 95011,
+                        "name": { "full": "Kouichi Yamadera", "alternative": [] },
+                        "languageV2": "Japanese",
+                        "gender": "Male",
+                        "yearsActive": [1985],
+                        "siteUrl": "https://anilist.co/staff/95011",
+                        "characters": {
+                            "nodes": [character(1, "Spike Spiegel"), character(2, "Jet Black")]
+                        }
+                    }
+                }
+            }),
+        )
+        .with_response(
+            "GetPersonCharacters",
+            serde_json::json!({
+                "data": {
+                    "Staff": {
+                        "characters": {
+                            "pageInfo": { "currentPage": 2, "hasNextPage": false },
+                            "nodes": [character(3, "Ein")]
+                        }
+                    }
+                }
+            }),
+        );
+
+    (Client::with_transport(mock.clone()), mock)
+}
+
+#[tokio::test]
+async fn get_person_loads_the_first_page_of_characters() {
+    let (client, mock) = client();
+
+    let person = client.get_person(95011).await.unwrap();
+    let characters = person.characters.as_ref().unwrap();
+
+    assert_eq!(person.years_active, Some((1985, None)));
+    assert_eq!(characters.len(), 2);
+    assert_eq!(characters[0].name.to_string(), "Spike Spiegel");
+    assert!(characters[1].description.is_empty());
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn get_characters_fetches_a_page() {
+    let (client, mock) = client();
+
+    let person = client.get_person(95011).await.unwrap();
+    let page = person.get_characters(2).await.unwrap();
+
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].id, 3);
+    assert!(page.next().await.unwrap().is_none());
+
+    let variables = &mock.requests()[1]["variables"];
+    assert_eq!(variables["id"], 95011);
+    assert_eq!(variables["page"], 2);
+    assert_eq!(variables["per_page"], 25);
+}
@@ -0,0 +1,20 @@
+use rust_anilist::prelude::*;
+
+#[test]
+fn prelude_exports_the_common_types() {
+    let client = Client::detached();
+    let anime: Anime = client.anime(1);
+
+    assert_eq!(anime.id, AnimeId::from(1));
+    assert_eq!(anime.format, Format::Tv);
+    assert_eq!(MediaType::from("manga"), MediaType::Manga);
+    assert_eq!(Language::default(), Language::Japanese);
+    assert_eq!(Status::default(), Status::NotYetReleased);
+}
+
+#[test]
+fn prelude_keeps_the_std_result() {
+    let result: Result<u8, String> = Ok(1);
+
+    assert_eq!(result, Ok(1));
+}
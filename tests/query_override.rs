@@ -0,0 +1,59 @@
+use rust_anilist::{queries, Client, Error, ErrorKind, MockTransport, Operation};
+
+const STUDIO: &str = include_str!("fixtures/studio.json");
+
+fn studio_query() -> String {
+    queries::GET_STUDIO.replacen("favourites", "favourites isFavouriteBlocked", 1)
+}
+
+#[tokio::test]
+async fn overridden_query_is_sent() {
+    let mock =
+        MockTransport::new().with_response("GetStudio", serde_json::from_str(STUDIO).unwrap());
+    let client = Client::builder()
+        .transport(mock.clone())
+        .override_query(Operation::GetStudio, &studio_query())
+        .unwrap()
+        .build();
+
+    let studio = client.get_studio(14).await.unwrap();
+    let requests = mock.requests();
+
+    assert_eq!(studio.name, "Sunrise");
+    assert_eq!(requests[0]["query"], studio_query());
+    assert_eq!(requests[0]["variables"]["id"], 14);
+}
+
+#[tokio::test]
+async fn raw_queries_are_not_overridden() {
+    let mock =
+        MockTransport::new().with_response("GetStudio", serde_json::from_str(STUDIO).unwrap());
+    let client = Client::builder()
+        .transport(mock.clone())
+        .override_query(Operation::GetStudio, &studio_query())
+        .unwrap()
+        .build();
+
+    let query = "query GetStudio($id: Int) { Studio(id: $id) { id name } }";
+    client
+        .query_raw_value(query, serde_json::json!({ "id": 14 }))
+        .await
+        .unwrap();
+
+    assert_eq!(mock.requests()[0]["query"], query);
+}
+
+#[test]
+fn invalid_overrides_are_rejected() {
+    let renamed = queries::GET_STUDIO.replace("GetStudio", "GetStudioWithExtras");
+    let result = Client::builder().override_query(Operation::GetStudio, &renamed);
+
+    assert!(matches!(result, Err(Error::InvalidQuery(_))));
+
+    let result = Client::builder().override_query(
+        Operation::GetAnime,
+        "query GetAnime($id: Int) { Page { media(id: $id) { id } } }",
+    );
+
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::Usage);
+}
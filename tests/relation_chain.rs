@@ -0,0 +1,102 @@
+use std::sync::{Arc, Mutex};
+
+use rust_anilist::{Client, Transport, TransportFuture};
+
+/// A franchise where 10 precedes 11, 11 precedes 12 and 12 precedes 13,
+/// whose sequel loops back to 11. Anime 12 also has a manga sequel and
+/// a side story.
+#[derive(Clone, Default)]
+struct Franchise {
+    requests: Arc<Mutex<Vec<i64>>>,
+}
+
+fn relation(id: i64, media_type: &str, relation_type: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id * 100,
+        "relationType": relation_type,
+        "isMainStudio": false,
+        "node": {
+            "id": id,
+            "idMal": null,
+            "title": { "romaji": format!("Media {id}"), "native": format!("Media {id}") },
+            "type": media_type,
+            "format": "TV",
+            "status": "FINISHED",
+            "description": "",
+            "coverImage": {},
+            "bannerImage": null,
+            "averageScore": null,
+            "meanScore": null,
+            "siteUrl": format!("https://anilist.co/anime/{id}")
+        }
+    })
+}
+
+impl Transport for Franchise {
+    fn execute(&self, body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let id = body["variables"]["id"].as_i64().unwrap();
+            self.requests.lock().unwrap().push(id);
+
+            let edges = match id {
+                10 => vec![relation(11, "ANIME", "SEQUEL")],
+                11 => vec![
+                    relation(10, "ANIME", "PREQUEL"),
+                    relation(12, "ANIME", "SEQUEL"),
+                ],
+                12 => vec![
+                    relation(11, "ANIME", "PREQUEL"),
+                    relation(99, "MANGA", "SEQUEL"),
+                    relation(13, "ANIME", "SEQUEL"),
+                    relation(20, "ANIME", "SIDE_STORY"),
+                ],
+                13 => vec![
+                    relation(12, "ANIME", "PREQUEL"),
+                    relation(11, "ANIME", "SEQUEL"),
+                ],
+                _ => vec![],
+            };
+
+            Ok(serde_json::json!({
+                "data": {
+                    "Media": {
+                        "id": id,
+                        "title": { "romaji": format!("Anime {id}"), "native": format!("Anime {id}") },
+                        "format": "TV",
+                        "status": "FINISHED",
+                        "description": "",
+                        "coverImage": {},
+                        "isAdult": false,
+                        "siteUrl": format!("https://anilist.co/anime/{id}"),
+                        "relations": { "edges": edges }
+                    }
+                }
+            }))
+        })
+    }
+}
+
+#[tokio::test]
+async fn get_relation_chain_follows_prequels_and_sequels() {
+    let transport = Franchise::default();
+    let client = Client::with_transport(transport.clone());
+
+    let chain = client.get_relation_chain(12).await.unwrap();
+    let ids: Vec<i64> = chain.iter().map(|anime| anime.id.get()).collect();
+
+    assert_eq!(ids, vec![10, 11, 12, 13]);
+    assert_eq!(*transport.requests.lock().unwrap(), vec![12, 11, 10, 13]);
+}
+
+#[tokio::test]
+async fn relation_filters() {
+    let transport = Franchise::default();
+    let client = Client::with_transport(transport);
+
+    let anime = client.get_anime(12).await.unwrap();
+
+    assert_eq!(anime.sequels().len(), 2);
+    assert_eq!(anime.prequels().len(), 1);
+    assert_eq!(anime.side_stories()[0].media().id(), 20);
+    assert!(anime.adaptation().is_none());
+}
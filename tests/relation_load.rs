@@ -0,0 +1,72 @@
+use rust_anilist::models::{Media, RelationType};
+use rust_anilist::{Client, MockTransport};
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+const MANGA: &str = include_str!("fixtures/manga.json");
+
+fn mock() -> MockTransport {
+    MockTransport::new()
+        .with_response("GetAnime", serde_json::from_str(ANIME).unwrap())
+        .with_response("GetManga", serde_json::from_str(MANGA).unwrap())
+}
+
+#[tokio::test]
+async fn load_fetches_the_related_anime() {
+    let mock = mock();
+    let client = Client::with_transport(mock.clone());
+    let manga = client.get_manga(30002).await.unwrap();
+
+    let adaptation = manga
+        .relations()
+        .unwrap()
+        .into_iter()
+        .find(|relation| relation.relation_type == RelationType::Adaptation)
+        .unwrap();
+
+    assert!(matches!(adaptation.load().await.unwrap(), Media::Anime(_)));
+
+    let request = &mock.requests()[1];
+    assert!(request["query"]
+        .as_str()
+        .unwrap()
+        .contains("query GetAnime"));
+    assert_eq!(request["variables"]["id"], 33);
+}
+
+#[tokio::test]
+async fn load_fetches_the_related_manga() {
+    let mock = mock();
+    let client = Client::with_transport(mock.clone());
+    let manga = client.get_manga(30002).await.unwrap();
+
+    let spin_off = manga
+        .relations()
+        .unwrap()
+        .into_iter()
+        .find(|relation| relation.relation_type == RelationType::SpinOff)
+        .unwrap();
+
+    match spin_off.load().await.unwrap() {
+        Media::Manga(manga) => assert!(manga.characters.is_some()),
+        media => panic!("expected a manga, got {media:?}"),
+    }
+
+    let request = &mock.requests()[1];
+    assert!(request["query"]
+        .as_str()
+        .unwrap()
+        .contains("query GetManga"));
+    assert_eq!(request["variables"]["id"], 86082);
+}
+
+#[tokio::test]
+async fn relations_of_get_anime_are_loadable() {
+    let mock = mock();
+    let client = Client::with_transport(mock.clone());
+    let anime = client.get_anime(1).await.unwrap();
+
+    let side_story = anime.side_stories().into_iter().next().unwrap();
+
+    assert!(matches!(side_story.load().await.unwrap(), Media::Anime(_)));
+    assert_eq!(mock.requests()[1]["variables"]["id"], 5);
+}
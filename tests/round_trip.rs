@@ -0,0 +1,198 @@
+use std::fmt::Debug;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use rust_anilist::models::{
+    Anime, Character, CharacterRole, Color, Cover, Date, Format, Gender, Image, Language, Link,
+    LinkType, Manga, MediaListStatus, MediaStats, Name, PageInfo, Person, Ranking, RankingType,
+    RelationType, ScoreFormat, Season, Source, Status, Studio, Tag, Title, Trailer, User,
+};
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+const MANGA: &str = include_str!("fixtures/manga.json");
+const STUDIO: &str = include_str!("fixtures/studio.json");
+
+/// Serializes the value to JSON and back, asserting nothing is lost.
+fn round_trip<T>(value: &T) -> serde_json::Value
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let json = serde_json::to_value(value).unwrap();
+    let back: T = serde_json::from_value(json.clone()).unwrap();
+
+    assert_eq!(&back, value);
+
+    json
+}
+
+fn fixture<T: DeserializeOwned>(fixture: &str, field: &str) -> T {
+    let response: serde_json::Value = serde_json::from_str(fixture).unwrap();
+
+    serde_json::from_value(response["data"][field].clone()).unwrap()
+}
+
+#[test]
+fn anime_round_trips() {
+    let anime: Anime = fixture(ANIME, "Media");
+
+    let json = round_trip(&anime);
+
+    assert_eq!(json["siteUrl"], "https://anilist.co/anime/1");
+    assert!(json["coverImage"].is_object());
+    assert!(json["idMal"].is_number());
+    assert!(json["title"]["userPreferred"].is_string());
+
+    let back: Anime = serde_json::from_value(json).unwrap();
+
+    assert_eq!(back.staff_by_role("Director").len(), 1);
+    assert_eq!(back.main_studio().unwrap().name, "Sunrise");
+    assert_eq!(
+        back.relations().unwrap().len(),
+        anime.relations().unwrap().len()
+    );
+}
+
+#[test]
+fn manga_round_trips() {
+    let manga: Manga = fixture(MANGA, "Media");
+
+    let json = round_trip(&manga);
+
+    assert_eq!(json["siteUrl"], "https://anilist.co/manga/30002");
+    assert!(json["characters"].is_array());
+
+    let back: Manga = serde_json::from_value(json).unwrap();
+
+    assert_eq!(back.staff_by_role("Story & Art").len(), 1);
+    assert_eq!(back.characters_by_role(CharacterRole::Main).len(), 3);
+    assert_eq!(
+        back.relations().unwrap().len(),
+        manga.relations().unwrap().len()
+    );
+}
+
+#[test]
+fn studio_round_trips() {
+    let studio: Studio = fixture(STUDIO, "Studio");
+
+    round_trip(&studio);
+
+    let anime: Anime = fixture(ANIME, "Media");
+    for studio in anime.studios.unwrap() {
+        round_trip(&studio);
+    }
+}
+
+#[test]
+fn people_round_trip() {
+    let anime: Anime = fixture(ANIME, "Media");
+
+    for character in anime.characters.unwrap() {
+        round_trip(&character);
+    }
+    for person in anime.staff.unwrap() {
+        round_trip(&person);
+    }
+
+    round_trip(&Character::default());
+    round_trip(&Person::default());
+    round_trip(&Name::default());
+}
+
+#[test]
+fn media_round_trip() {
+    round_trip(&Manga::default());
+    round_trip(&User::default());
+    round_trip(&Anime::default());
+}
+
+#[test]
+fn parts_round_trip() {
+    let anime: Anime = fixture(ANIME, "Media");
+
+    round_trip(&anime.title);
+    round_trip(&anime.cover);
+    round_trip(&anime.start_date);
+    round_trip(&anime.trailer);
+    round_trip(&anime.rankings);
+    round_trip(&anime.stats);
+    round_trip(&anime.tags);
+    round_trip(&anime.external_links);
+
+    round_trip(&Title::default());
+    round_trip(&Cover::default());
+    round_trip(&Date::default());
+    round_trip(&Image::default());
+    round_trip(&Tag::default());
+    round_trip(&Link::default());
+    round_trip(&Trailer::default());
+    round_trip(&Ranking::default());
+    round_trip(&MediaStats::default());
+    round_trip(&PageInfo::default());
+}
+
+#[test]
+fn enums_round_trip() {
+    assert_eq!(round_trip(&Format::TvShort), "TV_SHORT");
+    assert_eq!(round_trip(&Status::NotYetReleased), "NOT_YET_RELEASED");
+    assert_eq!(round_trip(&Season::Spring), "SPRING");
+    assert_eq!(round_trip(&Source::LightNovel), "LIGHT_NOVEL");
+    assert_eq!(round_trip(&Gender::NonBinary), "Non-binary");
+    assert_eq!(round_trip(&Color::Blue), "BLUE");
+    assert_eq!(round_trip(&RankingType::Popular), "POPULAR");
+    assert_eq!(round_trip(&MediaListStatus::Repeating), "REPEATING");
+    assert_eq!(round_trip(&ScoreFormat::Point10Decimal), "POINT_10_DECIMAL");
+
+    round_trip(&Gender::default());
+    round_trip(&Color::Hex("#ffffff".to_string()));
+    round_trip(&Language::default());
+    round_trip(&LinkType::default());
+    round_trip(&RelationType::default());
+    round_trip(&CharacterRole::default());
+}
+
+#[test]
+fn unknown_enum_values_round_trip() {
+    let format = Format::Unknown("HOLOGRAM".to_string());
+    assert_eq!(round_trip(&format), "HOLOGRAM");
+    assert_eq!(format.to_string(), "HOLOGRAM");
+
+    round_trip(&Status::Unknown("REBOOTING".to_string()));
+    round_trip(&Season::Unknown("MONSOON".to_string()));
+    round_trip(&Source::Unknown("PODCAST".to_string()));
+    round_trip(&Language::Unknown("Welsh".to_string()));
+    round_trip(&LinkType::Unknown("SHOP".to_string()));
+    round_trip(&RelationType::Unknown("REMAKE".to_string()));
+    round_trip(&CharacterRole::Unknown("CAMEO".to_string()));
+    round_trip(&RankingType::Unknown);
+    round_trip(&MediaListStatus::Unknown);
+    round_trip(&ScoreFormat::Unknown);
+}
+
+#[test]
+fn anime_with_unknown_values_parses() {
+    let mut response: serde_json::Value = serde_json::from_str(ANIME).unwrap();
+    let media = &mut response["data"]["Media"];
+    media["format"] = "HOLOGRAM".into();
+    media["status"] = "REBOOTING".into();
+    media["season"] = "MONSOON".into();
+    media["source"] = "PODCAST".into();
+    media["rankings"][0]["type"] = "TRENDING".into();
+    media["stats"]["statusDistribution"][0]["status"] = "REWATCHING".into();
+
+    let anime: Anime = serde_json::from_value(media.clone()).unwrap();
+
+    assert_eq!(anime.format, Format::Unknown("HOLOGRAM".to_string()));
+    assert_eq!(anime.status, Status::Unknown("REBOOTING".to_string()));
+    assert_eq!(anime.season, Some(Season::Unknown("MONSOON".to_string())));
+    assert_eq!(anime.source, Some(Source::Unknown("PODCAST".to_string())));
+    assert_eq!(
+        anime.rankings.unwrap()[0].ranking_type,
+        RankingType::Unknown
+    );
+    assert_eq!(
+        anime.stats.unwrap().status_distribution.unwrap()[0].status,
+        MediaListStatus::Unknown
+    );
+}
@@ -0,0 +1,103 @@
+use rust_anilist::{Client, MockTransport};
+
+#[tokio::test]
+async fn every_entity_type_is_searched_in_one_request() {
+    let mock = MockTransport::new().with_response(
+        "SearchAll",
+        serde_json::json!({
+            "data": {
+                "anime": {
+                    "media": [{
+                        "id": 1,
+                        "idMal": 1,
+                        "title": { "romaji": "Cowboy Bebop", "native": "カウボーイビバップ" },
+                        "format": "TV",
+                        "status": "FINISHED",
+                        "description": "",
+                        "coverImage": { "large": null },
+                        "bannerImage": null,
+                        "averageScore": 86,
+                        "meanScore": 86,
+                        "isAdult": false,
+                        "siteUrl": "https://anilist.co/anime/1"
+                    }]
+                },
+                "manga": { "media": [] },
+                "characters": {
+                    "nodes": [{
+                        "id": 1,
+                        "name": { "full": "Spike Spiegel", "alternative": [] },
+                        "image": {
+                            "large": "https://s4.anilist.co/file/anilistcdn/character/large/1.png",
+                            "medium": "https://s4.anilist.co/file/anilistcdn/character/medium/1.png"
+                        },
+                        "description": null,
+                        "siteUrl": "https://anilist.co/character/1",
+                        "favourites": 20000
+                    }]
+                },
+                "staff": { "nodes": [] },
+                "studios": {
+                    "nodes": [{
+                        "id": 14,
+                        "name": "Sunrise",
+                        "isAnimationStudio": true,
+                        "siteUrl": "https://anilist.co/studio/14",
+                        "favourites": 5000
+                    }]
+                }
+            }
+        }),
+    );
+
+    let client = Client::with_transport(mock.clone());
+
+    let results = client.search_all("bebop", 3).await.unwrap();
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results.anime[0].title.romaji(), "Cowboy Bebop");
+    assert!(results.manga.is_empty());
+    assert_eq!(results.characters[0].favourites, Some(20000));
+    assert!(results.staff.is_empty());
+    assert_eq!(results.studios[0].name, "Sunrise");
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0]["variables"]["search"], "bebop");
+    assert_eq!(requests[0]["variables"]["per_page"], 3);
+}
+
+#[tokio::test]
+async fn incomplete_medias_are_kept_and_medias_without_an_id_skipped() {
+    let mock = MockTransport::new().with_response(
+        "SearchAll",
+        serde_json::json!({
+            "data": {
+                "anime": { "media": [{ "id": null, "title": { "romaji": "Broken" } }] },
+                "manga": {
+                    "media": [{
+                        "id": 30001,
+                        "title": { "romaji": "Monster", "native": "MONSTER" },
+                        "format": null,
+                        "description": null,
+                        "coverImage": null,
+                        "isAdult": null,
+                        "siteUrl": null
+                    }]
+                },
+                "characters": { "nodes": [] },
+                "staff": { "nodes": [] },
+                "studios": { "nodes": [] }
+            }
+        }),
+    );
+
+    let client = Client::with_transport(mock);
+
+    let results = client.search_all("monster", 3).await.unwrap();
+
+    assert!(results.anime.is_empty());
+    assert_eq!(results.manga[0].id, 30001);
+    assert_eq!(results.manga[0].title.romaji(), "Monster");
+    assert!(results.manga[0].url.is_empty());
+}
@@ -0,0 +1,68 @@
+//! Compile-time checks that the client and the models can be shared
+//! across threads and their futures spawned onto a multi-threaded runtime.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::future::Future;
+
+use rust_anilist::models::{Anime, Character, Manga, Person, Relation, Studio, User};
+use rust_anilist::{Client, Error};
+
+fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+fn assert_spawnable<F: Future + Send + 'static>(_: F)
+where
+    F::Output: Send + 'static,
+{
+}
+
+#[test]
+fn types_are_send_and_sync() {
+    assert_send_sync::<Client>();
+    assert_send_sync::<rust_anilist::blocking::Client>();
+    assert_send_sync::<Error>();
+    assert_send_sync::<Anime>();
+    assert_send_sync::<Manga>();
+    assert_send_sync::<Character>();
+    assert_send_sync::<Person>();
+    assert_send_sync::<Studio>();
+    assert_send_sync::<User>();
+    assert_send_sync::<Relation>();
+}
+
+#[allow(dead_code)]
+fn futures_are_spawnable(client: Client, anime: Anime, character: Character, person: Person) {
+    let cloned = client.clone();
+    assert_spawnable(async move { cloned.get_anime(1).await });
+    let cloned = client.clone();
+    assert_spawnable(async move { cloned.get_manga(1).await });
+    let cloned = client.clone();
+    assert_spawnable(async move { cloned.get_character(1).await });
+    let cloned = client.clone();
+    assert_spawnable(async move { cloned.get_person(1).await });
+    let cloned = client.clone();
+    assert_spawnable(async move { cloned.get_user(1).await });
+    let cloned = client.clone();
+    assert_spawnable(async move { cloned.get_studio(1).await });
+    let cloned = client.clone();
+    assert_spawnable(async move { cloned.search_anime("Naruto", 1, 10).await });
+    let cloned = client.clone();
+    assert_spawnable(async move { cloned.get_relation_chain(1).await });
+    let cloned = client.clone();
+    assert_spawnable(async move { cloned.get_animes_concurrent(&[1, 2], 2).await });
+    let cloned = client.clone();
+    assert_spawnable(async move { cloned.get_mangas_concurrent(&[1, 2], 2).await });
+    let cloned = client.clone();
+    assert_spawnable(async move { cloned.get_characters_concurrent(&[1, 2], 2).await });
+    assert_spawnable(async move {
+        client
+            .query_raw_value("query { Viewer { id } }", serde_json::json!({}))
+            .await
+    });
+
+    let relations = anime.relations().unwrap();
+    assert_spawnable(anime.load_full());
+    assert_spawnable(character.load_full());
+    assert_spawnable(async move { person.get_characters(1).await });
+    assert_spawnable(async move { relations[0].load().await });
+}
@@ -0,0 +1,222 @@
+use rust_anilist::{
+    models::{Anime, StudioRole},
+    Client, Error, MockTransport,
+};
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+const STUDIO: &str = include_str!("fixtures/studio.json");
+
+fn client() -> (Client, MockTransport) {
+    let mock = MockTransport::new()
+        .with_response("GetAnime", serde_json::from_str(ANIME).unwrap())
+        .with_response("GetStudio", serde_json::from_str(STUDIO).unwrap());
+
+    (Client::with_transport(mock.clone()), mock)
+}
+
+#[tokio::test]
+async fn get_studio() {
+    let (client, mock) = client();
+
+    let studio = client.get_studio(14).await.unwrap();
+    let animes = studio.get_medias::<Anime>().await.unwrap();
+
+    assert_eq!(studio.name, "Sunrise");
+    assert!(!studio.main_studio());
+    assert_eq!(animes.len(), 2);
+    assert_eq!(animes[1].title.romaji(), "Kidou Senshi Gundam");
+    assert_eq!(mock.requests().len(), 1);
+    assert_eq!(mock.requests()[0]["variables"]["id"], 14);
+}
+
+#[tokio::test]
+async fn studio_medias_are_filtered_by_role() {
+    let mut studio: serde_json::Value = serde_json::from_str(STUDIO).unwrap();
+    studio["data"]["Studio"]["media"]["edges"][1]["isMainStudio"] = false.into();
+    let mock = MockTransport::new().with_response("GetStudio", studio);
+    let client = Client::with_transport(mock.clone());
+
+    let studio = client.get_studio(14).await.unwrap();
+    let animated = studio
+        .get_medias_by_role::<Anime>(StudioRole::Animation)
+        .await
+        .unwrap();
+    let produced = studio
+        .get_medias_by_role::<Anime>(StudioRole::Production)
+        .await
+        .unwrap();
+
+    assert!(studio.is_animation_studio);
+    assert_eq!(animated.len(), 1);
+    assert_eq!(animated[0].title.romaji(), "Cowboy Bebop");
+    assert_eq!(produced.len(), 1);
+    assert_eq!(produced[0].title.romaji(), "Kidou Senshi Gundam");
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn anime_studios() {
+    let (client, mock) = client();
+
+    let anime = client.get_anime(1).await.unwrap();
+    let studios = anime.studios.unwrap();
+
+    assert!(studios[0].main_studio());
+    assert!(!studios[1].main_studio());
+
+    let animes = studios[0].get_medias::<Anime>().await.unwrap();
+
+    assert_eq!(animes[0].id, 1);
+    assert_eq!(mock.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn get_studio_animes_reports_the_total() {
+    let mock = MockTransport::new().with_response(
+        "GetStudioAnimes",
+        serde_json::json!({
+            "data": {
+                "Studio": {
+                    "media": {
+                        "pageInfo": {
+                            "total": 142,
+                            "perPage": 1,
+                            "currentPage": 1,
+                            "lastPage": 142,
+                            "hasNextPage": true
+                        },
+                        "media": [{
+                            "id": 1,
+                            "idMal": 1,
+                            "title": { "romaji": "Cowboy Bebop", "native": "カウボーイビバップ" },
+                            "format": "TV",
+                            "status": "FINISHED",
+                            "description": null,
+                            "coverImage": {},
+                            "bannerImage": null,
+                            "averageScore": 86,
+                            "meanScore": 86,
+                            "isAdult": false,
+                            "siteUrl": "https://anilist.co/anime/1"
+                        }]
+                    }
+                }
+            }
+        }),
+    );
+    let client = Client::with_transport(mock.clone());
+
+    let page = client.studio(14).get_animes(1, 1).await.unwrap();
+
+    assert_eq!(page.page_info.total, Some(142));
+    assert!(page.has_next());
+    assert_eq!(page[0].title.romaji(), "Cowboy Bebop");
+
+    let variables = &mock.requests()[0]["variables"];
+    assert_eq!(variables["id"], 14);
+    assert_eq!(variables["per_page"], 1);
+}
+
+#[tokio::test]
+async fn get_studio_animes_skips_animes_without_an_id() {
+    let mock = MockTransport::new().with_response(
+        "GetStudioAnimes",
+        serde_json::json!({
+            "data": {
+                "Studio": {
+                    "media": {
+                        "pageInfo": { "currentPage": 1, "hasNextPage": false },
+                        "media": [
+                            { "id": null, "title": { "romaji": "Broken" } },
+                            { "id": 1, "title": { "romaji": "Cowboy Bebop", "native": "カウボーイビバップ" }, "format": null }
+                        ]
+                    }
+                }
+            }
+        }),
+    );
+    let client = Client::with_transport(mock);
+
+    let page = client.get_studio_animes(14, 1, 2).await.unwrap();
+
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].title.romaji(), "Cowboy Bebop");
+}
+
+fn search_studio(names: &[&str]) -> serde_json::Value {
+    let nodes: Vec<_> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            serde_json::json!({
+                "id": 14 + i,
+                "name": name,
+                "isAnimationStudio": true,
+                "siteUrl": format!("https://anilist.co/studio/{}", 14 + i),
+                "favourites": 5000
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "data": {
+            "Page": {
+                "pageInfo": { "currentPage": 1, "hasNextPage": false },
+                "nodes": nodes
+            }
+        }
+    })
+}
+
+#[tokio::test]
+async fn get_studio_by_name_ignores_case_and_punctuation() {
+    let mock = MockTransport::new()
+        .with_response(
+            "SearchStudio",
+            search_studio(&["Sunrise Beyond", "SUN-RISE"]),
+        )
+        .with_response("GetStudio", serde_json::from_str(STUDIO).unwrap());
+    let client = Client::with_transport(mock.clone());
+
+    let studio = client.get_studio_by_name("sunrise").await.unwrap();
+    let requests = mock.requests();
+
+    assert_eq!(studio.name, "Sunrise");
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0]["variables"]["search"], "sunrise");
+    assert_eq!(requests[1]["variables"]["id"], 15);
+}
+
+#[tokio::test]
+async fn get_studio_by_name_prefers_the_same_name() {
+    let mock = MockTransport::new()
+        .with_response(
+            "SearchStudio",
+            search_studio(&["A1 Pictures", "A-1 Pictures"]),
+        )
+        .with_response("GetStudio", serde_json::from_str(STUDIO).unwrap());
+    let client = Client::with_transport(mock.clone());
+
+    client.get_studio_by_name("a-1 pictures").await.unwrap();
+
+    assert_eq!(mock.requests()[1]["variables"]["id"], 15);
+}
+
+#[tokio::test]
+async fn get_studio_by_name_without_match() {
+    let mock = MockTransport::new().with_response(
+        "SearchStudio",
+        search_studio(&["Sunrise Beyond", "Bandai Namco Pictures"]),
+    );
+    let client = Client::with_transport(mock.clone());
+
+    let result = client.get_studio_by_name("Sunrise").await;
+
+    assert!(matches!(result, Err(Error::NoMatch(name)) if name == "Sunrise"));
+    assert_eq!(mock.requests().len(), 1);
+
+    let candidates = client.search_studio("Sunrise", 1, 10).await.unwrap();
+
+    assert_eq!(candidates.len(), 2);
+    assert_eq!(candidates[0].name, "Sunrise Beyond");
+}
@@ -0,0 +1,89 @@
+use rust_anilist::{Client, MockTransport};
+
+#[tokio::test]
+async fn top_characters_are_paginated() {
+    let mock = MockTransport::new().with_response(
+        "GetTopCharacters",
+        serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": {
+                        "total": 5000,
+                        "perPage": 2,
+                        "currentPage": 3,
+                        "lastPage": 2500,
+                        "hasNextPage": true
+                    },
+                    "nodes": [
+                        {
+                            "id": 40882,
+                            "name": { "full": "Levi", "alternative": [] },
+                            "image": {
+                                "large": "https://s4.anilist.co/file/anilistcdn/character/large/40882.png",
+                                "medium": "https://s4.anilist.co/file/anilistcdn/character/medium/40882.png"
+                            },
+                            "siteUrl": "https://anilist.co/character/40882",
+                            "favourites": 60000
+                        },
+                        {
+                            "id": 1,
+                            "name": { "full": "Spike Spiegel", "alternative": [] },
+                            "image": {
+                                "large": "https://s4.anilist.co/file/anilistcdn/character/large/1.png",
+                                "medium": "https://s4.anilist.co/file/anilistcdn/character/medium/1.png"
+                            },
+                            "siteUrl": "https://anilist.co/character/1",
+                            "favourites": 20000
+                        }
+                    ]
+                }
+            }
+        }),
+    );
+    let client = Client::with_transport(mock.clone());
+
+    let page = client.get_top_characters(3, 2).await.unwrap();
+
+    assert_eq!(page.len(), 2);
+    assert_eq!(page[0].id, 40882);
+    assert_eq!(page[0].favourites, Some(60000));
+    assert_eq!(page[1].url, "https://anilist.co/character/1");
+    assert_eq!(page.page_info.current_page, 3);
+    assert_eq!(page.page_info.total, Some(5000));
+    assert!(page.has_next());
+
+    let requests = mock.requests();
+    assert_eq!(requests[0]["variables"]["page"], 3);
+    assert_eq!(requests[0]["variables"]["per_page"], 2);
+}
+
+#[tokio::test]
+async fn top_staff_are_paginated() {
+    let mock = MockTransport::new().with_response(
+        "GetTopStaff",
+        serde_json::json!({
+            "data": {
+                "Page": {
+                    "pageInfo": { "currentPage": 1, "hasNextPage": false },
+                    "nodes": [{
+                        "id": 95061,
+                        "name": { "full": "Hiroshi Kamiya", "alternative": [] },
+                        "languageV2": "Japanese",
+                        "image": null,
+                        "gender": "Male",
+                        "siteUrl": "https://anilist.co/staff/95061",
+                        "favourites": 30000
+                    }]
+                }
+            }
+        }),
+    );
+    let client = Client::with_transport(mock);
+
+    let page = client.get_top_staff(1, 10).await.unwrap();
+
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].favourites, Some(30000));
+    assert_eq!(page[0].name.full(), Some("Hiroshi Kamiya".to_string()));
+    assert!(!page.has_next());
+}
@@ -0,0 +1,245 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rust_anilist::{
+    models::TitleLanguage, AnimeFields, Client, Error, MockTransport, RetryPolicy, Transport,
+    TransportFuture,
+};
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+
+#[tokio::test]
+async fn get_anime_with_mock_transport() {
+    let mock = MockTransport::new().with_response("GetAnime", serde_json::from_str(ANIME).unwrap());
+    let client = Client::with_transport(mock.clone());
+
+    let anime = client.get_anime(1).await.unwrap();
+    let requests = mock.requests();
+
+    assert_eq!(anime.id, 1);
+    assert_eq!(anime.title.romaji(), "Cowboy Bebop");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0]["variables"]["id"], 1);
+}
+
+#[tokio::test]
+async fn get_anime_with_selected_fields() {
+    let mut response: serde_json::Value = serde_json::from_str(ANIME).unwrap();
+    let media = response["data"]["Media"].as_object_mut().unwrap();
+    for block in [
+        "characters",
+        "staff",
+        "relations",
+        "tags",
+        "rankings",
+        "stats",
+    ] {
+        media.remove(block);
+    }
+    let mock = MockTransport::new().with_response("GetAnime", response);
+    let client = Client::with_transport(mock.clone());
+
+    let anime = client
+        .get_anime_with(1, AnimeFields::basic().studios(true).links(true))
+        .await
+        .unwrap();
+    let variables = &mock.requests()[0]["variables"];
+
+    assert_eq!(anime.title.romaji(), "Cowboy Bebop");
+    assert!(anime.characters.is_none());
+    assert!(anime.staff.is_none());
+    assert!(anime.tags.is_none());
+    assert!(anime.relations().unwrap().is_empty());
+    assert!(anime.main_studio().is_some());
+    assert_eq!(variables["withCharacters"], false);
+    assert_eq!(variables["withStudios"], true);
+}
+
+#[tokio::test]
+async fn query_raw_with_mock_transport() {
+    #[derive(serde::Deserialize)]
+    struct Data {
+        #[serde(rename = "Media")]
+        media: Media,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct Media {
+        id: i64,
+        popularity: u32,
+    }
+
+    let mock = MockTransport::new().with_response(
+        "GetPopularity",
+        serde_json::json!({ "data": { "Media": { "id": 1, "popularity": 380000 } } }),
+    );
+    let client = Client::with_transport(mock.clone());
+
+    let data: Data = client
+        .query_raw(
+            "query GetPopularity($id: Int) { Media(id: $id) { id popularity } }",
+            serde_json::json!({ "id": 1 }),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(data.media.id, 1);
+    assert_eq!(data.media.popularity, 380000);
+    assert_eq!(mock.requests()[0]["variables"]["id"], 1);
+}
+
+#[tokio::test]
+async fn query_raw_returns_graphql_errors() {
+    let mock = MockTransport::new().with_response(
+        "GetMedia",
+        serde_json::json!({ "data": null, "errors": [{ "message": "Not Found.", "status": 404 }] }),
+    );
+    let client = Client::with_transport(mock);
+
+    let error = client
+        .query_raw_value(
+            "query GetMedia { Media(id: 0) { id } }",
+            serde_json::json!({}),
+        )
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.kind(), rust_anilist::ErrorKind::Api);
+}
+
+#[tokio::test]
+async fn mock_transport_without_response() {
+    let client = Client::with_transport(MockTransport::new());
+
+    assert!(client.get_anime(1).await.is_err());
+}
+
+/// A transport failing a fixed number of times before answering.
+struct Flaky {
+    failures: u32,
+    error: fn() -> Error,
+    calls: Arc<AtomicU32>,
+}
+
+impl Transport for Flaky {
+    fn execute(&self, _body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            if self.calls.fetch_add(1, Ordering::SeqCst) < self.failures {
+                Err((self.error)())
+            } else {
+                Ok(serde_json::from_str(ANIME).unwrap())
+            }
+        })
+    }
+}
+
+#[tokio::test]
+async fn retry_policy_retries_server_errors() {
+    let calls = Arc::new(AtomicU32::new(0));
+    let client = Client::builder()
+        .transport(Flaky {
+            failures: 2,
+            error: || Error::HttpError(502),
+            calls: calls.clone(),
+        })
+        .retry_policy(
+            RetryPolicy::default()
+                .max_attempts(3)
+                .base_delay(Duration::from_millis(1)),
+        )
+        .build();
+
+    assert!(client.get_anime(1).await.is_ok());
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn retry_policy_gives_up() {
+    let calls = Arc::new(AtomicU32::new(0));
+    let client = Client::builder()
+        .transport(Flaky {
+            failures: 5,
+            error: || Error::ConnectionError("reset".to_string()),
+            calls: calls.clone(),
+        })
+        .retry_policy(
+            RetryPolicy::default()
+                .max_attempts(2)
+                .base_delay(Duration::from_millis(1)),
+        )
+        .build();
+
+    assert!(matches!(
+        client.get_anime(1).await,
+        Err(Error::ConnectionError(_))
+    ));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn rate_limited_without_retry() {
+    let calls = Arc::new(AtomicU32::new(0));
+    let client = Client::with_transport(Flaky {
+        failures: 1,
+        error: || Error::RateLimited {
+            retry_after: Some(Duration::from_secs(30)),
+        },
+        calls: calls.clone(),
+    });
+
+    assert!(matches!(
+        client.get_anime(1).await,
+        Err(Error::RateLimited { .. })
+    ));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn rate_limited_with_retry() {
+    let calls = Arc::new(AtomicU32::new(0));
+    let client = Client::builder()
+        .transport(Flaky {
+            failures: 1,
+            error: || Error::RateLimited {
+                retry_after: Some(Duration::from_millis(1)),
+            },
+            calls: calls.clone(),
+        })
+        .retry_on_rate_limit(2, Duration::from_secs(1))
+        .build();
+
+    assert!(client.get_anime(1).await.is_ok());
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn unloaded_anime_loads_in_full() {
+    let mock = MockTransport::new().with_response("GetAnime", serde_json::from_str(ANIME).unwrap());
+    let client = Client::with_transport(mock.clone());
+
+    let anime = client.anime(1);
+
+    assert!(mock.requests().is_empty());
+
+    let anime = anime.load_full().await.unwrap();
+
+    assert_eq!(anime.title.romaji(), "Cowboy Bebop");
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn title_language_applies_to_fetched_titles() {
+    let mock = MockTransport::new().with_response("GetAnime", serde_json::from_str(ANIME).unwrap());
+    let client = Client::builder()
+        .transport(mock)
+        .title_language(TitleLanguage::Native)
+        .build();
+
+    let anime = client.get_anime(1).await.unwrap();
+    let relations = anime.relations().unwrap();
+
+    assert_eq!(client.title_language(), Some(TitleLanguage::Native));
+    assert_eq!(anime.title.preferred(), "カウボーイビバップ");
+    assert_eq!(relations[0].media().title(), "カウボーイビバップ 天国の扉");
+}
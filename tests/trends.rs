@@ -0,0 +1,63 @@
+use std::sync::{Arc, Mutex};
+
+use rust_anilist::{Client, Transport, TransportFuture};
+
+/// A transport answering the trends in pages of two days.
+#[derive(Clone, Default)]
+struct Trends {
+    pages: Arc<Mutex<Vec<i64>>>,
+}
+
+impl Transport for Trends {
+    fn execute(&self, body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let page = body["variables"]["page"].as_i64().unwrap();
+            self.pages.lock().unwrap().push(page);
+
+            let nodes: Vec<_> = (page * 2 - 1..=page * 2)
+                .map(|day| {
+                    serde_json::json!({
+                        "date": 1_609_459_200 + day * 86_400,
+                        "trending": 100 - day,
+                        "popularity": 1_000 + day,
+                        "averageScore": if day == 1 { None } else { Some(80) }
+                    })
+                })
+                .collect();
+
+            Ok(serde_json::json!({
+                "data": {
+                    "Media": {
+                        "trends": {
+                            "pageInfo": { "hasNextPage": page < 2 },
+                            "nodes": nodes
+                        }
+                    }
+                }
+            }))
+        })
+    }
+}
+
+#[tokio::test]
+async fn get_trends_fetches_every_page() {
+    let transport = Trends::default();
+    let client = Client::with_transport(transport.clone());
+
+    let trends = client.anime(1).get_trends().await.unwrap();
+
+    assert_eq!(*transport.pages.lock().unwrap(), vec![1, 2]);
+    assert_eq!(trends.len(), 4);
+    assert_eq!(trends[0].date, 1_609_545_600);
+    assert_eq!(trends[0].average_score, None);
+    assert_eq!(trends[3].trending, 96);
+    assert_eq!(trends[3].popularity, Some(1_004));
+    assert_eq!(trends[3].average_score, Some(80));
+}
+
+#[tokio::test]
+async fn get_trends_of_a_detached_anime_fails() {
+    let anime = Client::detached().anime(1);
+
+    assert!(anime.get_trends().await.is_err());
+}
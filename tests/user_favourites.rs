@@ -0,0 +1,122 @@
+use std::sync::{Arc, Mutex};
+
+use rust_anilist::{Client, Transport, TransportFuture};
+
+/// A transport answering with the favourites of a user who has two pages
+/// of favourite animes and one page of everything else.
+#[derive(Clone, Default)]
+struct Favourites {
+    requests: Arc<Mutex<Vec<serde_json::Value>>>,
+}
+
+fn anime(id: i64) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "idMal": id,
+        "title": { "romaji": format!("Anime {id}"), "native": format!("Anime {id}") },
+        "format": "TV",
+        "status": "FINISHED",
+        "description": "",
+        "coverImage": { "large": null },
+        "bannerImage": null,
+        "averageScore": 80,
+        "meanScore": 81,
+        "isAdult": false,
+        "siteUrl": format!("https://anilist.co/anime/{id}")
+    })
+}
+
+impl Transport for Favourites {
+    fn execute(&self, body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let variables = body["variables"].clone();
+            self.requests.lock().unwrap().push(variables.clone());
+
+            let page = variables["page"].as_i64().unwrap();
+            let mut favourites = serde_json::json!({
+                "anime": {
+                    "pageInfo": { "hasNextPage": page == 1 },
+                    "media": [anime(page)]
+                }
+            });
+            if page == 1 {
+                favourites["manga"] = serde_json::json!({
+                    "pageInfo": { "hasNextPage": false },
+                    "media": [{
+                        "id": 30001,
+                        "title": { "romaji": "Monster", "native": "MONSTER" },
+                        "format": null,
+                        "description": null,
+                        "coverImage": null,
+                        "siteUrl": null
+                    }]
+                });
+                favourites["characters"] = serde_json::json!({
+                    "pageInfo": { "hasNextPage": false },
+                    "nodes": [{
+                        "id": 1,
+                        "name": { "full": "Spike Spiegel", "alternative": [] },
+                        "image": {
+                            "large": "https://s4.anilist.co/file/anilistcdn/character/large/1.png",
+                            "medium": "https://s4.anilist.co/file/anilistcdn/character/medium/1.png"
+                        },
+                        "description": null,
+                        "siteUrl": "https://anilist.co/character/1"
+                    }]
+                });
+                favourites["staff"] = serde_json::json!({
+                    "pageInfo": { "hasNextPage": false },
+                    "nodes": [{
+                        "id": 95011,
+                        "name": { "full": "Shinichirou Watanabe", "alternative": [] },
+                        "languageV2": "Japanese",
+                        "image": null,
+                        "description": null,
+                        "gender": "Male",
+                        "siteUrl": "https://anilist.co/staff/95011",
+                        "favourites": 3000
+                    }]
+                });
+                favourites["studios"] = serde_json::json!({
+                    "pageInfo": { "hasNextPage": false },
+                    "nodes": [{
+                        "id": 14,
+                        "name": "Sunrise",
+                        "isAnimationStudio": true,
+                        "siteUrl": "https://anilist.co/studio/14",
+                        "favourites": 5000
+                    }]
+                });
+            }
+
+            Ok(serde_json::json!({ "data": { "User": { "favourites": favourites } } }))
+        })
+    }
+}
+
+#[tokio::test]
+async fn every_favourites_connection_is_fetched_in_full() {
+    let transport = Favourites::default();
+    let client = Client::with_transport(transport.clone());
+
+    let favourites = client.get_user_favourites(1).await.unwrap();
+
+    let anime_ids = favourites
+        .anime
+        .iter()
+        .map(|anime| anime.id)
+        .collect::<Vec<_>>();
+    assert_eq!(anime_ids, [1, 2]);
+    assert_eq!(favourites.manga[0].id, 30001);
+    assert!(favourites.manga[0].description.is_empty());
+    assert!(!favourites.manga[0].is_adult);
+    assert_eq!(favourites.characters[0].id, 1);
+    assert_eq!(favourites.staff[0].favourites, Some(3000));
+    assert_eq!(favourites.studios[0].name, "Sunrise");
+
+    let requests = transport.requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0]["studios"], true);
+    assert_eq!(requests[1]["anime"], true);
+    assert_eq!(requests[1]["studios"], false);
+}
@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use rust_anilist::{Client, Error, MockTransport, Transport, TransportFuture};
+
+/// A transport answering each poll in turn: episode 10, episodes 10
+/// again and 11, an error, then episode 12.
+#[derive(Clone, Default)]
+struct Polls {
+    requests: Arc<Mutex<Vec<serde_json::Value>>>,
+}
+
+fn schedules(ids: &[i64]) -> serde_json::Value {
+    let schedules = ids
+        .iter()
+        .map(|id| {
+            serde_json::json!({
+                "id": id,
+                "airingAt": 1_700_000_000 + id,
+                "timeUntilAiring": -60,
+                "episode": id - 9,
+                "media": {
+                    "id": 1,
+                    "idMal": 1,
+                    "title": { "romaji": "Cowboy Bebop", "native": "カウボーイビバップ" },
+                    "format": "TV",
+                    "status": "RELEASING",
+                    "description": null,
+                    "coverImage": {},
+                    "bannerImage": null,
+                    "averageScore": null,
+                    "meanScore": null,
+                    "isAdult": false,
+                    "siteUrl": "https://anilist.co/anime/1"
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::json!({
+        "data": {
+            "Page": { "pageInfo": { "hasNextPage": false }, "airingSchedules": schedules }
+        }
+    })
+}
+
+impl Transport for Polls {
+    fn execute(&self, body: serde_json::Value) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let mut requests = self.requests.lock().unwrap();
+            requests.push(body["variables"].clone());
+
+            match requests.len() {
+                1 => Ok(schedules(&[10])),
+                2 => Ok(schedules(&[10, 11])),
+                3 => Err(Error::ApiError("unavailable".to_string())),
+                _ => Ok(schedules(&[12])),
+            }
+        })
+    }
+}
+
+#[tokio::test]
+async fn each_aired_episode_is_reported_once() {
+    let transport = Polls::default();
+    let client = Client::with_transport(transport.clone());
+
+    let events = client.watch_airing_after(
+        vec![1, 21],
+        Duration::from_millis(5),
+        1_700_000_000,
+        HashSet::new(),
+    );
+    let events = events.take(4).collect::<Vec<_>>().await;
+
+    let ids = events
+        .iter()
+        .map(|event| event.as_ref().map(|event| event.schedule.id).ok())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [Some(10), Some(11), None, Some(12)]);
+
+    let requests = transport.requests.lock().unwrap();
+    assert_eq!(requests.len(), 4);
+    assert_eq!(requests[0]["start"], 1_700_000_000);
+    assert_eq!(requests[0]["mediaIds"], serde_json::json!([1, 21]));
+    // The failed check is retried from where the last one stopped.
+    assert_eq!(requests[2]["start"], requests[3]["start"]);
+    assert!(requests[1]["start"].as_i64() > requests[0]["start"].as_i64());
+}
+
+#[tokio::test]
+async fn seen_episodes_are_not_reported_again() {
+    let transport = Polls::default();
+    let client = Client::with_transport(transport.clone());
+
+    let seen = HashSet::from([10]);
+    let mut events =
+        Box::pin(client.watch_airing_after(vec![1], Duration::from_millis(5), 1_700_000_000, seen));
+
+    assert_eq!(events.next().await.unwrap().unwrap().schedule.id, 11);
+    assert_eq!(transport.requests.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn dropped_watches_stop_polling() {
+    let transport = Polls::default();
+    let client = Client::with_transport(transport.clone());
+
+    let mut events = Box::pin(client.watch_airing(vec![1], Duration::from_millis(5)));
+    events.next().await.unwrap().unwrap();
+    drop(events);
+    tokio::time::sleep(Duration::from_millis(30)).await;
+
+    assert_eq!(transport.requests.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn watching_no_anime_ends_right_away() {
+    let transport = Polls::default();
+    let client = Client::with_transport(transport.clone());
+
+    let events = client
+        .watch_airing(Vec::new(), Duration::from_millis(5))
+        .collect::<Vec<_>>()
+        .await;
+
+    assert!(events.is_empty());
+    assert!(transport.requests.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn episodes_without_an_anime_id_are_skipped() {
+    let mut response = schedules(&[10, 11]);
+    response["data"]["Page"]["airingSchedules"][0]["media"]["id"] = serde_json::Value::Null;
+    let mock = MockTransport::new().with_response("GetAiringCalendar", response);
+    let client = Client::with_transport(mock);
+
+    let mut events = Box::pin(client.watch_airing(vec![1], Duration::from_millis(5)));
+
+    assert_eq!(events.next().await.unwrap().unwrap().schedule.id, 11);
+}